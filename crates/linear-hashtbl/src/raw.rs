@@ -690,15 +690,17 @@ impl<T, S: Status, A: Clone + Allocator> RawTable<T, S, A> {
                     if last_is_free {
                         slot.status = S::FREE;
                         self.free += 1;
-                    } else {
-                        last_is_free = false;
                     }
                 }
                 continue;
             }
             // SAFETY: The slot's status is a hash value meaning that the data
             // is initialized.
-            if !predicate(unsafe { slot.data.assume_init_mut() }) {
+            if predicate(unsafe { slot.data.assume_init_mut() }) {
+                // A removed entry directly before this one must become a
+                // tombstone, otherwise `find()` would stop early.
+                last_is_free = false;
+            } else {
                 self.len -= 1;
                 if last_is_free {
                     slot.status = S::FREE;
@@ -1065,6 +1067,30 @@ mod test {
         assert_eq!(table.len(), 0);
     }
 
+    #[test]
+    fn retain_collisions() {
+        let mut table = RawTable::<u32, u32>::new();
+        let numbers = [1, 2, 3, 4, 5, 6];
+
+        // All numbers have the same hash value, so they form a single probing
+        // sequence. Removing only a few of them does not shrink the table.
+        for number in numbers {
+            match table.find_or_find_insert_slot(0, |&x| x == number) {
+                Ok(_) => unreachable!(),
+                Err(slot) => unsafe {
+                    table.insert_in_slot_unchecked(0, slot, number);
+                },
+            }
+        }
+
+        table.retain(|&mut x| x != 1, |x| assert_eq!(x, 1));
+        assert_eq!(table.len(), 5);
+        assert_eq!(table.get(0, |&x| x == 1), None);
+        for number in &numbers[1..] {
+            assert_eq!(table.get(0, |x| x == number), Some(number));
+        }
+    }
+
     #[test]
     fn remove() {
         let mut table = RawTable::<u32, u32>::new();
@@ -32,6 +32,7 @@ use oxidd_core::WorkerManager;
 use oxidd_dump::dddmp;
 use oxidd_dump::dot;
 use oxidd_parser::load_file::load_file;
+use oxidd_parser::static_order;
 use oxidd_parser::ClauseOrderNode;
 use oxidd_parser::ParseOptionsBuilder;
 use oxidd_parser::Problem;
@@ -40,7 +41,7 @@ use oxidd_parser::Var;
 use rustc_hash::FxHashMap;
 use rustc_hash::FxHasher;
 
-// spell-checker:ignore mref,subsec,funcs,dotfile,dmpfile
+// spell-checker:ignore mref,subsec,funcs,dotfile,dmpfile,cuthill
 
 #[derive(Parser, Debug)]
 #[command(author, version, about, long_about = None)]
@@ -85,6 +86,11 @@ struct Cli {
     #[arg(long)]
     read_var_order: bool,
 
+    /// Compute a static variable order from the problem structure instead of
+    /// using the order of the variables in the input file
+    #[arg(value_enum, long, conflicts_with = "read_var_order")]
+    var_order_heuristic: Option<VarOrderHeuristic>,
+
     /// Order in which to apply operations when building a CNF
     #[arg(value_enum, long, default_value_t = CNFBuildOrder::Seq)]
     cnf_build_order: CNFBuildOrder,
@@ -128,6 +134,26 @@ enum CNFBuildOrder {
     File,
 }
 
+#[derive(Copy, Clone, PartialEq, Eq, Debug, ValueEnum)]
+enum VarOrderHeuristic {
+    /// FORCE (move variables to the center of gravity of their clauses)
+    Force,
+    /// Recursive min-cut bisection of the clause hypergraph
+    Mince,
+    /// Reverse Cuthill–McKee (minimize the bandwidth)
+    Bandwidth,
+}
+
+impl From<VarOrderHeuristic> for static_order::VarOrderHeuristic {
+    fn from(heuristic: VarOrderHeuristic) -> Self {
+        match heuristic {
+            VarOrderHeuristic::Force => Self::Force,
+            VarOrderHeuristic::Mince => Self::Mince,
+            VarOrderHeuristic::Bandwidth => Self::Bandwidth,
+        }
+    }
+}
+
 /// Human-readable durations
 struct HDuration(Duration);
 
@@ -256,6 +282,19 @@ where
 
     let start = Instant::now();
 
+    let heuristic_var_order = cli.var_order_heuristic.map(|heuristic| {
+        let order = static_order::VarOrderHeuristic::from(heuristic).var_order(&problem);
+        println!(
+            "static variable order computed within {}",
+            HDuration(start.elapsed())
+        );
+        // Name the variables as if there was no order
+        order
+            .into_iter()
+            .map(|(var, _)| (var, (var.get() - 1).to_string()))
+            .collect()
+    });
+
     let func = match problem {
         Problem::CNF {
             num_vars,
@@ -263,7 +302,9 @@ where
             clauses,
             clause_order,
         } => {
-            if !cli.read_var_order {
+            if let Some(order) = heuristic_var_order {
+                var_order = order;
+            } else if !cli.read_var_order {
                 var_order.clear();
             } else if var_order.is_empty() {
                 eprintln!("error: variable order not given");
@@ -279,30 +320,26 @@ where
             });
 
             mref.with_manager_shared(|manager| {
+                let var_levels: Vec<LevelNo> = vars
+                    .iter()
+                    .map(|var| {
+                        manager
+                            .get_node(var.as_edge(manager))
+                            .unwrap_inner()
+                            .level()
+                    })
+                    .collect();
+                let mut literals = Vec::new();
                 let clauses: Vec<B> = clauses
                     .into_iter()
-                    .map(|mut clause| {
-                        if clause.is_empty() {
-                            return B::f(manager);
-                        }
-
-                        // Build clause bottom-up
-                        clause.sort_unstable_by_key(|lit| lit.0);
-                        let (var, neg) = clause.pop().unwrap();
-                        let init = &vars[(var.get() - 1) as usize];
-                        let init = if neg {
-                            init.not().expect(OOM_MSG)
-                        } else {
-                            init.clone()
-                        };
-                        clause.iter().rev().fold(init, |acc, &(var, neg)| {
-                            let var = &vars[(var.get() - 1) as usize];
-                            if neg {
-                                acc.or(&var.not().expect(OOM_MSG)).expect(OOM_MSG)
-                            } else {
-                                acc.or(var).expect(OOM_MSG)
-                            }
-                        })
+                    .map(|clause| {
+                        literals.clear();
+                        literals.extend(
+                            clause
+                                .iter()
+                                .map(|&(var, neg)| (var_levels[(var.get() - 1) as usize], !neg)),
+                        );
+                        B::clause(manager, &literals).expect(OOM_MSG)
                     })
                     .collect();
                 println!(
@@ -333,11 +370,13 @@ where
 
         Problem::Prop {
             num_vars,
-            var_order,
+            mut var_order,
             ast,
             ..
         } => {
-            if cli.read_var_order && var_order.is_empty() {
+            if let Some(order) = heuristic_var_order {
+                var_order = order;
+            } else if cli.read_var_order && var_order.is_empty() {
                 eprintln!("error: variable order not given");
                 std::process::exit(1);
             }
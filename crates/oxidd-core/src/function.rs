@@ -7,15 +7,23 @@ use nanorand::Rng;
 
 use crate::util::num::F64;
 use crate::util::AllocResult;
+use crate::util::Assignments;
 use crate::util::Borrowed;
+use crate::util::CostNumber;
+use crate::util::Cubes;
 use crate::util::EdgeDropGuard;
 use crate::util::NodeSet;
 use crate::util::OptBool;
+use crate::util::OutOfMemory;
 use crate::util::SatCountCache;
 use crate::util::SatCountNumber;
 use crate::util::Substitution;
+use crate::util::Unateness;
+use crate::util::WeightedCountNumber;
+use crate::util::WeightedSatCountCache;
 use crate::DiagramRules;
 use crate::Edge;
+use crate::HasLevel;
 use crate::InnerNode;
 use crate::LevelNo;
 use crate::Manager;
@@ -47,6 +55,16 @@ pub type INodeOfFunc<'id, F> = <<F as Function>::Manager<'id> as Manager>::Inner
 /// this lock accordingly. In a sequential implementation, a
 /// [`RefCell`][std::cell::RefCell] or the like may be used instead of lock.
 ///
+/// Operations that create nodes and are provided by the function traits run
+/// via [`Self::with_manager_shared_restart()`]: If automatic reordering is
+/// enabled and a reordering is requested during the operation, the operation
+/// is aborted, the shared lock is released, the reordering is performed with
+/// exclusive access, and the operation is started over. Hence, where the notes
+/// say that such an operation acquires the lock for shared access, it may
+/// additionally acquire the lock for exclusive access in between. Constructors
+/// that only receive a manager (e.g., [`BooleanFunction::cube()`]) cannot be
+/// restarted and therefore do not abort for a requested reordering.
+///
 /// # Safety
 ///
 /// An implementation must ensure that the "[`Edge`] part" of the function
@@ -147,6 +165,32 @@ pub unsafe trait Function: Clone + Ord + Hash {
     where
         F: for<'id> FnOnce(&mut Self::Manager<'id>, &EdgeOfFunc<'id, Self>) -> T;
 
+    /// Obtain a shared manager reference as well as the underlying edge and
+    /// run `f`, restarting it if it was aborted for dynamic reordering
+    ///
+    /// If `f` returns `Err(OutOfMemory)` and the manager
+    /// [requests a reordering][Manager::reorder_requested], this method
+    /// releases the shared lock, calls [`Manager::reorder_if_requested()`] with
+    /// exclusive access, and runs `f` again.
+    ///
+    /// Locking behavior: acquires the manager's lock for shared access, and
+    /// temporarily for exclusive access in case of a reordering.
+    fn with_manager_shared_restart<F, T>(&self, f: F) -> AllocResult<T>
+    where
+        F: for<'id> Fn(&Self::Manager<'id>, &EdgeOfFunc<'id, Self>) -> AllocResult<T>,
+    {
+        loop {
+            let res = self.with_manager_shared(|manager, edge| match f(manager, edge) {
+                Err(OutOfMemory) if manager.reorder_requested() => None,
+                res => Some(res),
+            });
+            if let Some(res) = res {
+                return res;
+            }
+            self.with_manager_exclusive(|manager, _| manager.reorder_if_requested());
+        }
+    }
+
     /// Count the number of nodes in this function, including terminal nodes
     ///
     /// Locking behavior: acquires the manager's lock for shared access.
@@ -167,6 +211,54 @@ pub unsafe trait Function: Clone + Ord + Hash {
             set.len()
         })
     }
+
+    /// Get the levels of all variables this function depends on (i.e., its
+    /// support) in ascending order
+    ///
+    /// For ZBDDs, these are the levels of the variables contained in at least
+    /// one set of the family.
+    ///
+    /// Locking behavior: acquires the manager's lock for shared access.
+    fn support_levels(&self) -> impl ExactSizeIterator<Item = LevelNo>
+    where
+        for<'id> INodeOfFunc<'id, Self>: HasLevel,
+    {
+        self.with_manager_shared(|manager, edge| Self::support_levels_edge(manager, edge))
+            .into_iter()
+    }
+
+    /// Get the levels of all variables this function depends on, edge version
+    ///
+    /// See [`Self::support_levels()`] for more details.
+    #[must_use]
+    fn support_levels_edge<'id>(
+        manager: &Self::Manager<'id>,
+        edge: &EdgeOfFunc<'id, Self>,
+    ) -> Vec<LevelNo>
+    where
+        INodeOfFunc<'id, Self>: HasLevel,
+    {
+        fn inner<M: Manager>(manager: &M, e: &M::Edge, set: &mut M::NodeSet, occurs: &mut [bool])
+        where
+            M::InnerNode: HasLevel,
+        {
+            if set.insert(e) {
+                if let Node::Inner(node) = manager.get_node(e) {
+                    occurs[node.level() as usize] = true;
+                    for e in node.children() {
+                        inner(manager, &*e, set, occurs)
+                    }
+                }
+            }
+        }
+
+        let mut occurs = vec![false; manager.num_levels() as usize];
+        inner(manager, edge, &mut Default::default(), &mut occurs);
+        (0..)
+            .zip(occurs)
+            .filter_map(|(level, occurs)| occurs.then_some(level))
+            .collect()
+    }
 }
 
 /// Substitution extension for [`Function`]
@@ -187,7 +279,7 @@ pub trait FunctionSubst: Function {
         if substitution.pairs().len() == 0 {
             return Ok(self.clone());
         }
-        self.with_manager_shared(|manager, edge| {
+        self.with_manager_shared_restart(|manager, edge| {
             Ok(Self::from_edge(
                 manager,
                 Self::substitute_edge(
@@ -219,6 +311,13 @@ pub trait FunctionSubst: Function {
 /// [`Self::not()`], [`Self::and()`], and [`Self::or()`]. As an implementor, it
 /// suffices to implement the functions operating on edges.
 pub trait BooleanFunction: Function {
+    /// Valuation of the variables whose levels are skipped on a path to a
+    /// satisfying terminal
+    ///
+    /// In BDDs and BCDDs, these variables are "don't cares". In ZBDDs, they are
+    /// false.
+    const SKIPPED_VAR: OptBool = OptBool::None;
+
     /// Get the always false function `⊥`
     fn f<'id>(manager: &Self::Manager<'id>) -> Self {
         Self::from_edge(manager, Self::f_edge(manager))
@@ -232,6 +331,55 @@ pub trait BooleanFunction: Function {
     /// variable is true. This adds a new level to a decision diagram.
     fn new_var<'id>(manager: &mut Self::Manager<'id>) -> AllocResult<Self>;
 
+    /// Get the conjunction of `literals`
+    ///
+    /// Each literal is given as a pair of the variable's level number and its
+    /// polarity (`true` for `x`, `false` for `¬x`). The order of `literals` is
+    /// irrelevant. If a variable occurs in both polarities, the result is `⊥`.
+    ///
+    /// In contrast to a chain of [`Self::and()`] calls, the decision diagram is
+    /// built in a single bottom-up pass. For BDDs, this pass does not consult
+    /// the apply cache. For ZBDDs, the "don't care" chains below the literals
+    /// are taken from the manager's tautology cache. The pass is not aborted
+    /// if a reordering is requested, so this never fails spuriously when
+    /// called inside a manager closure with automatic reordering enabled.
+    ///
+    /// **Level numbers are positions, not variables.** A level number only
+    /// identifies a variable with respect to the variable order at the time of
+    /// the call. Reordering (explicitly via [`Manager::reorder()`] or
+    /// automatically during some other operation) moves variables to other
+    /// levels, so a level number obtained before a reordering may denote a
+    /// different variable afterwards. Always determine the levels (e.g., via
+    /// [`HasLevel::level()`] on the variables' nodes) in the same manager
+    /// closure in which this function is called, and do not store them across
+    /// operations that may reorder.
+    ///
+    /// All level numbers must be less than the number of levels.
+    fn cube<'id>(manager: &Self::Manager<'id>, literals: &[(LevelNo, bool)]) -> AllocResult<Self> {
+        Ok(Self::from_edge(
+            manager,
+            Self::cube_edge(manager, literals)?,
+        ))
+    }
+
+    /// Get the disjunction of `literals`
+    ///
+    /// This is the dual of [`Self::cube()`]: If a variable occurs in both
+    /// polarities, the result is `⊤`.
+    ///
+    /// As for [`Self::cube()`], the level numbers in `literals` are positions
+    /// in the current variable order and only valid until the next reordering.
+    /// Neither function aborts if a reordering is requested.
+    fn clause<'id>(
+        manager: &Self::Manager<'id>,
+        literals: &[(LevelNo, bool)],
+    ) -> AllocResult<Self> {
+        Ok(Self::from_edge(
+            manager,
+            Self::clause_edge(manager, literals)?,
+        ))
+    }
+
     /// Get the cofactors `(f_true, f_false)` of `self`
     ///
     /// Let f(x₀, …, xₙ) be represented by `self`, where x₀ is (currently) the
@@ -301,7 +449,7 @@ pub trait BooleanFunction: Function {
     ///
     /// Locking behavior: acquires the manager's lock for shared access.
     fn not(&self) -> AllocResult<Self> {
-        self.with_manager_shared(|manager, edge| {
+        self.with_manager_shared_restart(|manager, edge| {
             Ok(Self::from_edge(manager, Self::not_edge(manager, edge)?))
         })
     }
@@ -321,7 +469,7 @@ pub trait BooleanFunction: Function {
     ///
     /// Panics if `self` and `rhs` don't belong to the same manager.
     fn and(&self, rhs: &Self) -> AllocResult<Self> {
-        self.with_manager_shared(|manager, lhs| {
+        self.with_manager_shared_restart(|manager, lhs| {
             let e = Self::and_edge(manager, lhs, rhs.as_edge(manager))?;
             Ok(Self::from_edge(manager, e))
         })
@@ -332,7 +480,7 @@ pub trait BooleanFunction: Function {
     ///
     /// Panics if `self` and `rhs` don't belong to the same manager.
     fn or(&self, rhs: &Self) -> AllocResult<Self> {
-        self.with_manager_shared(|manager, lhs| {
+        self.with_manager_shared_restart(|manager, lhs| {
             let e = Self::or_edge(manager, lhs, rhs.as_edge(manager))?;
             Ok(Self::from_edge(manager, e))
         })
@@ -343,7 +491,7 @@ pub trait BooleanFunction: Function {
     ///
     /// Panics if `self` and `rhs` don't belong to the same manager.
     fn nand(&self, rhs: &Self) -> AllocResult<Self> {
-        self.with_manager_shared(|manager, lhs| {
+        self.with_manager_shared_restart(|manager, lhs| {
             let e = Self::nand_edge(manager, lhs, rhs.as_edge(manager))?;
             Ok(Self::from_edge(manager, e))
         })
@@ -354,7 +502,7 @@ pub trait BooleanFunction: Function {
     ///
     /// Panics if `self` and `rhs` don't belong to the same manager.
     fn nor(&self, rhs: &Self) -> AllocResult<Self> {
-        self.with_manager_shared(|manager, lhs| {
+        self.with_manager_shared_restart(|manager, lhs| {
             let e = Self::nor_edge(manager, lhs, rhs.as_edge(manager))?;
             Ok(Self::from_edge(manager, e))
         })
@@ -365,7 +513,7 @@ pub trait BooleanFunction: Function {
     ///
     /// Panics if `self` and `rhs` don't belong to the same manager.
     fn xor(&self, rhs: &Self) -> AllocResult<Self> {
-        self.with_manager_shared(|manager, lhs| {
+        self.with_manager_shared_restart(|manager, lhs| {
             let e = Self::xor_edge(manager, lhs, rhs.as_edge(manager))?;
             Ok(Self::from_edge(manager, e))
         })
@@ -376,7 +524,7 @@ pub trait BooleanFunction: Function {
     ///
     /// Panics if `self` and `rhs` don't belong to the same manager.
     fn equiv(&self, rhs: &Self) -> AllocResult<Self> {
-        self.with_manager_shared(|manager, lhs| {
+        self.with_manager_shared_restart(|manager, lhs| {
             let e = Self::equiv_edge(manager, lhs, rhs.as_edge(manager))?;
             Ok(Self::from_edge(manager, e))
         })
@@ -387,7 +535,7 @@ pub trait BooleanFunction: Function {
     ///
     /// Panics if `self` and `rhs` don't belong to the same manager.
     fn imp(&self, rhs: &Self) -> AllocResult<Self> {
-        self.with_manager_shared(|manager, lhs| {
+        self.with_manager_shared_restart(|manager, lhs| {
             let e = Self::imp_edge(manager, lhs, rhs.as_edge(manager))?;
             Ok(Self::from_edge(manager, e))
         })
@@ -398,7 +546,7 @@ pub trait BooleanFunction: Function {
     ///
     /// Panics if `self` and `rhs` don't belong to the same manager.
     fn imp_strict(&self, rhs: &Self) -> AllocResult<Self> {
-        self.with_manager_shared(|manager, lhs| {
+        self.with_manager_shared_restart(|manager, lhs| {
             let e = Self::imp_strict_edge(manager, lhs, rhs.as_edge(manager))?;
             Ok(Self::from_edge(manager, e))
         })
@@ -409,6 +557,21 @@ pub trait BooleanFunction: Function {
     /// Get the always true function `⊤` as edge
     fn t_edge<'id>(manager: &Self::Manager<'id>) -> EdgeOfFunc<'id, Self>;
 
+    /// Get the conjunction of `literals`, edge version
+    ///
+    /// See [`Self::cube()`] for more details.
+    fn cube_edge<'id>(
+        manager: &Self::Manager<'id>,
+        literals: &[(LevelNo, bool)],
+    ) -> AllocResult<EdgeOfFunc<'id, Self>>;
+    /// Get the disjunction of `literals`, edge version
+    ///
+    /// See [`Self::clause()`] for more details.
+    fn clause_edge<'id>(
+        manager: &Self::Manager<'id>,
+        literals: &[(LevelNo, bool)],
+    ) -> AllocResult<EdgeOfFunc<'id, Self>>;
+
     /// Get the cofactors `(f_true, f_false)` of `f`, edge version
     ///
     /// Returns `None` iff `f` references a terminal node. For more details on
@@ -558,7 +721,7 @@ pub trait BooleanFunction: Function {
     /// Panics if `self`, `then_case`, and `else_case` don't belong to the same
     /// manager.
     fn ite(&self, then_case: &Self, else_case: &Self) -> AllocResult<Self> {
-        self.with_manager_shared(|manager, if_edge| {
+        self.with_manager_shared_restart(|manager, if_edge| {
             let then_edge = then_case.as_edge(manager);
             let else_edge = else_case.as_edge(manager);
             let res = Self::ite_edge(manager, if_edge, then_edge, else_edge)?;
@@ -583,6 +746,27 @@ pub trait BooleanFunction: Function {
         Self::or_edge(manager, &*f, &*g)
     }
 
+    /// Compute the support of `self` as a cube, i.e., the conjunction of all
+    /// variables `self` depends on
+    ///
+    /// The result can be used as variable set for quantification.
+    ///
+    /// Locking behavior: acquires the manager's lock for shared access.
+    fn support(&self) -> AllocResult<Self> {
+        self.with_manager_shared_restart(|manager, edge| {
+            Ok(Self::from_edge(manager, Self::support_edge(manager, edge)?))
+        })
+    }
+
+    /// Compute the support of `edge` as a cube, edge version
+    ///
+    /// See [`Self::support()`] for more details.
+    #[must_use]
+    fn support_edge<'id>(
+        manager: &Self::Manager<'id>,
+        edge: &EdgeOfFunc<'id, Self>,
+    ) -> AllocResult<EdgeOfFunc<'id, Self>>;
+
     /// Count the number of satisfying assignments, assuming `vars` input
     /// variables
     ///
@@ -612,6 +796,76 @@ pub trait BooleanFunction: Function {
         cache: &mut SatCountCache<N, S>,
     ) -> N;
 
+    /// Compute the weighted model count of this function
+    ///
+    /// `weights` maps each level to the pair of weights for the positive and
+    /// the negative literal of the variable at that level. It is called once
+    /// for every level of the manager. The weight of an assignment is the
+    /// product of its literal weights, and the result is the sum of the
+    /// weights of all satisfying assignments, where sums and products are
+    /// taken in the semiring `W` (see [`WeightedCountNumber`]). If all positive
+    /// and negative weights sum up to one, this is the probability of the
+    /// function evaluating to true.
+    ///
+    /// The `cache` can be used to speed up multiple queries for functions in
+    /// the same decision diagram using the same weights. Similar to
+    /// [`Self::sat_count()`], the cache is invalidated in case of reordering,
+    /// but it is the caller's responsibility to not use it for different
+    /// weights or managers.
+    ///
+    /// Locking behavior: acquires the manager's lock for shared access.
+    fn weighted_sat_count<W: WeightedCountNumber, S: std::hash::BuildHasher>(
+        &self,
+        weights: impl Fn(LevelNo) -> (W, W),
+        cache: &mut WeightedSatCountCache<W, S>,
+    ) -> W {
+        self.with_manager_shared(|manager, edge| {
+            Self::weighted_sat_count_edge(manager, edge, weights, cache)
+        })
+    }
+
+    /// `Edge` version of [`Self::weighted_sat_count()`]
+    fn weighted_sat_count_edge<'id, W: WeightedCountNumber, S: std::hash::BuildHasher>(
+        manager: &Self::Manager<'id>,
+        edge: &EdgeOfFunc<'id, Self>,
+        weights: impl Fn(LevelNo) -> (W, W),
+        cache: &mut WeightedSatCountCache<W, S>,
+    ) -> W;
+
+    /// Compute the probability that this function evaluates to true if the
+    /// variable at each level `i` is independently true with probability
+    /// `p(i)`
+    ///
+    /// `p` is called once for every level of the manager. This is a special
+    /// case of [`Self::weighted_sat_count()`] with the weights `p(i)` and
+    /// `1 - p(i)`. The intermediate results are memoized per node, but only
+    /// for the duration of a single call: Every call starts with a fresh
+    /// [`WeightedSatCountCache`]. To reuse the results across calls with the
+    /// same `p`, use [`Self::weighted_sat_count()`] with a cache of your own.
+    ///
+    /// Locking behavior: acquires the manager's lock for shared access.
+    fn probability(&self, p: impl Fn(LevelNo) -> f64) -> f64 {
+        self.with_manager_shared(|manager, edge| Self::probability_edge(manager, edge, p))
+    }
+
+    /// `Edge` version of [`Self::probability()`]
+    ///
+    /// Like [`Self::probability()`], this memoizes intermediate results within
+    /// a single call only.
+    fn probability_edge<'id>(
+        manager: &Self::Manager<'id>,
+        edge: &EdgeOfFunc<'id, Self>,
+        p: impl Fn(LevelNo) -> f64,
+    ) -> f64 {
+        let mut cache: WeightedSatCountCache<f64, std::collections::hash_map::RandomState> =
+            Default::default();
+        let weights = |level| {
+            let p = p(level);
+            (p, 1.0 - p)
+        };
+        Self::weighted_sat_count_edge(manager, edge, weights, &mut cache)
+    }
+
     /// Pick a cube of this function
     ///
     /// `order` is a list of variables. If it is non-empty, it must contain as
@@ -711,6 +965,158 @@ pub trait BooleanFunction: Function {
         })
     }
 
+    /// Iterate over the cubes of this function
+    ///
+    /// Each cube corresponds to a path from the root to a satisfying terminal.
+    /// The i-th entry of a cube indicates if the variable currently at the
+    /// i-th level is true, false, or "don't care". The cubes are pairwise
+    /// disjoint, so together, they describe every satisfying assignment
+    /// exactly once.
+    ///
+    /// The iterator is lazy: It only stores the current path and acquires the
+    /// manager's lock for shared access in each call to
+    /// [`Iterator::next()`]. Reordering the variables while iterating yields
+    /// unspecified (but safe) results.
+    fn cubes(&self) -> Cubes<Self>
+    where
+        for<'id> INodeOfFunc<'id, Self>: HasLevel,
+    {
+        Cubes::new(self)
+    }
+
+    /// Iterate over the satisfying assignments of this function
+    ///
+    /// The i-th entry of an assignment is the value of the i-th variable in
+    /// `vars`. Every variable must refer to an inner node (for ZBDDs, use the
+    /// singleton sets). This is [`Self::cubes()`] with the "don't cares"
+    /// expanded, so the assignments are pairwise distinct if `vars` contains
+    /// all variables of the support of `self`.
+    ///
+    /// Like [`Self::cubes()`], the iterator is lazy.
+    fn assignments<'a>(&self, vars: impl IntoIterator<Item = &'a Self>) -> Assignments<Self>
+    where
+        Self: 'a,
+        for<'id> INodeOfFunc<'id, Self>: HasLevel,
+    {
+        Assignments::new(self, vars)
+    }
+
+    /// Compute a satisfying assignment of minimal cost
+    ///
+    /// `costs` maps each level to the pair of costs for the positive and the
+    /// negative literal of the variable at that level. It is called once for
+    /// every level of the manager. The cost of an assignment is the sum of its
+    /// literal costs.
+    ///
+    /// Returns `None` if the function is unsatisfiable. Otherwise, the result
+    /// is a minimum-cost satisfying assignment, where the i-th entry is the
+    /// value of the variable currently at the i-th level, along with its cost.
+    /// This is a single bottom-up pass over the decision diagram.
+    ///
+    /// Locking behavior: acquires the manager's lock for shared access.
+    fn min_cost_sat<C: CostNumber>(
+        &self,
+        costs: impl Fn(LevelNo) -> (C, C),
+    ) -> Option<(Vec<bool>, C)>
+    where
+        for<'id> INodeOfFunc<'id, Self>: HasLevel,
+    {
+        self.with_manager_shared(|manager, edge| Self::min_cost_sat_edge(manager, edge, costs))
+    }
+
+    /// `Edge` version of [`Self::min_cost_sat()`]
+    fn min_cost_sat_edge<'id, C: CostNumber>(
+        manager: &Self::Manager<'id>,
+        edge: &EdgeOfFunc<'id, Self>,
+        costs: impl Fn(LevelNo) -> (C, C),
+    ) -> Option<(Vec<bool>, C)>
+    where
+        INodeOfFunc<'id, Self>: HasLevel,
+    {
+        crate::util::min_cost_sat::<Self, C>(manager, edge, costs)
+    }
+
+    /// Draw a satisfying assignment at random, with probability proportional
+    /// to the product of its literal weights
+    ///
+    /// `weights` maps each level to the pair of weights for the positive and
+    /// the negative literal of the variable at that level. It is called once
+    /// for every level of the manager. The weights must be non-negative, and
+    /// the two weights of a level must not both be zero. With all weights
+    /// being `(1.0, 1.0)`, the assignment is drawn uniformly.
+    ///
+    /// Returns `None` if there is no satisfying assignment of positive weight.
+    /// Otherwise, the i-th entry of the result is the value of the variable
+    /// currently at the i-th level. To draw many assignments, use
+    /// [`Self::sample_n()`], which needs to preprocess the decision diagram
+    /// only once.
+    ///
+    /// Locking behavior: acquires the manager's lock for shared access.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a weight returned by `weights` is negative or NaN, or if both
+    /// weights of a level are zero.
+    #[cfg(feature = "rand")]
+    fn sample<R: rand::Rng + ?Sized>(
+        &self,
+        rng: &mut R,
+        weights: impl Fn(LevelNo) -> (f64, f64),
+    ) -> Option<Vec<bool>>
+    where
+        for<'id> INodeOfFunc<'id, Self>: HasLevel,
+    {
+        self.sample_n(rng, weights, 1).pop()
+    }
+
+    /// Draw `n` satisfying assignments independently at random, with
+    /// probabilities proportional to the products of their literal weights
+    ///
+    /// See [`Self::sample()`] for more details. The weighted model counts of
+    /// the nodes are computed once, and afterwards, each sample takes time
+    /// linear in the number of levels. Returns an empty vector if there is no
+    /// satisfying assignment of positive weight.
+    ///
+    /// Locking behavior: acquires the manager's lock for shared access.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a weight returned by `weights` is negative or NaN, or if both
+    /// weights of a level are zero.
+    #[cfg(feature = "rand")]
+    fn sample_n<R: rand::Rng + ?Sized>(
+        &self,
+        rng: &mut R,
+        weights: impl Fn(LevelNo) -> (f64, f64),
+        n: usize,
+    ) -> Vec<Vec<bool>>
+    where
+        for<'id> INodeOfFunc<'id, Self>: HasLevel,
+    {
+        self.with_manager_shared(|manager, edge| {
+            Self::sample_n_edge(manager, edge, rng, weights, n)
+        })
+    }
+
+    /// `Edge` version of [`Self::sample_n()`]
+    ///
+    /// # Panics
+    ///
+    /// Panics under the same conditions as [`Self::sample_n()`].
+    #[cfg(feature = "rand")]
+    fn sample_n_edge<'id, R: rand::Rng + ?Sized>(
+        manager: &Self::Manager<'id>,
+        edge: &EdgeOfFunc<'id, Self>,
+        rng: &mut R,
+        weights: impl Fn(LevelNo) -> (f64, f64),
+        n: usize,
+    ) -> Vec<Vec<bool>>
+    where
+        INodeOfFunc<'id, Self>: HasLevel,
+    {
+        crate::util::sample_n::<Self, R>(manager, edge, rng, weights, n)
+    }
+
     /// Evaluate this Boolean function
     ///
     /// `args` determines the valuation for all variables. Missing values are
@@ -739,6 +1145,46 @@ pub trait BooleanFunction: Function {
     ) -> bool;
 }
 
+/// Binary operators on Boolean functions
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub enum BooleanOperator {
+    /// Conjunction `lhs ∧ rhs`
+    And,
+    /// Disjunction `lhs ∨ rhs`
+    Or,
+    /// Exclusive disjunction `lhs ⊕ rhs`
+    Xor,
+    /// Equivalence `lhs ↔ rhs`
+    Equiv,
+    /// Negated conjunction `lhs ⊼ rhs`
+    Nand,
+    /// Negated disjunction `lhs ⊽ rhs`
+    Nor,
+    /// Implication `lhs → rhs`
+    Imp,
+    /// Strict implication `lhs < rhs`
+    ImpStrict,
+}
+
+/// Apply `op` to `lhs` and `rhs` using the respective method of `B`
+fn apply_bin_edge<'id, B: BooleanFunction>(
+    manager: &B::Manager<'id>,
+    op: BooleanOperator,
+    lhs: &EdgeOfFunc<'id, B>,
+    rhs: &EdgeOfFunc<'id, B>,
+) -> AllocResult<EdgeOfFunc<'id, B>> {
+    match op {
+        BooleanOperator::And => B::and_edge(manager, lhs, rhs),
+        BooleanOperator::Or => B::or_edge(manager, lhs, rhs),
+        BooleanOperator::Xor => B::xor_edge(manager, lhs, rhs),
+        BooleanOperator::Equiv => B::equiv_edge(manager, lhs, rhs),
+        BooleanOperator::Nand => B::nand_edge(manager, lhs, rhs),
+        BooleanOperator::Nor => B::nor_edge(manager, lhs, rhs),
+        BooleanOperator::Imp => B::imp_edge(manager, lhs, rhs),
+        BooleanOperator::ImpStrict => B::imp_strict_edge(manager, lhs, rhs),
+    }
+}
+
 /// Quantification extension for [`BooleanFunction`]
 pub trait BooleanFunctionQuant: BooleanFunction {
     /// Restrict a set of `vars` to constant values
@@ -751,12 +1197,248 @@ pub trait BooleanFunctionQuant: BooleanFunction {
     ///
     /// Panics if `self` and `vars` don't belong to the same manager.
     fn restrict(&self, vars: &Self) -> AllocResult<Self> {
-        self.with_manager_shared(|manager, root| {
+        self.with_manager_shared_restart(|manager, root| {
             let e = Self::restrict_edge(manager, root, vars.as_edge(manager))?;
             Ok(Self::from_edge(manager, e))
         })
     }
 
+    /// Compute the generalized cofactor `self↓care` (Coudert and Madre's
+    /// `constrain` operator)
+    ///
+    /// The result agrees with `self` on all valuations satisfying `care`. Any
+    /// other valuation is mapped to the satisfying valuation of `care` that is
+    /// closest with respect to the variable order. In contrast to
+    /// [`Self::minimize()`], this makes `constrain` distribute over the binary
+    /// connectives, e.g., `(f ∧ g)↓c ≡ (f↓c) ∧ (g↓c)`. The result may be
+    /// larger than `self`, though. If `care` is `⊥`, the result is `⊥`.
+    ///
+    /// Locking behavior: acquires the manager's lock for shared access.
+    ///
+    /// Panics if `self` and `care` don't belong to the same manager.
+    fn constrain(&self, care: &Self) -> AllocResult<Self> {
+        self.with_manager_shared_restart(|manager, root| {
+            let e = Self::constrain_edge(manager, root, care.as_edge(manager))?;
+            Ok(Self::from_edge(manager, e))
+        })
+    }
+
+    /// Heuristically minimize `self` with respect to the care set `care`
+    /// (Coudert and Madre's `restrict` operator)
+    ///
+    /// The result agrees with `self` on all valuations satisfying `care`.
+    /// Unlike [`Self::constrain()`], variables that `self` does not depend on
+    /// are quantified out of `care` existentially, so the result never
+    /// contains variables not present in `self`. Usually, but not always, the
+    /// result is smaller than `self`. If `care` is `⊥`, the result is `⊥`.
+    ///
+    /// Locking behavior: acquires the manager's lock for shared access.
+    ///
+    /// Panics if `self` and `care` don't belong to the same manager.
+    fn minimize(&self, care: &Self) -> AllocResult<Self> {
+        self.with_manager_shared_restart(|manager, root| {
+            let e = Self::minimize_edge(manager, root, care.as_edge(manager))?;
+            Ok(Self::from_edge(manager, e))
+        })
+    }
+
+    /// Minimize `self` with respect to the care set `care` using safe
+    /// leaf-identifying compaction (Hong et al., 1997)
+    ///
+    /// The result agrees with `self` on all valuations satisfying `care`. In
+    /// contrast to [`Self::minimize()`], the number of nodes of the result is
+    /// guaranteed not to exceed the one of `self`.
+    ///
+    /// Locking behavior: acquires the manager's lock for shared access.
+    ///
+    /// Panics if `self` and `care` don't belong to the same manager.
+    fn li_compaction(&self, care: &Self) -> AllocResult<Self> {
+        self.with_manager_shared_restart(|manager, root| {
+            let e = Self::li_compaction_edge(manager, root, care.as_edge(manager))?;
+            Ok(Self::from_edge(manager, e))
+        })
+    }
+
+    /// Compute an irredundant sum-of-products cover of the incompletely
+    /// specified function given by the interval `[lower, upper]`
+    /// (Minato–Morreale algorithm)
+    ///
+    /// `lower` is the on-set and `¬upper` the off-set, so `lower` must imply
+    /// `upper`. The result is a function `f` with `lower → f` and `f → upper`
+    /// along with an irredundant cover of `f`, i.e., a list of cubes whose
+    /// disjunction is `f` and from which no cube can be removed. Like the
+    /// items of [`BooleanFunction::cubes()`], the i-th entry of a cube
+    /// indicates if the variable currently at the i-th level is true, false,
+    /// or "don't care".
+    ///
+    /// Locking behavior: acquires the manager's lock for shared access.
+    ///
+    /// Panics if `lower` and `upper` don't belong to the same manager.
+    fn isop(lower: &Self, upper: &Self) -> AllocResult<(Self, Vec<Vec<OptBool>>)>
+    where
+        for<'id> INodeOfFunc<'id, Self>: HasLevel,
+    {
+        lower.with_manager_shared_restart(|manager, lower| {
+            let upper = upper.as_edge(manager);
+            let f = Self::from_edge(manager, Self::isop_edge(manager, lower, upper)?);
+            let mut cube = vec![OptBool::None; manager.num_levels() as usize];
+            let mut cubes = Vec::new();
+            crate::util::isop_cubes::<Self>(manager, lower, upper, &mut cube, &mut cubes)?;
+            Ok((f, cubes))
+        })
+    }
+
+    /// Like [`Self::isop()`], but return the cover as a ZBDD (or some other
+    /// [`BooleanVecSet`]) in the manager referenced by `cover_manager`
+    ///
+    /// The cover is a family of sets of literals: The positive literal of the
+    /// variable at level `i` is represented by the element at level `2i`, the
+    /// negative literal by the element at level `2i + 1`. Hence, the manager
+    /// of the cover must have at least twice as many levels as the manager of
+    /// `lower` and `upper`. The cover is built directly during the recursion,
+    /// without enumerating its cubes.
+    ///
+    /// Locking behavior: acquires the manager's lock for shared access, and
+    /// while holding it, the lock of `cover_manager` for shared access. The
+    /// computation is restarted after a reordering of either manager.
+    ///
+    /// Panics if `lower` and `upper` don't belong to the same manager.
+    fn isop_cover<S: BooleanVecSet>(
+        lower: &Self,
+        upper: &Self,
+        cover_manager: &S::ManagerRef,
+    ) -> AllocResult<(Self, S)>
+    where
+        for<'id> INodeOfFunc<'id, Self>: HasLevel,
+    {
+        crate::util::restart_for_cover(cover_manager, || {
+            lower.with_manager_shared_restart(|manager, lower| {
+                let upper = upper.as_edge(manager);
+                let f = Self::from_edge(manager, Self::isop_edge(manager, lower, upper)?);
+                let cover = cover_manager.with_manager_shared(|cover_manager| {
+                    let e =
+                        crate::util::isop_cover::<Self, S>(manager, cover_manager, lower, upper)?;
+                    Ok(S::from_edge(cover_manager, e))
+                })?;
+                Ok((f, cover))
+            })
+        })
+    }
+
+    /// Compute the set of prime implicants of `self` (Coudert–Madre algorithm)
+    ///
+    /// An implicant of `self` is a cube implying `self`. It is prime if no
+    /// literal can be removed from it without losing this property. The
+    /// result is a family of sets of literals in the manager referenced by
+    /// `cover_manager`, using the same encoding as [`Self::isop_cover()`]:
+    /// The positive literal of the variable at level `i` is represented by the
+    /// element at level `2i`, the negative literal by the element at level
+    /// `2i + 1`. Due to the sharing in a ZBDD, this representation is usually
+    /// much more compact than an explicit list of the prime implicants.
+    ///
+    /// Locking behavior: acquires the manager's lock for shared access, and
+    /// while holding it, the lock of `cover_manager` for shared access. If
+    /// either manager requests a reordering, the computation is restarted
+    /// after the reordering.
+    fn prime_implicants<S: BooleanVecSet>(&self, cover_manager: &S::ManagerRef) -> AllocResult<S>
+    where
+        for<'id> INodeOfFunc<'id, Self>: HasLevel,
+    {
+        crate::util::restart_for_cover(cover_manager, || {
+            self.with_manager_shared_restart(|manager, f| {
+                cover_manager.with_manager_shared(|cover_manager| {
+                    let e = crate::util::prime_implicants::<Self, S>(manager, cover_manager, f)?;
+                    Ok(S::from_edge(cover_manager, e))
+                })
+            })
+        })
+    }
+
+    /// Compute the literals implied by `self`
+    ///
+    /// The i-th entry of the result is `True` if `self` implies the positive
+    /// literal of the variable at level `i`, `False` if `self` implies the
+    /// negative literal, and `None` otherwise. Hence, the literals given by
+    /// the result are contained in every implicant of `self`. If `self` is
+    /// `⊥`, all entries are `None`.
+    ///
+    /// Locking behavior: acquires the manager's lock for shared access.
+    fn essential_literals(&self) -> AllocResult<Vec<OptBool>> {
+        crate::util::essential_literals(self)
+    }
+
+    /// Compute the unateness of `self` in each variable
+    ///
+    /// The i-th entry of the result refers to the variable at level `i`.
+    ///
+    /// Locking behavior: acquires the manager's lock for shared access.
+    fn unateness(&self) -> AllocResult<Vec<Unateness>> {
+        crate::util::unateness(self)
+    }
+
+    /// Compute the Boolean difference `f|x=1 ⊕ f|x=0` of `self` with respect
+    /// to the variable `var`
+    ///
+    /// This is the unique quantification over `var`, see [`Self::unique()`].
+    ///
+    /// Locking behavior: acquires the manager's lock for shared access.
+    ///
+    /// Panics if `self` and `var` don't belong to the same manager.
+    fn boolean_difference(&self, var: &Self) -> AllocResult<Self> {
+        self.unique(var)
+    }
+
+    /// Compute the influence of the variable `var` on `self`
+    ///
+    /// The influence is the probability that flipping `var` changes the value
+    /// of `self` under a uniformly random valuation of the variables. It is
+    /// computed via weighted model counting on the
+    /// [Boolean difference][Self::boolean_difference()].
+    ///
+    /// Locking behavior: acquires the manager's lock for shared access
+    /// (multiple times).
+    ///
+    /// Panics if `self` and `var` don't belong to the same manager.
+    fn influence(&self, var: &Self) -> AllocResult<f64> {
+        crate::util::influence(self, var)
+    }
+
+    /// Compute the (average) sensitivity of `self`
+    ///
+    /// This is the expected number of variables whose flip changes the value
+    /// of `self` under a uniformly random valuation, i.e., the sum of the
+    /// [influences][Self::influence()] of all variables. It is computed from
+    /// [`Self::banzhaf_values()`].
+    ///
+    /// Locking behavior: acquires the manager's lock for shared access.
+    fn sensitivity(&self) -> AllocResult<f64>
+    where
+        for<'id> INodeOfFunc<'id, Self>: HasLevel,
+    {
+        Ok(self.banzhaf_values()?.iter().sum())
+    }
+
+    /// Compute the Banzhaf values of all variables in a single pass over the
+    /// decision diagram
+    ///
+    /// The i-th entry of the result is the Banzhaf value of the variable `x`
+    /// at level `i`, i.e., its [influence][Self::influence()]
+    /// `Pr[f|x=1 ≠ f|x=0]` under uniformly random valuations. In contrast to
+    /// calling [`Self::influence()`] for every variable, this does not compute
+    /// the Boolean differences with respect to the whole function. Instead, it
+    /// weights the difference of the cofactors of every node by the
+    /// probability to reach the node.
+    ///
+    /// Locking behavior: acquires the manager's lock for shared access.
+    fn banzhaf_values(&self) -> AllocResult<Vec<f64>>
+    where
+        for<'id> INodeOfFunc<'id, Self>: HasLevel,
+    {
+        self.with_manager_shared_restart(|manager, edge| {
+            crate::util::banzhaf_values::<Self>(manager, edge)
+        })
+    }
+
     /// Compute the universal quantification over `vars`
     ///
     /// `vars` is a set of variables, which in turn is just the conjunction of
@@ -769,7 +1451,7 @@ pub trait BooleanFunctionQuant: BooleanFunction {
     ///
     /// Panics if `self` and `vars` don't belong to the same manager.
     fn forall(&self, vars: &Self) -> AllocResult<Self> {
-        self.with_manager_shared(|manager, root| {
+        self.with_manager_shared_restart(|manager, root| {
             let e = Self::forall_edge(manager, root, vars.as_edge(manager))?;
             Ok(Self::from_edge(manager, e))
         })
@@ -787,7 +1469,7 @@ pub trait BooleanFunctionQuant: BooleanFunction {
     ///
     /// Panics if `self` and `vars` don't belong to the same manager.
     fn exist(&self, vars: &Self) -> AllocResult<Self> {
-        self.with_manager_shared(|manager, root| {
+        self.with_manager_shared_restart(|manager, root| {
             let e = Self::exist_edge(manager, root, vars.as_edge(manager))?;
             Ok(Self::from_edge(manager, e))
         })
@@ -810,12 +1492,89 @@ pub trait BooleanFunctionQuant: BooleanFunction {
     ///
     /// Panics if `self` and `vars` don't belong to the same manager.
     fn unique(&self, vars: &Self) -> AllocResult<Self> {
-        self.with_manager_shared(|manager, root| {
+        self.with_manager_shared_restart(|manager, root| {
             let e = Self::unique_edge(manager, root, vars.as_edge(manager))?;
             Ok(Self::from_edge(manager, e))
         })
     }
 
+    /// Combined application of `op` and existential quantification over `vars`
+    ///
+    /// This is equivalent to `self.<op>(rhs)?.exist(vars)` (e.g.,
+    /// `self.and(rhs)?.exist(vars)` for [`BooleanOperator::And`]), but does
+    /// not construct the intermediate result of `op`, which may be much larger
+    /// than the final result.
+    ///
+    /// Locking behavior: acquires the manager's lock for shared access.
+    ///
+    /// Panics if `self`, `rhs`, and `vars` don't belong to the same manager.
+    fn apply_exist(&self, op: BooleanOperator, rhs: &Self, vars: &Self) -> AllocResult<Self> {
+        self.with_manager_shared_restart(|manager, root| {
+            let e = Self::apply_exist_edge(
+                manager,
+                op,
+                root,
+                rhs.as_edge(manager),
+                vars.as_edge(manager),
+            )?;
+            Ok(Self::from_edge(manager, e))
+        })
+    }
+
+    /// Compute the relational product `∃ vars. self ∧ rhs`
+    ///
+    /// Shorthand for [`Self::apply_exist()`] with [`BooleanOperator::And`].
+    ///
+    /// Locking behavior: acquires the manager's lock for shared access.
+    ///
+    /// Panics if `self`, `rhs`, and `vars` don't belong to the same manager.
+    fn and_exist(&self, rhs: &Self, vars: &Self) -> AllocResult<Self> {
+        self.apply_exist(BooleanOperator::And, rhs, vars)
+    }
+
+    /// Combined application of `op` and universal quantification over `vars`
+    ///
+    /// This is equivalent to `self.<op>(rhs)?.forall(vars)` (e.g.,
+    /// `self.imp(rhs)?.forall(vars)` for [`BooleanOperator::Imp`]), but does
+    /// not construct the intermediate result of `op`.
+    ///
+    /// Locking behavior: acquires the manager's lock for shared access.
+    ///
+    /// Panics if `self`, `rhs`, and `vars` don't belong to the same manager.
+    fn apply_forall(&self, op: BooleanOperator, rhs: &Self, vars: &Self) -> AllocResult<Self> {
+        self.with_manager_shared_restart(|manager, root| {
+            let e = Self::apply_forall_edge(
+                manager,
+                op,
+                root,
+                rhs.as_edge(manager),
+                vars.as_edge(manager),
+            )?;
+            Ok(Self::from_edge(manager, e))
+        })
+    }
+
+    /// Combined application of `op` and unique quantification over `vars`
+    ///
+    /// This is equivalent to `self.<op>(rhs)?.unique(vars)`, but does not
+    /// construct the intermediate result of `op`.
+    ///
+    /// Locking behavior: acquires the manager's lock for shared access.
+    ///
+    /// Panics if `self`, `rhs`, and `vars` don't belong to the same manager.
+    fn apply_unique(&self, op: BooleanOperator, rhs: &Self, vars: &Self) -> AllocResult<Self> {
+        self.with_manager_shared_restart(|manager, root| {
+            let e = Self::apply_unique_edge(
+                manager,
+                op,
+                root,
+                rhs.as_edge(manager),
+                vars.as_edge(manager),
+            )?;
+            Ok(Self::from_edge(manager, e))
+        })
+    }
+
     /// Restrict a set of `vars` to constant values, edge version
     ///
     /// See [`Self::restrict()`] for more details.
@@ -826,6 +1585,50 @@ pub trait BooleanFunctionQuant: BooleanFunction {
         vars: &EdgeOfFunc<'id, Self>,
     ) -> AllocResult<EdgeOfFunc<'id, Self>>;
 
+    /// Compute the generalized cofactor of `root` with respect to `care`, edge
+    /// version
+    ///
+    /// See [`Self::constrain()`] for more details.
+    #[must_use]
+    fn constrain_edge<'id>(
+        manager: &Self::Manager<'id>,
+        root: &EdgeOfFunc<'id, Self>,
+        care: &EdgeOfFunc<'id, Self>,
+    ) -> AllocResult<EdgeOfFunc<'id, Self>>;
+
+    /// Heuristically minimize `root` with respect to `care`, edge version
+    ///
+    /// See [`Self::minimize()`] for more details.
+    #[must_use]
+    fn minimize_edge<'id>(
+        manager: &Self::Manager<'id>,
+        root: &EdgeOfFunc<'id, Self>,
+        care: &EdgeOfFunc<'id, Self>,
+    ) -> AllocResult<EdgeOfFunc<'id, Self>>;
+
+    /// Minimize `root` with respect to `care` using leaf-identifying
+    /// compaction, edge version
+    ///
+    /// See [`Self::li_compaction()`] for more details.
+    #[must_use]
+    fn li_compaction_edge<'id>(
+        manager: &Self::Manager<'id>,
+        root: &EdgeOfFunc<'id, Self>,
+        care: &EdgeOfFunc<'id, Self>,
+    ) -> AllocResult<EdgeOfFunc<'id, Self>>;
+
+    /// Compute the function of an irredundant sum-of-products cover of the
+    /// interval `[lower, upper]`, edge version
+    ///
+    /// See [`Self::isop()`] for more details. If `lower` does not imply
+    /// `upper`, the result is unspecified.
+    #[must_use]
+    fn isop_edge<'id>(
+        manager: &Self::Manager<'id>,
+        lower: &EdgeOfFunc<'id, Self>,
+        upper: &EdgeOfFunc<'id, Self>,
+    ) -> AllocResult<EdgeOfFunc<'id, Self>>;
+
     /// Compute the universal quantification of `root` over `vars`, edge
     /// version
     ///
@@ -857,6 +1660,60 @@ pub trait BooleanFunctionQuant: BooleanFunction {
         root: &EdgeOfFunc<'id, Self>,
         vars: &EdgeOfFunc<'id, Self>,
     ) -> AllocResult<EdgeOfFunc<'id, Self>>;
+
+    /// Combined application of `op` and existential quantification over
+    /// `vars`, edge version
+    ///
+    /// See [`Self::apply_exist()`] for more details. The default
+    /// implementation applies `op` and then quantifies the intermediate
+    /// result.
+    #[must_use]
+    fn apply_exist_edge<'id>(
+        manager: &Self::Manager<'id>,
+        op: BooleanOperator,
+        lhs: &EdgeOfFunc<'id, Self>,
+        rhs: &EdgeOfFunc<'id, Self>,
+        vars: &EdgeOfFunc<'id, Self>,
+    ) -> AllocResult<EdgeOfFunc<'id, Self>> {
+        let tmp = EdgeDropGuard::new(manager, apply_bin_edge::<Self>(manager, op, lhs, rhs)?);
+        Self::exist_edge(manager, &tmp, vars)
+    }
+
+    /// Combined application of `op` and universal quantification over
+    /// `vars`, edge version
+    ///
+    /// See [`Self::apply_forall()`] for more details. The default
+    /// implementation applies `op` and then quantifies the intermediate
+    /// result.
+    #[must_use]
+    fn apply_forall_edge<'id>(
+        manager: &Self::Manager<'id>,
+        op: BooleanOperator,
+        lhs: &EdgeOfFunc<'id, Self>,
+        rhs: &EdgeOfFunc<'id, Self>,
+        vars: &EdgeOfFunc<'id, Self>,
+    ) -> AllocResult<EdgeOfFunc<'id, Self>> {
+        let tmp = EdgeDropGuard::new(manager, apply_bin_edge::<Self>(manager, op, lhs, rhs)?);
+        Self::forall_edge(manager, &tmp, vars)
+    }
+
+    /// Combined application of `op` and unique quantification over `vars`,
+    /// edge version
+    ///
+    /// See [`Self::apply_unique()`] for more details. The default
+    /// implementation applies `op` and then quantifies the intermediate
+    /// result.
+    #[must_use]
+    fn apply_unique_edge<'id>(
+        manager: &Self::Manager<'id>,
+        op: BooleanOperator,
+        lhs: &EdgeOfFunc<'id, Self>,
+        rhs: &EdgeOfFunc<'id, Self>,
+        vars: &EdgeOfFunc<'id, Self>,
+    ) -> AllocResult<EdgeOfFunc<'id, Self>> {
+        let tmp = EdgeDropGuard::new(manager, apply_bin_edge::<Self>(manager, op, lhs, rhs)?);
+        Self::unique_edge(manager, &tmp, vars)
+    }
 }
 
 /// Set of Boolean vectors
@@ -899,6 +1756,34 @@ pub trait BooleanVecSet: Function {
         Self::from_edge(manager, Self::base_edge(manager))
     }
 
+    /// Get the set {`elements`}, i.e., the family containing only the set of
+    /// the variables at the given levels
+    ///
+    /// The order of `elements` is irrelevant, duplicates are ignored. The
+    /// decision diagram is built in a single bottom-up pass without consulting
+    /// any cache.
+    ///
+    /// As for [`BooleanFunction::cube()`], the level numbers are positions in
+    /// the current variable order and only valid until the next reordering,
+    /// and the pass is not aborted if a reordering is requested.
+    ///
+    /// All level numbers must be less than the number of levels.
+    fn set<'id>(manager: &Self::Manager<'id>, elements: &[LevelNo]) -> AllocResult<Self> {
+        Ok(Self::from_edge(manager, Self::set_edge(manager, elements)?))
+    }
+
+    /// Get the family of the given `sets`, where each set is given by the
+    /// level numbers of its elements
+    ///
+    /// This is the union of [`Self::set()`] for all the sets, but the decision
+    /// diagram is built in a single bottom-up pass. Like [`Self::set()`], this
+    /// does not abort if a reordering is requested.
+    ///
+    /// All level numbers must be less than the number of levels.
+    fn family<'id>(manager: &Self::Manager<'id>, sets: &[&[LevelNo]]) -> AllocResult<Self> {
+        Ok(Self::from_edge(manager, Self::family_edge(manager, sets)?))
+    }
+
     /// Get the set of subsets of `self` not containing `var`, formally
     /// `{s ∈ self | var ∉ s}`
     ///
@@ -909,7 +1794,7 @@ pub trait BooleanVecSet: Function {
     ///
     /// Panics if `self` and `var` do not belong to the same manager.
     fn subset0(&self, var: &Self) -> AllocResult<Self> {
-        self.with_manager_shared(|manager, set| {
+        self.with_manager_shared_restart(|manager, set| {
             let e = Self::subset0_edge(manager, set, var.as_edge(manager))?;
             Ok(Self::from_edge(manager, e))
         })
@@ -925,7 +1810,7 @@ pub trait BooleanVecSet: Function {
     ///
     /// Panics if `self` and `var` do not belong to the same manager.
     fn subset1(&self, var: &Self) -> AllocResult<Self> {
-        self.with_manager_shared(|manager, set| {
+        self.with_manager_shared_restart(|manager, set| {
             let e = Self::subset1_edge(manager, set, var.as_edge(manager))?;
             Ok(Self::from_edge(manager, e))
         })
@@ -943,7 +1828,7 @@ pub trait BooleanVecSet: Function {
     ///
     /// Panics if `self` and `var` do not belong to the same manager.
     fn change(&self, var: &Self) -> AllocResult<Self> {
-        self.with_manager_shared(|manager, set| {
+        self.with_manager_shared_restart(|manager, set| {
             let e = Self::change_edge(manager, set, var.as_edge(manager))?;
             Ok(Self::from_edge(manager, e))
         })
@@ -955,7 +1840,7 @@ pub trait BooleanVecSet: Function {
     ///
     /// Panics if `self` and `rhs` do not belong to the same manager.
     fn union(&self, rhs: &Self) -> AllocResult<Self> {
-        self.with_manager_shared(|manager, lhs| {
+        self.with_manager_shared_restart(|manager, lhs| {
             let e = Self::union_edge(manager, lhs, rhs.as_edge(manager))?;
             Ok(Self::from_edge(manager, e))
         })
@@ -967,7 +1852,7 @@ pub trait BooleanVecSet: Function {
     ///
     /// Panics if `self` and `rhs` do not belong to the same manager.
     fn intsec(&self, rhs: &Self) -> AllocResult<Self> {
-        self.with_manager_shared(|manager, lhs| {
+        self.with_manager_shared_restart(|manager, lhs| {
             let e = Self::intsec_edge(manager, lhs, rhs.as_edge(manager))?;
             Ok(Self::from_edge(manager, e))
         })
@@ -979,7 +1864,7 @@ pub trait BooleanVecSet: Function {
     ///
     /// Panics if `self` and `rhs` do not belong to the same manager.
     fn diff(&self, rhs: &Self) -> AllocResult<Self> {
-        self.with_manager_shared(|manager, lhs| {
+        self.with_manager_shared_restart(|manager, lhs| {
             let e = Self::diff_edge(manager, lhs, rhs.as_edge(manager))?;
             Ok(Self::from_edge(manager, e))
         })
@@ -991,6 +1876,20 @@ pub trait BooleanVecSet: Function {
     /// Edge version of [`Self::base()`]
     fn base_edge<'id>(manager: &Self::Manager<'id>) -> EdgeOfFunc<'id, Self>;
 
+    /// Edge version of [`Self::set()`]
+    fn set_edge<'id>(
+        manager: &Self::Manager<'id>,
+        elements: &[LevelNo],
+    ) -> AllocResult<EdgeOfFunc<'id, Self>> {
+        Self::family_edge(manager, &[elements])
+    }
+
+    /// Edge version of [`Self::family()`]
+    fn family_edge<'id>(
+        manager: &Self::Manager<'id>,
+        sets: &[&[LevelNo]],
+    ) -> AllocResult<EdgeOfFunc<'id, Self>>;
+
     /// Edge version of [`Self::subset0()`]
     fn subset0_edge<'id>(
         manager: &Self::Manager<'id>,
@@ -1101,7 +2000,7 @@ pub trait PseudoBooleanFunction: Function {
     ///
     /// Panics if `self` and `rhs` do not belong to the same manager.
     fn add(&self, rhs: &Self) -> AllocResult<Self> {
-        self.with_manager_shared(|manager, lhs| {
+        self.with_manager_shared_restart(|manager, lhs| {
             let e = Self::add_edge(manager, lhs, rhs.as_edge(manager))?;
             Ok(Self::from_edge(manager, e))
         })
@@ -1113,7 +2012,7 @@ pub trait PseudoBooleanFunction: Function {
     ///
     /// Panics if `self` and `rhs` do not belong to the same manager.
     fn sub(&self, rhs: &Self) -> AllocResult<Self> {
-        self.with_manager_shared(|manager, lhs| {
+        self.with_manager_shared_restart(|manager, lhs| {
             let e = Self::sub_edge(manager, lhs, rhs.as_edge(manager))?;
             Ok(Self::from_edge(manager, e))
         })
@@ -1125,7 +2024,7 @@ pub trait PseudoBooleanFunction: Function {
     ///
     /// Panics if `self` and `rhs` do not belong to the same manager.
     fn mul(&self, rhs: &Self) -> AllocResult<Self> {
-        self.with_manager_shared(|manager, lhs| {
+        self.with_manager_shared_restart(|manager, lhs| {
             let e = Self::mul_edge(manager, lhs, rhs.as_edge(manager))?;
             Ok(Self::from_edge(manager, e))
         })
@@ -1137,7 +2036,7 @@ pub trait PseudoBooleanFunction: Function {
     ///
     /// Panics if `self` and `rhs` do not belong to the same manager.
     fn div(&self, rhs: &Self) -> AllocResult<Self> {
-        self.with_manager_shared(|manager, lhs| {
+        self.with_manager_shared_restart(|manager, lhs| {
             let e = Self::div_edge(manager, lhs, rhs.as_edge(manager))?;
             Ok(Self::from_edge(manager, e))
         })
@@ -1149,7 +2048,7 @@ pub trait PseudoBooleanFunction: Function {
     ///
     /// Panics if `self` and `rhs` do not belong to the same manager.
     fn min(&self, rhs: &Self) -> AllocResult<Self> {
-        self.with_manager_shared(|manager, lhs| {
+        self.with_manager_shared_restart(|manager, lhs| {
             let e = Self::min_edge(manager, lhs, rhs.as_edge(manager))?;
             Ok(Self::from_edge(manager, e))
         })
@@ -1161,12 +2060,23 @@ pub trait PseudoBooleanFunction: Function {
     ///
     /// Panics if `self` and `rhs` do not belong to the same manager.
     fn max(&self, rhs: &Self) -> AllocResult<Self> {
-        self.with_manager_shared(|manager, lhs| {
+        self.with_manager_shared_restart(|manager, lhs| {
             let e = Self::max_edge(manager, lhs, rhs.as_edge(manager))?;
             Ok(Self::from_edge(manager, e))
         })
     }
 
+    /// Compute the support of `self` as a cube, i.e., the product of all
+    /// variables `self` depends on (`1` iff all these variables are `1`, `0`
+    /// otherwise)
+    ///
+    /// Locking behavior: acquires a shared manager lock
+    fn support(&self) -> AllocResult<Self> {
+        self.with_manager_shared_restart(|manager, edge| {
+            Ok(Self::from_edge(manager, Self::support_edge(manager, edge)?))
+        })
+    }
+
     /// Get the constant `value`, edge version
     fn constant_edge<'id>(
         manager: &Self::Manager<'id>,
@@ -1214,6 +2124,14 @@ pub trait PseudoBooleanFunction: Function {
         lhs: &EdgeOfFunc<'id, Self>,
         rhs: &EdgeOfFunc<'id, Self>,
     ) -> AllocResult<EdgeOfFunc<'id, Self>>;
+
+    /// Compute the support of `edge` as a cube, edge version
+    ///
+    /// See [`Self::support()`] for more details.
+    fn support_edge<'id>(
+        manager: &Self::Manager<'id>,
+        edge: &EdgeOfFunc<'id, Self>,
+    ) -> AllocResult<EdgeOfFunc<'id, Self>>;
 }
 
 /// Function of three valued logic
@@ -1315,7 +2233,7 @@ pub trait TVLFunction: Function {
     ///
     /// Locking behavior: acquires the manager's lock for shared access.
     fn not(&self) -> AllocResult<Self> {
-        self.with_manager_shared(|manager, edge| {
+        self.with_manager_shared_restart(|manager, edge| {
             Ok(Self::from_edge(manager, Self::not_edge(manager, edge)?))
         })
     }
@@ -1335,7 +2253,7 @@ pub trait TVLFunction: Function {
     ///
     /// Panics if `self` and `rhs` don't belong to the same manager.
     fn and(&self, rhs: &Self) -> AllocResult<Self> {
-        self.with_manager_shared(|manager, lhs| {
+        self.with_manager_shared_restart(|manager, lhs| {
             let e = Self::and_edge(manager, lhs, rhs.as_edge(manager))?;
             Ok(Self::from_edge(manager, e))
         })
@@ -1346,7 +2264,7 @@ pub trait TVLFunction: Function {
     ///
     /// Panics if `self` and `rhs` don't belong to the same manager.
     fn or(&self, rhs: &Self) -> AllocResult<Self> {
-        self.with_manager_shared(|manager, lhs| {
+        self.with_manager_shared_restart(|manager, lhs| {
             let e = Self::or_edge(manager, lhs, rhs.as_edge(manager))?;
             Ok(Self::from_edge(manager, e))
         })
@@ -1357,7 +2275,7 @@ pub trait TVLFunction: Function {
     ///
     /// Panics if `self` and `rhs` don't belong to the same manager.
     fn nand(&self, rhs: &Self) -> AllocResult<Self> {
-        self.with_manager_shared(|manager, lhs| {
+        self.with_manager_shared_restart(|manager, lhs| {
             let e = Self::nand_edge(manager, lhs, rhs.as_edge(manager))?;
             Ok(Self::from_edge(manager, e))
         })
@@ -1368,7 +2286,7 @@ pub trait TVLFunction: Function {
     ///
     /// Panics if `self` and `rhs` don't belong to the same manager.
     fn nor(&self, rhs: &Self) -> AllocResult<Self> {
-        self.with_manager_shared(|manager, lhs| {
+        self.with_manager_shared_restart(|manager, lhs| {
             let e = Self::nor_edge(manager, lhs, rhs.as_edge(manager))?;
             Ok(Self::from_edge(manager, e))
         })
@@ -1379,7 +2297,7 @@ pub trait TVLFunction: Function {
     ///
     /// Panics if `self` and `rhs` don't belong to the same manager.
     fn xor(&self, rhs: &Self) -> AllocResult<Self> {
-        self.with_manager_shared(|manager, lhs| {
+        self.with_manager_shared_restart(|manager, lhs| {
             let e = Self::xor_edge(manager, lhs, rhs.as_edge(manager))?;
             Ok(Self::from_edge(manager, e))
         })
@@ -1390,7 +2308,7 @@ pub trait TVLFunction: Function {
     ///
     /// Panics if `self` and `rhs` don't belong to the same manager.
     fn equiv(&self, rhs: &Self) -> AllocResult<Self> {
-        self.with_manager_shared(|manager, lhs| {
+        self.with_manager_shared_restart(|manager, lhs| {
             let e = Self::equiv_edge(manager, lhs, rhs.as_edge(manager))?;
             Ok(Self::from_edge(manager, e))
         })
@@ -1401,7 +2319,7 @@ pub trait TVLFunction: Function {
     ///
     /// Panics if `self` and `rhs` don't belong to the same manager.
     fn imp(&self, rhs: &Self) -> AllocResult<Self> {
-        self.with_manager_shared(|manager, lhs| {
+        self.with_manager_shared_restart(|manager, lhs| {
             let e = Self::imp_edge(manager, lhs, rhs.as_edge(manager))?;
             Ok(Self::from_edge(manager, e))
         })
@@ -1412,7 +2330,7 @@ pub trait TVLFunction: Function {
     ///
     /// Panics if `self` and `rhs` don't belong to the same manager.
     fn imp_strict(&self, rhs: &Self) -> AllocResult<Self> {
-        self.with_manager_shared(|manager, lhs| {
+        self.with_manager_shared_restart(|manager, lhs| {
             let e = Self::imp_strict_edge(manager, lhs, rhs.as_edge(manager))?;
             Ok(Self::from_edge(manager, e))
         })
@@ -1562,7 +2480,7 @@ pub trait TVLFunction: Function {
     /// Panics if `self`, `then_case`, and `else_case` don't belong to the same
     /// manager.
     fn ite(&self, then_case: &Self, else_case: &Self) -> AllocResult<Self> {
-        self.with_manager_shared(|manager, if_edge| {
+        self.with_manager_shared_restart(|manager, if_edge| {
             let then_edge = then_case.as_edge(manager);
             let else_edge = else_case.as_edge(manager);
             let res = Self::ite_edge(manager, if_edge, then_edge, else_edge)?;
@@ -1586,4 +2504,23 @@ pub trait TVLFunction: Function {
         let g = EdgeDropGuard::new(manager, Self::imp_strict_edge(manager, if_edge, else_edge)?);
         Self::or_edge(manager, &*f, &*g)
     }
+
+    /// Compute the support of `self` as a cube, i.e., the conjunction of all
+    /// variables `self` depends on
+    ///
+    /// Locking behavior: acquires the manager's lock for shared access.
+    fn support(&self) -> AllocResult<Self> {
+        self.with_manager_shared_restart(|manager, edge| {
+            Ok(Self::from_edge(manager, Self::support_edge(manager, edge)?))
+        })
+    }
+
+    /// Compute the support of `edge` as a cube, edge version
+    ///
+    /// See [`Self::support()`] for more details.
+    #[must_use]
+    fn support_edge<'id>(
+        manager: &Self::Manager<'id>,
+        edge: &EdgeOfFunc<'id, Self>,
+    ) -> AllocResult<EdgeOfFunc<'id, Self>>;
 }
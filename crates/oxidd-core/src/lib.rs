@@ -27,6 +27,7 @@ use util::AllocResult;
 use util::Borrowed;
 use util::DropWith;
 use util::NodeSet;
+use util::VarGroups;
 
 pub mod function;
 pub mod util;
@@ -144,6 +145,27 @@ pub trait DiagramRules<E: Edge, N: InnerNode<E>, T> {
     fn cofactor(tag: E::Tag, node: &N, n: usize) -> Borrowed<E> {
         Self::cofactors(tag, node).nth(n).expect("out of range")
     }
+
+    /// Get the `n`-th cofactor of the function represented by `edge` with
+    /// respect to a variable whose level is skipped by `edge`, i.e., a level
+    /// above the node `edge` points to
+    ///
+    /// Returns `Ok(None)` if the cofactor is `edge` itself. This is the case
+    /// in most diagram types, since the function does not depend on this
+    /// variable, and what the default implementation returns. In
+    /// zero-suppressed decision diagrams, however, a skipped level means that
+    /// the variable is false.
+    ///
+    /// This is used by reordering algorithms when swapping levels.
+    #[inline]
+    fn skipped_cofactor<M: Manager<Edge = E, InnerNode = N, Terminal = T>>(
+        manager: &M,
+        edge: &E,
+        n: usize,
+    ) -> AllocResult<Option<E>> {
+        let _ = (manager, edge, n);
+        Ok(None)
+    }
 }
 
 /// Result of the attempt to create a new node
@@ -746,6 +768,55 @@ pub unsafe trait Manager: Sized {
     /// This counter should monotonically increase to ensure that caches are
     /// invalidated accordingly.
     fn reorder_count(&self) -> u64;
+
+    /// Get the variable groups, i.e. blocks of adjacent levels that must be
+    /// kept together by reordering operations
+    ///
+    /// The default implementation returns an empty registry, i.e. every level
+    /// forms a group on its own.
+    #[must_use]
+    fn var_groups(&self) -> &VarGroups {
+        static GROUPS: VarGroups = VarGroups::new();
+        &GROUPS
+    }
+
+    /// Get the variable groups for modification
+    ///
+    /// Reordering operations are responsible for keeping the groups up to
+    /// date.
+    ///
+    /// Returns `None` if the manager does not support variable groups. This
+    /// is what the default implementation does.
+    #[must_use]
+    fn var_groups_mut(&mut self) -> Option<&mut VarGroups> {
+        None
+    }
+
+    /// Returns `true` if the manager requests a dynamic reordering
+    ///
+    /// Managers supporting automatic reordering set this flag, e.g., if the
+    /// number of nodes exceeds some threshold. Operations creating nodes should
+    /// then abort by returning `Err(OutOfMemory)`. The high-level methods of
+    /// [`Function`][function::Function] and its subtraits restart aborted
+    /// operations after calling [`Manager::reorder_if_requested()`].
+    ///
+    /// The default implementation always returns `false`.
+    #[inline(always)]
+    #[must_use]
+    fn reorder_requested(&self) -> bool {
+        false
+    }
+
+    /// Perform the dynamic reordering requested via
+    /// [`Manager::reorder_requested()`] (if any)
+    ///
+    /// Returns `true` if a reordering was performed.
+    ///
+    /// The default implementation does nothing and returns `false`.
+    #[inline(always)]
+    fn reorder_if_requested(&mut self) -> bool {
+        false
+    }
 }
 
 /// View of a single level in the manager
@@ -0,0 +1,63 @@
+use std::fmt;
+
+/// Configuration of automatic dynamic reordering
+///
+/// Once the number of inner nodes exceeds the current threshold, the manager
+/// [requests a reordering][crate::Manager::reorder_requested]: Running
+/// operations are aborted, `heuristic` is applied, and the operations are
+/// restarted. Afterwards, the threshold is set to `growth` times the number of
+/// inner nodes (but at least `threshold`).
+pub struct AutoReorder<M> {
+    /// Number of inner nodes at which the first reordering is triggered
+    pub threshold: usize,
+    /// Factor by which the number of nodes may grow after a reordering until
+    /// the next reordering is triggered
+    ///
+    /// Must be greater than `1.0`.
+    pub growth: f64,
+    /// The reordering heuristic
+    ///
+    /// The heuristic is called with exclusive access to the manager and is
+    /// responsible for calling [`Manager::reorder()`][crate::Manager::reorder].
+    pub heuristic: fn(&mut M),
+}
+
+impl<M> AutoReorder<M> {
+    /// Create a new configuration with the given `heuristic`, an initial
+    /// `threshold` of 4096 nodes, and a `growth` factor of 2
+    #[inline]
+    pub fn new(heuristic: fn(&mut M)) -> Self {
+        Self {
+            threshold: 4096,
+            growth: 2.0,
+            heuristic,
+        }
+    }
+
+    /// Compute the next threshold given the number of inner nodes after a
+    /// reordering
+    #[inline]
+    pub fn next_threshold(&self, num_inner_nodes: usize) -> usize {
+        std::cmp::max(
+            self.threshold,
+            (num_inner_nodes as f64 * self.growth) as usize,
+        )
+    }
+}
+
+impl<M> Clone for AutoReorder<M> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+impl<M> Copy for AutoReorder<M> {}
+
+impl<M> fmt::Debug for AutoReorder<M> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AutoReorder")
+            .field("threshold", &self.threshold)
+            .field("growth", &self.growth)
+            .finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,186 @@
+//! Iterators over the cubes and satisfying assignments of Boolean functions
+
+use std::iter::FusedIterator;
+
+use crate::function::BooleanFunction;
+use crate::function::INodeOfFunc;
+use crate::HasLevel;
+use crate::LevelNo;
+use crate::Manager;
+
+use super::OptBool;
+
+/// Iterator over the cubes of a Boolean function
+///
+/// Created by [`BooleanFunction::cubes()`], see there for more details.
+pub struct Cubes<F: BooleanFunction> {
+    /// `⊥`, used to tell the two kinds of paths apart
+    ff: F,
+    /// The nodes on the current path along with the branch to take next
+    /// (0: "then", 1: "else", 2: none)
+    stack: Vec<(F, u8)>,
+    /// The current cube, indexed by level
+    cube: Vec<OptBool>,
+    /// Whether the function is a terminal which satisfies all valuations and
+    /// has not yet been reported
+    pending_terminal: bool,
+}
+
+impl<F: BooleanFunction> Cubes<F>
+where
+    for<'id> INodeOfFunc<'id, F>: HasLevel,
+{
+    pub(crate) fn new(f: &F) -> Self {
+        f.with_manager_shared(|manager, edge| {
+            let ff = F::f(manager);
+            let cube = vec![F::SKIPPED_VAR; manager.num_levels() as usize];
+            if manager.get_node(edge).is_any_terminal() {
+                let pending_terminal = edge != ff.as_edge(manager);
+                Self {
+                    ff,
+                    stack: Vec::new(),
+                    cube,
+                    pending_terminal,
+                }
+            } else {
+                Self {
+                    ff,
+                    stack: vec![(f.clone(), 0)],
+                    cube,
+                    pending_terminal: false,
+                }
+            }
+        })
+    }
+}
+
+impl<F: BooleanFunction> Iterator for Cubes<F>
+where
+    for<'id> INodeOfFunc<'id, F>: HasLevel,
+{
+    type Item = Vec<OptBool>;
+
+    fn next(&mut self) -> Option<Vec<OptBool>> {
+        let Some((root, _)) = self.stack.first() else {
+            return std::mem::take(&mut self.pending_terminal).then(|| self.cube.clone());
+        };
+
+        root.clone().with_manager_shared(|manager, _| {
+            let ff = self.ff.as_edge(manager);
+            while let Some((f, branch)) = self.stack.last_mut() {
+                let edge = f.as_edge(manager);
+                let level = manager.get_node(edge).unwrap_inner().level() as usize;
+                if *branch == 2 {
+                    self.cube[level] = F::SKIPPED_VAR;
+                    self.stack.pop();
+                    continue;
+                }
+
+                let (t, e) = F::cofactors_edge(manager, edge).unwrap();
+                let child = if *t == *e {
+                    *branch = 2;
+                    self.cube[level] = OptBool::None;
+                    t
+                } else if *branch == 0 {
+                    *branch = 1;
+                    self.cube[level] = OptBool::True;
+                    t
+                } else {
+                    *branch = 2;
+                    self.cube[level] = OptBool::False;
+                    e
+                };
+
+                if manager.get_node(&child).is_any_terminal() {
+                    if *child != *ff {
+                        return Some(self.cube.clone());
+                    }
+                } else {
+                    let child = F::from_edge_ref(manager, &child);
+                    self.stack.push((child, 0));
+                }
+            }
+            None
+        })
+    }
+}
+
+impl<F: BooleanFunction> FusedIterator for Cubes<F> where for<'id> INodeOfFunc<'id, F>: HasLevel {}
+
+/// Iterator over the satisfying assignments of a Boolean function
+///
+/// Created by [`BooleanFunction::assignments()`], see there for more details.
+pub struct Assignments<F: BooleanFunction> {
+    cubes: Cubes<F>,
+    /// Levels of the variables the assignments refer to
+    levels: Vec<LevelNo>,
+    /// The current cube restricted to `levels`
+    cube: Vec<OptBool>,
+    /// The current assignment, `None` if a new cube needs to be fetched
+    assignment: Option<Vec<bool>>,
+}
+
+impl<F: BooleanFunction> Assignments<F>
+where
+    for<'id> INodeOfFunc<'id, F>: HasLevel,
+{
+    pub(crate) fn new<'a>(f: &F, vars: impl IntoIterator<Item = &'a F>) -> Self
+    where
+        F: 'a,
+    {
+        let levels = f.with_manager_shared(|manager, _| {
+            vars.into_iter()
+                .map(|var| {
+                    manager
+                        .get_node(var.as_edge(manager))
+                        .expect_inner("variables must refer to inner nodes")
+                        .level()
+                })
+                .collect()
+        });
+        Self {
+            cubes: Cubes::new(f),
+            levels,
+            cube: Vec::new(),
+            assignment: None,
+        }
+    }
+}
+
+impl<F: BooleanFunction> Iterator for Assignments<F>
+where
+    for<'id> INodeOfFunc<'id, F>: HasLevel,
+{
+    type Item = Vec<bool>;
+
+    fn next(&mut self) -> Option<Vec<bool>> {
+        if let Some(assignment) = &mut self.assignment {
+            // Treat the "don't care" positions as a binary counter
+            for (value, &c) in assignment.iter_mut().zip(&self.cube) {
+                if c == OptBool::None {
+                    *value = !*value;
+                    if *value {
+                        return Some(assignment.clone());
+                    }
+                }
+            }
+        }
+
+        let cube = self.cubes.next();
+        let Some(cube) = cube else {
+            self.assignment = None;
+            return None;
+        };
+        self.cube.clear();
+        self.cube
+            .extend(self.levels.iter().map(|&level| cube[level as usize]));
+        let assignment: Vec<bool> = self.cube.iter().map(|&c| c == OptBool::True).collect();
+        self.assignment = Some(assignment.clone());
+        Some(assignment)
+    }
+}
+
+impl<F: BooleanFunction> FusedIterator for Assignments<F> where
+    for<'id> INodeOfFunc<'id, F>: HasLevel
+{
+}
@@ -0,0 +1,110 @@
+//! Influence of variables on Boolean functions
+
+use std::collections::hash_map::RandomState;
+
+use crate::function::BooleanFunction;
+use crate::function::BooleanFunctionQuant;
+use crate::function::EdgeOfFunc;
+use crate::function::INodeOfFunc;
+use crate::HasLevel;
+use crate::Manager;
+
+use super::num::F64;
+use super::AllocResult;
+use super::EdgeDropGuard;
+use super::EdgeHashMap;
+use super::EdgeVecDropGuard;
+use super::WeightedSatCountCache;
+
+type Memo<'a, 'id, F> =
+    EdgeHashMap<'a, <F as crate::function::Function>::Manager<'id>, f64, RandomState>;
+
+/// Compute the influence of `var` on `f`
+///
+/// See [`BooleanFunctionQuant::influence()`] for more details.
+pub(crate) fn influence<F: BooleanFunctionQuant>(f: &F, var: &F) -> AllocResult<f64> {
+    let diff = f.boolean_difference(var)?;
+    let mut cache: WeightedSatCountCache<F64, RandomState> = Default::default();
+    let half = || F64(0.5);
+    Ok(diff.weighted_sat_count(|_| (half(), half()), &mut cache).0)
+}
+
+/// Probability that the function represented by `edge` evaluates to true
+/// under a uniformly random valuation
+fn probability<'a, 'id, F: BooleanFunction>(
+    manager: &'a F::Manager<'id>,
+    ff: &EdgeOfFunc<'id, F>,
+    edge: &EdgeOfFunc<'id, F>,
+    memo: &mut Memo<'a, 'id, F>,
+) -> f64 {
+    if manager.get_node(edge).is_any_terminal() {
+        return if edge != ff { 1.0 } else { 0.0 };
+    }
+    if let Some(&p) = memo.get(edge) {
+        return p;
+    }
+    let (t, e) = F::cofactors_edge(manager, edge).unwrap();
+    let p =
+        0.5 * (probability::<F>(manager, ff, &t, memo) + probability::<F>(manager, ff, &e, memo));
+    memo.insert(edge, p);
+    p
+}
+
+/// Compute the Banzhaf values (influences) of all variables for the function
+/// represented by `edge` in a single pass over the decision diagram
+///
+/// A uniformly random valuation reaches an inner node at level `i` with some
+/// probability `p`. Flipping the variable at level `i` changes the function
+/// value iff the node's cofactors `t` and `e` differ on the valuation, so the
+/// node contributes `p · Pr[t ⊕ e]` to the influence of this variable. Paths
+/// skipping level `i` do not depend on the variable and contribute nothing.
+///
+/// See [`BooleanFunctionQuant::banzhaf_values()`] for more details.
+///
+/// [`BooleanFunctionQuant::banzhaf_values()`]: crate::function::BooleanFunctionQuant::banzhaf_values
+pub(crate) fn banzhaf_values<'id, F>(
+    manager: &F::Manager<'id>,
+    edge: &EdgeOfFunc<'id, F>,
+) -> AllocResult<Vec<f64>>
+where
+    F: BooleanFunction,
+    INodeOfFunc<'id, F>: HasLevel,
+{
+    let mut values = vec![0.0; manager.num_levels() as usize];
+    let ff = EdgeDropGuard::new(manager, F::f_edge(manager));
+
+    // The memo's keys are exactly the reachable edges to inner nodes.
+    let mut prob: Memo<F> = EdgeHashMap::new(manager);
+    probability::<F>(manager, &ff, edge, &mut prob);
+    if prob.is_empty() {
+        return Ok(values);
+    }
+
+    // Top-down: probability of a uniformly random valuation to reach an edge
+    let level = |e: &EdgeOfFunc<'id, F>| manager.get_node(e).unwrap_inner().level();
+    let mut edges = EdgeVecDropGuard::new(
+        manager,
+        prob.iter().map(|(e, _)| manager.clone_edge(e)).collect(),
+    );
+    edges.sort_unstable_by_key(|e| level(e));
+    let mut reach: Memo<F> = EdgeHashMap::new(manager);
+    reach.insert(edge, 1.0);
+
+    for e in edges.iter() {
+        let p = reach.get(e).copied().unwrap_or(0.0);
+        let (t, el) = F::cofactors_edge(manager, e).unwrap();
+        let diff = EdgeDropGuard::new(manager, F::xor_edge(manager, &t, &el)?);
+        values[level(e) as usize] += p * probability::<F>(manager, &ff, &diff, &mut prob);
+        for child in [&*t, &*el] {
+            if !manager.get_node(child).is_any_terminal() {
+                match reach.get_mut(child) {
+                    Some(r) => *r += 0.5 * p,
+                    None => {
+                        reach.insert(child, 0.5 * p);
+                    }
+                }
+            }
+        }
+    }
+    Ok(values)
+}
@@ -0,0 +1,278 @@
+//! Irredundant sum-of-products covers (Minato–Morreale algorithm)
+//!
+//! The functions of the covers are computed by
+//! [`BooleanFunctionQuant::isop_edge()`], which memoizes its results in the
+//! apply cache. The functions in this module replay the recursion to collect
+//! the cubes of the cover. Since the sub-results for the functions are
+//! usually found in the apply cache, this is cheap compared to the
+//! computation of the function itself.
+
+use std::collections::HashMap;
+
+use crate::function::BooleanFunction;
+use crate::function::BooleanFunctionQuant;
+use crate::function::BooleanVecSet;
+use crate::function::EdgeOfFunc;
+use crate::function::INodeOfFunc;
+use crate::Edge;
+use crate::HasLevel;
+use crate::LevelNo;
+use crate::Manager;
+use crate::ManagerRef;
+use crate::Node;
+
+use super::AllocResult;
+use super::Borrowed;
+use super::EdgeDropGuard;
+use super::EdgeVecDropGuard;
+use super::OptBool;
+use super::OutOfMemory;
+
+/// Singleton sets of the literals in `cover_manager` for a manager with
+/// `num_levels` levels
+///
+/// The positive literal of the variable at level `i` is the element at level
+/// `2i`, the negative literal the element at level `2i + 1`.
+pub(super) fn literal_sets<'a, 'cid, S: BooleanVecSet>(
+    cover_manager: &'a S::Manager<'cid>,
+    num_levels: LevelNo,
+) -> AllocResult<EdgeVecDropGuard<'a, S::Manager<'cid>>> {
+    let num_elements = 2 * num_levels;
+    let mut literals =
+        EdgeVecDropGuard::new(cover_manager, Vec::with_capacity(num_elements as usize));
+    for element in 0..num_elements {
+        literals.push(S::set_edge(cover_manager, &[element])?);
+    }
+    Ok(literals)
+}
+
+/// Run `f`, restarting it if it was aborted for a reordering of the manager
+/// referenced by `cover_manager`
+///
+/// Reorderings of the manager `f` operates on are expected to be handled by
+/// `f` itself, e.g., via
+/// [`Function::with_manager_shared_restart()`][crate::function::Function::with_manager_shared_restart].
+pub(crate) fn restart_for_cover<MR: ManagerRef, T>(
+    cover_manager: &MR,
+    f: impl Fn() -> AllocResult<T>,
+) -> AllocResult<T> {
+    loop {
+        match f() {
+            Err(OutOfMemory) if cover_manager.with_manager_shared(|m| m.reorder_requested()) => {
+                cover_manager.with_manager_exclusive(|m| m.reorder_if_requested());
+            }
+            res => return res,
+        }
+    }
+}
+
+/// Level of the node referenced by `edge`, `LevelNo::MAX` for terminals
+fn edge_level<M: Manager>(manager: &M, edge: &M::Edge) -> LevelNo
+where
+    M::InnerNode: HasLevel,
+{
+    match manager.get_node(edge) {
+        Node::Inner(node) => node.level(),
+        Node::Terminal(_) => LevelNo::MAX,
+    }
+}
+
+/// Cofactors `(f_true, f_false)` of `f` with respect to the variable at
+/// `level`, assuming that `f` does not depend on variables above `level`
+fn cofactors<'a, 'id, F: BooleanFunction>(
+    manager: &'a F::Manager<'id>,
+    f: &'a EdgeOfFunc<'id, F>,
+    level: LevelNo,
+) -> (
+    Borrowed<'a, EdgeOfFunc<'id, F>>,
+    Borrowed<'a, EdgeOfFunc<'id, F>>,
+)
+where
+    INodeOfFunc<'id, F>: HasLevel,
+{
+    if edge_level(manager, f) == level {
+        F::cofactors_edge(manager, f).unwrap()
+    } else {
+        (f.borrowed(), f.borrowed())
+    }
+}
+
+/// Terminal cases of the recursion: `Some(false)` if the interval's lower
+/// bound is `⊥` (empty cover), `Some(true)` if its upper bound is `⊤` (cover
+/// consisting of the empty cube only)
+fn terminal<'id, F: BooleanFunction>(
+    manager: &F::Manager<'id>,
+    lower: &EdgeOfFunc<'id, F>,
+    upper: &EdgeOfFunc<'id, F>,
+) -> Option<bool> {
+    if *lower == *EdgeDropGuard::new(manager, F::f_edge(manager)) {
+        Some(false)
+    } else if *upper == *EdgeDropGuard::new(manager, F::t_edge(manager)) {
+        Some(true)
+    } else {
+        None
+    }
+}
+
+/// Split the interval `[lower, upper]` with respect to its top variable `x`
+///
+/// Returns the level of `x` along with the intervals for the cubes containing
+/// `x`, the cubes containing `¬x`, and the cubes containing neither `x` nor
+/// `¬x` (in this order).
+#[allow(clippy::type_complexity)]
+fn split<'a, 'id, F: BooleanFunctionQuant>(
+    manager: &'a F::Manager<'id>,
+    lower: &EdgeOfFunc<'id, F>,
+    upper: &EdgeOfFunc<'id, F>,
+) -> AllocResult<(LevelNo, [[EdgeDropGuard<'a, F::Manager<'id>>; 2]; 3])>
+where
+    INodeOfFunc<'id, F>: HasLevel,
+{
+    let level = std::cmp::min(edge_level(manager, lower), edge_level(manager, upper));
+    let (l1, l0) = cofactors::<F>(manager, lower, level);
+    let (u1, u0) = cofactors::<F>(manager, upper, level);
+    let guard = |e| EdgeDropGuard::new(manager, e);
+
+    // Cubes containing the positive literal
+    let l1_pos = guard(F::imp_strict_edge(manager, &u0, &l1)?);
+    let f1 = guard(F::isop_edge(manager, &l1_pos, &u1)?);
+    // Cubes containing the negative literal
+    let l0_neg = guard(F::imp_strict_edge(manager, &u1, &l0)?);
+    let f0 = guard(F::isop_edge(manager, &l0_neg, &u0)?);
+    // Cubes containing neither
+    let l1_dc = guard(F::imp_strict_edge(manager, &f1, &l1)?);
+    let l0_dc = guard(F::imp_strict_edge(manager, &f0, &l0)?);
+    let ld = guard(F::or_edge(manager, &l1_dc, &l0_dc)?);
+    let ud = guard(F::and_edge(manager, &u1, &u0)?);
+
+    let u1 = guard(manager.clone_edge(&u1));
+    let u0 = guard(manager.clone_edge(&u0));
+    Ok((level, [[l1_pos, u1], [l0_neg, u0], [ld, ud]]))
+}
+
+/// Collect the cubes of an irredundant sum-of-products cover of the interval
+/// `[lower, upper]` in `cubes`
+///
+/// `cube` is the prefix of the current cube, all entries for levels below the
+/// top variables of `lower` and `upper` must be `None`. See
+/// [`BooleanFunctionQuant::isop()`] for more details.
+///
+/// In contrast to [`isop_cover()`], this does not memoize the covers of the
+/// sub-intervals. Every recursive call with a satisfiable lower bound
+/// contributes at least one cube to the result, so the number of calls is
+/// bounded by the number of levels times the number of cubes.
+pub(crate) fn isop_cubes<'id, F: BooleanFunctionQuant>(
+    manager: &F::Manager<'id>,
+    lower: &EdgeOfFunc<'id, F>,
+    upper: &EdgeOfFunc<'id, F>,
+    cube: &mut Vec<OptBool>,
+    cubes: &mut Vec<Vec<OptBool>>,
+) -> AllocResult<()>
+where
+    INodeOfFunc<'id, F>: HasLevel,
+{
+    match terminal::<F>(manager, lower, upper) {
+        Some(false) => return Ok(()),
+        Some(true) => {
+            cubes.push(cube.clone());
+            return Ok(());
+        }
+        None => {}
+    }
+
+    let (level, intervals) = split::<F>(manager, lower, upper)?;
+    let literals = [OptBool::True, OptBool::False, OptBool::None];
+    for ([lower, upper], literal) in intervals.iter().zip(literals) {
+        cube[level as usize] = literal;
+        isop_cubes::<F>(manager, lower, upper, cube, cubes)?;
+    }
+    Ok(())
+}
+
+/// Compute an irredundant sum-of-products cover of the interval
+/// `[lower, upper]` as a family of sets of literals in `cover_manager`
+///
+/// See [`BooleanFunctionQuant::isop_cover()`] for more details.
+pub(crate) fn isop_cover<'id, 'cid, F: BooleanFunctionQuant, S: BooleanVecSet>(
+    manager: &F::Manager<'id>,
+    cover_manager: &S::Manager<'cid>,
+    lower: &EdgeOfFunc<'id, F>,
+    upper: &EdgeOfFunc<'id, F>,
+) -> AllocResult<EdgeOfFunc<'cid, S>>
+where
+    INodeOfFunc<'id, F>: HasLevel,
+{
+    /// Singleton sets of the literals (see [`literal_sets()`]) along with the
+    /// covers computed so far
+    struct Ctx<'a, 'id, 'cid, F: BooleanFunction, S: BooleanVecSet> {
+        literals: EdgeVecDropGuard<'a, S::Manager<'cid>>,
+        memo: HashMap<(EdgeOfFunc<'id, F>, EdgeOfFunc<'id, F>), EdgeOfFunc<'cid, S>>,
+    }
+
+    fn inner<'id, 'cid, F: BooleanFunctionQuant, S: BooleanVecSet>(
+        manager: &F::Manager<'id>,
+        cover_manager: &S::Manager<'cid>,
+        ctx: &mut Ctx<'_, 'id, 'cid, F, S>,
+        lower: &EdgeOfFunc<'id, F>,
+        upper: &EdgeOfFunc<'id, F>,
+    ) -> AllocResult<EdgeOfFunc<'cid, S>>
+    where
+        INodeOfFunc<'id, F>: HasLevel,
+    {
+        match terminal::<F>(manager, lower, upper) {
+            Some(false) => return Ok(S::empty_edge(cover_manager)),
+            Some(true) => return Ok(S::base_edge(cover_manager)),
+            None => {}
+        }
+        let key = (manager.clone_edge(lower), manager.clone_edge(upper));
+        if let Some(res) = ctx.memo.get(&key) {
+            let res = cover_manager.clone_edge(res);
+            manager.drop_edge(key.0);
+            manager.drop_edge(key.1);
+            return Ok(res);
+        }
+        let key = (
+            EdgeDropGuard::new(manager, key.0),
+            EdgeDropGuard::new(manager, key.1),
+        );
+
+        let (level, [[l1, u1], [l0, u0], [ld, ud]]) = split::<F>(manager, lower, upper)?;
+        let guard = |e| EdgeDropGuard::new(cover_manager, e);
+        let c1 = guard(inner::<F, S>(manager, cover_manager, ctx, &l1, &u1)?);
+        let c0 = guard(inner::<F, S>(manager, cover_manager, ctx, &l0, &u0)?);
+        let cd = guard(inner::<F, S>(manager, cover_manager, ctx, &ld, &ud)?);
+
+        // All elements of `c1`, `c0`, and `cd` are greater than `2 * level + 1`,
+        // so the following operations only create the nodes for the two
+        // literals of the current variable.
+        let level = level as usize;
+        let pos = guard(S::change_edge(
+            cover_manager,
+            &c1,
+            &ctx.literals[2 * level],
+        )?);
+        let neg = guard(S::change_edge(
+            cover_manager,
+            &c0,
+            &ctx.literals[2 * level + 1],
+        )?);
+        let lits = guard(S::union_edge(cover_manager, &pos, &neg)?);
+        let res = S::union_edge(cover_manager, &lits, &cd)?;
+
+        let key = (key.0.into_edge(), key.1.into_edge());
+        ctx.memo.insert(key, cover_manager.clone_edge(&res));
+        Ok(res)
+    }
+
+    let mut ctx = Ctx::<F, S> {
+        literals: literal_sets::<S>(cover_manager, manager.num_levels())?,
+        memo: HashMap::new(),
+    };
+    let res = inner::<F, S>(manager, cover_manager, &mut ctx, lower, upper);
+    for ((lower, upper), cover) in ctx.memo.drain() {
+        manager.drop_edge(lower);
+        manager.drop_edge(upper);
+        cover_manager.drop_edge(cover);
+    }
+    res
+}
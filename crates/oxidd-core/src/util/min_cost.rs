@@ -0,0 +1,154 @@
+//! Minimum-cost satisfying assignments of Boolean functions
+
+use std::collections::hash_map::RandomState;
+
+use crate::function::BooleanFunction;
+use crate::function::EdgeOfFunc;
+use crate::function::INodeOfFunc;
+use crate::HasLevel;
+use crate::Manager;
+use crate::Node;
+
+use super::EdgeDropGuard;
+use super::EdgeHashMap;
+use super::OptBool;
+
+/// A number type suitable for the costs of literals
+///
+/// `Self::from(0)` must be the neutral element of the addition, and costs are
+/// compared via [`PartialOrd`]. Examples are the unsigned integer types and
+/// [`f64`].
+pub trait CostNumber:
+    Clone + PartialOrd + From<u32> + for<'a> std::ops::AddAssign<&'a Self>
+{
+}
+
+impl<T: Clone + PartialOrd + From<u32> + for<'a> std::ops::AddAssign<&'a T>> CostNumber for T {}
+
+type Memo<'a, 'id, F, C> =
+    EdgeHashMap<'a, <F as crate::function::Function>::Manager<'id>, Option<C>, RandomState>;
+
+struct MinCost<'a, 'id, F: BooleanFunction, C> {
+    manager: &'a F::Manager<'id>,
+    /// `⊥` as edge, used to tell the two kinds of terminals apart
+    ff: &'a EdgeOfFunc<'id, F>,
+    /// Costs of the positive and negative literal, indexed by level
+    costs: Vec<(C, C)>,
+    /// Cost of a level that does not occur on a path, indexed by level
+    skip: Vec<C>,
+    /// Cost of the cheapest path from an edge to `⊤` (`None` if there is no
+    /// such path)
+    memo: Memo<'a, 'id, F, C>,
+}
+
+impl<'a, 'id, F: BooleanFunction, C: CostNumber> MinCost<'a, 'id, F, C>
+where
+    INodeOfFunc<'id, F>: HasLevel,
+{
+    fn level(&self, edge: &EdgeOfFunc<'id, F>) -> usize {
+        match self.manager.get_node(edge) {
+            Node::Inner(node) => node.level() as usize,
+            Node::Terminal(_) => self.costs.len(),
+        }
+    }
+
+    /// Sum of the `skip` costs for the levels in `from..to`
+    fn skip_cost(&self, from: usize, to: usize) -> C {
+        let mut sum = C::from(0);
+        for c in &self.skip[from..to] {
+            sum += c;
+        }
+        sum
+    }
+
+    /// Cost of the cheapest path from `edge` to `⊤`
+    fn cost(&mut self, edge: &EdgeOfFunc<'id, F>) -> Option<C> {
+        if self.manager.get_node(edge).is_any_terminal() {
+            return (edge != self.ff).then(|| C::from(0));
+        }
+        if let Some(c) = self.memo.get(edge) {
+            return c.clone();
+        }
+
+        let (t, e) = self.branch_costs(edge);
+        let res = match (t, e) {
+            (Some(t), Some(e)) => Some(if t < e { t } else { e }),
+            (t, e) => t.or(e),
+        };
+        self.memo.insert(edge, res.clone());
+        res
+    }
+
+    /// Costs of the cheapest paths to `⊤` via the "then" and the "else" edge
+    /// of the inner node referenced by `edge`, including the costs of the
+    /// literal at the node's level and of skipped levels
+    fn branch_costs(&mut self, edge: &EdgeOfFunc<'id, F>) -> (Option<C>, Option<C>) {
+        let manager = self.manager;
+        let level = self.level(edge);
+        let (t, e) = F::cofactors_edge(manager, edge).unwrap();
+        let mut branch = |child: &EdgeOfFunc<'id, F>, literal: bool| {
+            let mut cost = self.cost(child)?;
+            let (pos, neg) = &self.costs[level];
+            cost += if literal { pos } else { neg };
+            cost += &self.skip_cost(level + 1, self.level(child));
+            Some(cost)
+        };
+        (branch(&t, true), branch(&e, false))
+    }
+}
+
+/// Compute a minimum-cost satisfying assignment of `edge`
+///
+/// See [`BooleanFunction::min_cost_sat()`] for more details.
+pub(crate) fn min_cost_sat<'id, F, C>(
+    manager: &F::Manager<'id>,
+    edge: &EdgeOfFunc<'id, F>,
+    costs: impl Fn(crate::LevelNo) -> (C, C),
+) -> Option<(Vec<bool>, C)>
+where
+    F: BooleanFunction,
+    C: CostNumber,
+    INodeOfFunc<'id, F>: HasLevel,
+{
+    let ff = EdgeDropGuard::new(manager, F::f_edge(manager));
+    let costs: Vec<(C, C)> = (0..manager.num_levels()).map(costs).collect();
+
+    // Value and cost of levels that do not occur on a path
+    let (mut assignment, skip): (Vec<bool>, Vec<C>) = costs
+        .iter()
+        .map(|(pos, neg)| match F::SKIPPED_VAR {
+            OptBool::None if pos < neg => (true, pos.clone()),
+            OptBool::None | OptBool::False => (false, neg.clone()),
+            OptBool::True => (true, pos.clone()),
+        })
+        .unzip();
+
+    let mut state = MinCost::<F, C> {
+        manager,
+        ff: &ff,
+        costs,
+        skip,
+        memo: EdgeHashMap::new(manager),
+    };
+
+    let mut cost = state.cost(edge)?;
+    cost += &state.skip_cost(0, state.level(edge));
+
+    // Follow the cheapest path
+    let mut edge = manager.clone_edge(edge);
+    while !manager.get_node(&edge).is_any_terminal() {
+        let level = state.level(&edge);
+        let (t, e) = state.branch_costs(&edge);
+        let take_then = match (t, e) {
+            (Some(t), Some(e)) => t < e,
+            (t, _) => t.is_some(),
+        };
+        assignment[level] = take_then;
+        let (ct, ce) = F::cofactors_edge(manager, &edge).unwrap();
+        let child = manager.clone_edge(if take_then { &ct } else { &ce });
+        manager.drop_edge(std::mem::replace(&mut edge, child));
+    }
+    manager.drop_edge(edge);
+
+    Some((assignment, cost))
+}
@@ -15,11 +15,37 @@ use crate::LevelNo;
 use crate::Manager;
 use crate::NodeID;
 
+mod auto_reorder;
+pub use auto_reorder::AutoReorder;
+mod cube_iter;
+pub use cube_iter::Assignments;
+pub use cube_iter::Cubes;
 pub mod edge_hash_map;
 pub use edge_hash_map::EdgeHashMap;
+mod influence;
+pub(crate) use influence::banzhaf_values;
+pub(crate) use influence::influence;
+mod isop;
+pub(crate) use isop::isop_cover;
+pub(crate) use isop::isop_cubes;
+pub(crate) use isop::restart_for_cover;
+mod min_cost;
+pub(crate) use min_cost::min_cost_sat;
+pub use min_cost::CostNumber;
 pub mod num;
+mod primes;
+pub(crate) use primes::essential_literals;
+pub(crate) use primes::prime_implicants;
+pub(crate) use primes::unateness;
+pub use primes::Unateness;
+#[cfg(feature = "rand")]
+mod sample;
+#[cfg(feature = "rand")]
+pub(crate) use sample::sample_n;
 mod substitution;
 pub use substitution::*;
+mod var_groups;
+pub use var_groups::VarGroups;
 
 pub use nanorand::WyRand as Rng;
 
@@ -168,6 +194,25 @@ pub trait GCContainer<M: Manager> {
     unsafe fn post_gc(&self, manager: &M);
 }
 
+/// Manager data that needs to be informed about reordering operations
+///
+/// In contrast to [`GCContainer`], the hooks of this trait get exclusive
+/// access to the manager, which in turn owns the data. This way, the data may,
+/// e.g., create new nodes to rebuild itself.
+pub trait ReorderHook<M: Manager> {
+    /// Post-process a reordering
+    ///
+    /// [`Manager::reorder()`] calls this function after reordering the levels
+    /// and before [`GCContainer::post_gc()`]. Data depending on the variable
+    /// order may use this to rebuild itself.
+    ///
+    /// The default implementation does nothing.
+    #[inline]
+    fn post_reorder(manager: &mut M) {
+        let _ = manager;
+    }
+}
+
 /// Drop guard for edges to ensure that they are not leaked
 pub struct EdgeDropGuard<'a, M: Manager> {
     manager: &'a M,
@@ -434,6 +479,20 @@ impl From<bool> for OptBool {
     }
 }
 
+/// Sort `literals` by level number and remove duplicates
+///
+/// Each literal is a pair of a level number and a polarity. Returns `None` if
+/// some variable occurs both positively and negatively.
+pub fn sort_literals(literals: &[(LevelNo, bool)]) -> Option<Vec<(LevelNo, bool)>> {
+    let mut literals = literals.to_vec();
+    literals.sort_unstable();
+    literals.dedup();
+    if literals.windows(2).any(|w| w[0].0 == w[1].0) {
+        return None;
+    }
+    Some(literals)
+}
+
 /// Zero-sized struct that calls [`std::process::abort()`] if dropped
 ///
 /// This is useful to make code exception safe. If there is a region that must
@@ -512,6 +571,27 @@ impl<
 {
 }
 
+/// A number type suitable for weighted model counting
+///
+/// The operations are those of a commutative semiring: [`AddAssign`] is the
+/// semiring addition and [`MulAssign`] the semiring multiplication.
+/// `Self::from(0)` and `Self::from(1)` must be the respective neutral
+/// elements. Examples are [`f64`], [`num::LogF64`] for computations in log
+/// space, or arbitrary precision rationals (possibly wrapped in a newtype).
+///
+/// [`AddAssign`]: std::ops::AddAssign
+/// [`MulAssign`]: std::ops::MulAssign
+pub trait WeightedCountNumber:
+    Clone + From<u32> + for<'a> std::ops::AddAssign<&'a Self> + for<'a> std::ops::MulAssign<&'a Self>
+{
+}
+
+impl<
+        T: Clone + From<u32> + for<'a> std::ops::AddAssign<&'a T> + for<'a> std::ops::MulAssign<&'a T>,
+    > WeightedCountNumber for T
+{
+}
+
 /// Cache for counting satisfying assignments
 pub struct SatCountCache<N: SatCountNumber, S: BuildHasher> {
     /// Main map from [`NodeID`]s to their model count
@@ -560,3 +640,48 @@ impl<N: SatCountNumber, S: BuildHasher> SatCountCache<N, S> {
         }
     }
 }
+
+/// Cache for weighted model counting
+///
+/// In contrast to [`SatCountCache`], the cached values depend on the literal
+/// weights. It is the caller's responsibility to only reuse the cache for
+/// queries with the same weights (and the same manager).
+pub struct WeightedSatCountCache<W: WeightedCountNumber, S: BuildHasher> {
+    /// Main map from [`NodeID`]s to their weighted model count
+    ///
+    /// For decision diagrams with complement edges, the most significant bit
+    /// of the key is set for the count of the negated function.
+    pub map: HashMap<NodeID, W, S>,
+
+    /// Epoch to indicate if the cache is still valid (see
+    /// [`SatCountCache`])
+    epoch: u64,
+}
+
+impl<W: WeightedCountNumber, S: BuildHasher + Default> Default for WeightedSatCountCache<W, S> {
+    fn default() -> Self {
+        Self {
+            map: HashMap::default(),
+            epoch: 0,
+        }
+    }
+}
+
+impl<W: WeightedCountNumber, S: BuildHasher> WeightedSatCountCache<W, S> {
+    /// Create a new weighted model counting cache
+    pub fn with_hasher(hash_builder: S) -> Self {
+        Self {
+            map: HashMap::with_hasher(hash_builder),
+            epoch: 0,
+        }
+    }
+
+    /// Clear the cache if it has become invalid due to reordering
+    pub fn clear_if_invalid<M: Manager>(&mut self, manager: &M) {
+        let epoch = manager.reorder_count();
+        if epoch != self.epoch {
+            self.epoch = epoch;
+            self.map.clear();
+        }
+    }
+}
@@ -1,6 +1,7 @@
 //! Number types useful for counting satisfying assignments
 
 use std::ops::AddAssign;
+use std::ops::MulAssign;
 use std::ops::ShlAssign;
 use std::ops::ShrAssign;
 use std::ops::SubAssign;
@@ -117,6 +118,11 @@ impl<'a> SubAssign<&'a Self> for F64 {
         self.0 -= rhs.0;
     }
 }
+impl<'a> MulAssign<&'a Self> for F64 {
+    fn mul_assign(&mut self, rhs: &'a Self) {
+        self.0 *= rhs.0;
+    }
+}
 
 impl ShlAssign<u32> for F64 {
     #[allow(clippy::suspicious_op_assign_impl)]
@@ -130,3 +136,54 @@ impl ShrAssign<u32> for F64 {
         self.0 /= (rhs as f64).exp2()
     }
 }
+
+/// Non-negative floating point number in log space, i.e., `LogF64(x)`
+/// represents `eˣ`
+///
+/// This is useful for weighted model counting with tiny probabilities, where
+/// [`f64`] would underflow: multiplication is carried out by adding the
+/// logarithms and addition as `ln(eˣ + eʸ)` in a numerically stable way.
+#[derive(Clone, Copy, PartialEq, PartialOrd, Debug)]
+pub struct LogF64(pub f64);
+
+impl LogF64 {
+    /// Convert `value` into log space
+    #[inline]
+    pub fn from_f64(value: f64) -> Self {
+        Self(value.ln())
+    }
+
+    /// Get the represented value (`eˣ`)
+    #[inline]
+    pub fn to_f64(self) -> f64 {
+        self.0.exp()
+    }
+}
+
+impl From<u32> for LogF64 {
+    #[inline]
+    fn from(value: u32) -> Self {
+        Self::from_f64(value as f64)
+    }
+}
+
+impl<'a> AddAssign<&'a Self> for LogF64 {
+    fn add_assign(&mut self, rhs: &'a Self) {
+        let (hi, lo) = if self.0 >= rhs.0 {
+            (self.0, rhs.0)
+        } else {
+            (rhs.0, self.0)
+        };
+        if lo == f64::NEG_INFINITY {
+            self.0 = hi;
+        } else {
+            self.0 = hi + (lo - hi).exp().ln_1p();
+        }
+    }
+}
+impl<'a> MulAssign<&'a Self> for LogF64 {
+    #[allow(clippy::suspicious_op_assign_impl)]
+    fn mul_assign(&mut self, rhs: &'a Self) {
+        self.0 += rhs.0;
+    }
+}
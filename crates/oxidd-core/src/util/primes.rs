@@ -0,0 +1,182 @@
+//! Prime implicants (Coudert–Madre algorithm) and unateness of Boolean
+//! functions
+
+use std::collections::HashMap;
+
+use crate::function::BooleanFunction;
+use crate::function::BooleanFunctionQuant;
+use crate::function::BooleanVecSet;
+use crate::function::EdgeOfFunc;
+use crate::function::INodeOfFunc;
+use crate::HasLevel;
+use crate::Manager;
+
+use super::isop::literal_sets;
+use super::AllocResult;
+use super::EdgeDropGuard;
+use super::EdgeVecDropGuard;
+use super::OptBool;
+
+/// Unateness of a Boolean function in a variable
+///
+/// Returned by [`BooleanFunctionQuant::unateness()`], see there for more
+/// details.
+///
+/// [`BooleanFunctionQuant::unateness()`]: crate::function::BooleanFunctionQuant::unateness
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum Unateness {
+    /// The function does not depend on the variable
+    Independent,
+    /// The function is monotonically increasing in the variable, i.e., `f|x=0`
+    /// implies `f|x=1`
+    Positive,
+    /// The function is monotonically decreasing in the variable, i.e., `f|x=1`
+    /// implies `f|x=0`
+    Negative,
+    /// The function is neither positive nor negative unate in the variable
+    Binate,
+}
+
+/// Compute the prime implicants of `f` as a family of sets of literals in
+/// `cover_manager` (Coudert–Madre algorithm)
+///
+/// With `x` being the top variable of `f`, `P` the prime implicants of
+/// `f|x=0 ∧ f|x=1`, and `P1`, `P0` the ones of `f|x=1`, `f|x=0`, the prime
+/// implicants of `f` are `P ∪ x·(P1 ∖ P) ∪ ¬x·(P0 ∖ P)`.
+///
+/// See [`BooleanFunctionQuant::prime_implicants()`] for more details.
+///
+/// [`BooleanFunctionQuant::prime_implicants()`]: crate::function::BooleanFunctionQuant::prime_implicants
+pub(crate) fn prime_implicants<'id, 'cid, F: BooleanFunction, S: BooleanVecSet>(
+    manager: &F::Manager<'id>,
+    cover_manager: &S::Manager<'cid>,
+    f: &EdgeOfFunc<'id, F>,
+) -> AllocResult<EdgeOfFunc<'cid, S>>
+where
+    INodeOfFunc<'id, F>: HasLevel,
+{
+    /// Singleton sets of the literals (see [`literal_sets()`]) along with the
+    /// prime implicants computed so far
+    struct Ctx<'a, 'id, 'cid, F: BooleanFunction, S: BooleanVecSet> {
+        literals: EdgeVecDropGuard<'a, S::Manager<'cid>>,
+        memo: HashMap<EdgeOfFunc<'id, F>, EdgeOfFunc<'cid, S>>,
+    }
+
+    fn inner<'id, 'cid, F: BooleanFunction, S: BooleanVecSet>(
+        manager: &F::Manager<'id>,
+        cover_manager: &S::Manager<'cid>,
+        ctx: &mut Ctx<'_, 'id, 'cid, F, S>,
+        f: &EdgeOfFunc<'id, F>,
+    ) -> AllocResult<EdgeOfFunc<'cid, S>>
+    where
+        INodeOfFunc<'id, F>: HasLevel,
+    {
+        if *f == *EdgeDropGuard::new(manager, F::f_edge(manager)) {
+            return Ok(S::empty_edge(cover_manager));
+        }
+        if *f == *EdgeDropGuard::new(manager, F::t_edge(manager)) {
+            return Ok(S::base_edge(cover_manager));
+        }
+        if let Some(res) = ctx.memo.get(f) {
+            return Ok(cover_manager.clone_edge(res));
+        }
+
+        let level = manager.get_node(f).unwrap_inner().level() as usize;
+        let (f1, f0) = F::cofactors_edge(manager, f).unwrap();
+        let both = EdgeDropGuard::new(manager, F::and_edge(manager, &f0, &f1)?);
+        let guard = |e| EdgeDropGuard::new(cover_manager, e);
+        let p = guard(inner::<F, S>(manager, cover_manager, ctx, &both)?);
+        let p1 = guard(inner::<F, S>(manager, cover_manager, ctx, &f1)?);
+        let p1 = guard(S::diff_edge(cover_manager, &p1, &p)?);
+        let p0 = guard(inner::<F, S>(manager, cover_manager, ctx, &f0)?);
+        let p0 = guard(S::diff_edge(cover_manager, &p0, &p)?);
+
+        let pos = guard(S::change_edge(
+            cover_manager,
+            &p1,
+            &ctx.literals[2 * level],
+        )?);
+        let neg = guard(S::change_edge(
+            cover_manager,
+            &p0,
+            &ctx.literals[2 * level + 1],
+        )?);
+        let res = guard(S::union_edge(cover_manager, &p, &pos)?);
+        let res = S::union_edge(cover_manager, &res, &neg)?;
+
+        ctx.memo
+            .insert(manager.clone_edge(f), cover_manager.clone_edge(&res));
+        Ok(res)
+    }
+
+    let mut ctx = Ctx::<F, S> {
+        literals: literal_sets::<S>(cover_manager, manager.num_levels())?,
+        memo: HashMap::new(),
+    };
+    let res = inner::<F, S>(manager, cover_manager, &mut ctx, f);
+    for (f, primes) in ctx.memo.drain() {
+        manager.drop_edge(f);
+        cover_manager.drop_edge(primes);
+    }
+    res
+}
+
+/// Compute the literals implied by `f`
+///
+/// See [`BooleanFunctionQuant::essential_literals()`] for more details.
+///
+/// [`BooleanFunctionQuant::essential_literals()`]: crate::function::BooleanFunctionQuant::essential_literals
+pub(crate) fn essential_literals<F: BooleanFunction>(f: &F) -> AllocResult<Vec<OptBool>> {
+    // All levels must refer to the same variable order, so the whole
+    // computation is restarted after a reordering.
+    f.with_manager_shared_restart(|manager, f| {
+        let num_levels = manager.num_levels();
+        let bot = EdgeDropGuard::new(manager, F::f_edge(manager));
+        if *f == *bot {
+            return Ok(vec![OptBool::None; num_levels as usize]);
+        }
+        (0..num_levels)
+            .map(|level| {
+                let var = EdgeDropGuard::new(manager, F::cube_edge(manager, &[(level, true)])?);
+                let neg = EdgeDropGuard::new(manager, F::imp_strict_edge(manager, &var, f)?);
+                if *neg == *bot {
+                    return Ok(OptBool::True);
+                }
+                let pos = EdgeDropGuard::new(manager, F::and_edge(manager, f, &var)?);
+                Ok(if *pos == *bot {
+                    OptBool::False
+                } else {
+                    OptBool::None
+                })
+            })
+            .collect()
+    })
+}
+
+/// Compute the unateness of `f` in each variable
+///
+/// See [`BooleanFunctionQuant::unateness()`] for more details.
+///
+/// [`BooleanFunctionQuant::unateness()`]: crate::function::BooleanFunctionQuant::unateness
+pub(crate) fn unateness<F: BooleanFunctionQuant>(f: &F) -> AllocResult<Vec<Unateness>> {
+    f.with_manager_shared_restart(|manager, f| {
+        let bot = EdgeDropGuard::new(manager, F::f_edge(manager));
+        (0..manager.num_levels())
+            .map(|level| {
+                let guard = |e| EdgeDropGuard::new(manager, e);
+                let pos = guard(F::cube_edge(manager, &[(level, true)])?);
+                let neg = guard(F::cube_edge(manager, &[(level, false)])?);
+                let f1 = guard(F::restrict_edge(manager, f, &pos)?);
+                let f0 = guard(F::restrict_edge(manager, f, &neg)?);
+                let positive = *guard(F::imp_strict_edge(manager, &f1, &f0)?) == *bot;
+                let negative = *guard(F::imp_strict_edge(manager, &f0, &f1)?) == *bot;
+                Ok(match (positive, negative) {
+                    (true, true) => Unateness::Independent,
+                    (true, false) => Unateness::Positive,
+                    (false, true) => Unateness::Negative,
+                    (false, false) => Unateness::Binate,
+                })
+            })
+            .collect()
+    })
+}
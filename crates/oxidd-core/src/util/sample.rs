@@ -0,0 +1,168 @@
+//! Weighted random sampling of satisfying assignments
+
+use std::collections::hash_map::RandomState;
+
+use rand::Rng;
+
+use crate::function::BooleanFunction;
+use crate::function::EdgeOfFunc;
+use crate::function::INodeOfFunc;
+use crate::HasLevel;
+use crate::LevelNo;
+use crate::Manager;
+use crate::Node;
+
+use super::EdgeDropGuard;
+use super::EdgeHashMap;
+use super::OptBool;
+
+type Memo<'a, 'id, F> =
+    EdgeHashMap<'a, <F as crate::function::Function>::Manager<'id>, f64, RandomState>;
+
+struct Sampler<'a, 'id, F: BooleanFunction> {
+    manager: &'a F::Manager<'id>,
+    /// `⊥` as edge, used to tell the two kinds of terminals apart
+    ff: &'a EdgeOfFunc<'id, F>,
+    /// Normalized weights of the positive and negative literal, indexed by
+    /// level
+    weights: Vec<(f64, f64)>,
+    /// Weight of a level that does not occur on a path, indexed by level
+    skip: Vec<f64>,
+    /// Weighted model count of an edge's function, restricted to the levels
+    /// starting at the edge's level
+    memo: Memo<'a, 'id, F>,
+}
+
+impl<'a, 'id, F: BooleanFunction> Sampler<'a, 'id, F>
+where
+    INodeOfFunc<'id, F>: HasLevel,
+{
+    fn level(&self, edge: &EdgeOfFunc<'id, F>) -> usize {
+        match self.manager.get_node(edge) {
+            Node::Inner(node) => node.level() as usize,
+            Node::Terminal(_) => self.weights.len(),
+        }
+    }
+
+    /// Product of the `skip` weights for the levels in `from..to`
+    fn skip_weight(&self, from: usize, to: usize) -> f64 {
+        self.skip[from..to].iter().product()
+    }
+
+    fn count(&mut self, edge: &EdgeOfFunc<'id, F>) -> f64 {
+        if self.manager.get_node(edge).is_any_terminal() {
+            return if edge != self.ff { 1.0 } else { 0.0 };
+        }
+        if let Some(&c) = self.memo.get(edge) {
+            return c;
+        }
+        let (t, e) = self.branch_counts(edge);
+        self.memo.insert(edge, t + e);
+        t + e
+    }
+
+    /// Weighted model counts via the "then" and the "else" edge of the inner
+    /// node referenced by `edge`, including the weights of the literal at the
+    /// node's level and of skipped levels
+    fn branch_counts(&mut self, edge: &EdgeOfFunc<'id, F>) -> (f64, f64) {
+        let level = self.level(edge);
+        let (t, e) = F::cofactors_edge(self.manager, edge).unwrap();
+        let (pos, neg) = self.weights[level];
+        let ct = pos * self.count(&t) * self.skip_weight(level + 1, self.level(&t));
+        let ce = neg * self.count(&e) * self.skip_weight(level + 1, self.level(&e));
+        (ct, ce)
+    }
+
+    /// Sample the values of the levels in `from..to` that do not occur on the
+    /// path
+    fn sample_skipped<R: Rng + ?Sized>(
+        &self,
+        rng: &mut R,
+        assignment: &mut [bool],
+        from: usize,
+        to: usize,
+    ) {
+        for (value, &(pos, _)) in assignment[from..to].iter_mut().zip(&self.weights[from..to]) {
+            *value = match F::SKIPPED_VAR {
+                OptBool::None => rng.random::<f64>() < pos,
+                OptBool::False => false,
+                OptBool::True => true,
+            };
+        }
+    }
+
+    /// Sample a satisfying assignment of `edge`, assuming that `edge` is
+    /// satisfiable
+    fn sample<R: Rng + ?Sized>(&mut self, rng: &mut R, edge: &EdgeOfFunc<'id, F>) -> Vec<bool> {
+        let manager = self.manager;
+        let mut assignment = vec![false; self.weights.len()];
+        self.sample_skipped(rng, &mut assignment, 0, self.level(edge));
+
+        let mut edge = manager.clone_edge(edge);
+        while !manager.get_node(&edge).is_any_terminal() {
+            let level = self.level(&edge);
+            let (ct, ce) = self.branch_counts(&edge);
+            let take_then = rng.random::<f64>() * (ct + ce) < ct;
+            assignment[level] = take_then;
+            let (t, e) = F::cofactors_edge(manager, &edge).unwrap();
+            let child = manager.clone_edge(if take_then { &t } else { &e });
+            self.sample_skipped(rng, &mut assignment, level + 1, self.level(&child));
+            manager.drop_edge(std::mem::replace(&mut edge, child));
+        }
+        manager.drop_edge(edge);
+        assignment
+    }
+}
+
+/// Draw `n` satisfying assignments of `edge` at random, with probabilities
+/// proportional to the products of the literal weights
+///
+/// See [`BooleanFunction::sample_n()`] for more details.
+pub(crate) fn sample_n<'id, F, R>(
+    manager: &F::Manager<'id>,
+    edge: &EdgeOfFunc<'id, F>,
+    rng: &mut R,
+    weights: impl Fn(LevelNo) -> (f64, f64),
+    n: usize,
+) -> Vec<Vec<bool>>
+where
+    F: BooleanFunction,
+    R: Rng + ?Sized,
+    INodeOfFunc<'id, F>: HasLevel,
+{
+    let ff = EdgeDropGuard::new(manager, F::f_edge(manager));
+    // Scaling the weights of a level does not change the distribution, but
+    // normalizing them avoids overflows and underflows
+    let weights: Vec<(f64, f64)> = (0..manager.num_levels())
+        .map(|level| {
+            let (pos, neg) = weights(level);
+            assert!(
+                pos >= 0.0 && neg >= 0.0 && pos + neg > 0.0,
+                "weights must be non-negative with a positive sum"
+            );
+            (pos / (pos + neg), neg / (pos + neg))
+        })
+        .collect();
+    let skip = weights
+        .iter()
+        .map(|&(pos, neg)| match F::SKIPPED_VAR {
+            OptBool::None => 1.0,
+            OptBool::False => neg,
+            OptBool::True => pos,
+        })
+        .collect();
+
+    let mut sampler = Sampler::<F> {
+        manager,
+        ff: &ff,
+        weights,
+        skip,
+        memo: EdgeHashMap::new(manager),
+    };
+
+    let total = sampler.count(edge) * sampler.skip_weight(0, sampler.level(edge));
+    if total <= 0.0 {
+        return Vec::new();
+    }
+    (0..n).map(|_| sampler.sample(rng, edge)).collect()
+}
@@ -0,0 +1,124 @@
+use std::ops::Range;
+
+use crate::LevelNo;
+
+/// Partition of the levels into groups of adjacent levels
+///
+/// Reordering operations keep the levels of a group contiguous and move the
+/// group as a unit, i.e. the relative order of levels inside a group is
+/// preserved. Levels that are not part of an explicitly declared group form
+/// groups on their own.
+#[derive(Clone, PartialEq, Eq, Default, Debug)]
+pub struct VarGroups {
+    /// Sorted and disjoint ranges `start..end` with at least two levels each
+    groups: Vec<Range<LevelNo>>,
+}
+
+impl VarGroups {
+    /// Create an empty registry (every level forms a group on its own)
+    #[inline]
+    pub const fn new() -> Self {
+        Self { groups: Vec::new() }
+    }
+
+    /// Returns `true` iff there are no groups with more than one level
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.groups.is_empty()
+    }
+
+    /// Get the number of groups with more than one level
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.groups.len()
+    }
+
+    /// Iterate over all groups with more than one level (from top to bottom)
+    #[inline]
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = Range<LevelNo>> + '_ {
+        self.groups.iter().cloned()
+    }
+
+    /// Declare the levels in `range` as a group
+    ///
+    /// Existing groups contained in `range` are merged into the new group.
+    /// Adding an empty range or a range with a single level does nothing.
+    ///
+    /// Panics if `range` partially overlaps with an existing group.
+    pub fn add(&mut self, range: Range<LevelNo>) {
+        if range.len() <= 1 {
+            return;
+        }
+        let first = self.groups.partition_point(|g| g.end <= range.start);
+        let last = self.groups.partition_point(|g| g.start < range.end);
+        for g in &self.groups[first..last] {
+            assert!(
+                range.start <= g.start && g.end <= range.end,
+                "group {range:?} partially overlaps with existing group {g:?}"
+            );
+        }
+        self.groups.splice(first..last, [range]);
+    }
+
+    /// Remove the group containing `level`
+    ///
+    /// Returns `true` if there was a group with more than one level.
+    pub fn remove(&mut self, level: LevelNo) -> bool {
+        let i = self.groups.partition_point(|g| g.end <= level);
+        if i < self.groups.len() && self.groups[i].start <= level {
+            self.groups.remove(i);
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Remove all groups
+    #[inline]
+    pub fn clear(&mut self) {
+        self.groups.clear();
+    }
+
+    /// Get the group containing `level`
+    pub fn group(&self, level: LevelNo) -> Range<LevelNo> {
+        let i = self.groups.partition_point(|g| g.end <= level);
+        match self.groups.get(i) {
+            Some(g) if g.start <= level => g.clone(),
+            _ => level..level + 1,
+        }
+    }
+
+    /// Get the sizes of all groups (including single levels) partitioning the
+    /// levels `0..num_levels` (from top to bottom)
+    ///
+    /// Groups reaching beyond `num_levels` are truncated.
+    pub fn block_sizes(&self, num_levels: LevelNo) -> Vec<LevelNo> {
+        let mut sizes = Vec::with_capacity(num_levels as usize);
+        let mut level = 0;
+        for g in &self.groups {
+            if g.start >= num_levels {
+                break;
+            }
+            sizes.extend((level..g.start).map(|_| 1));
+            sizes.push(std::cmp::min(g.end, num_levels) - g.start);
+            level = g.end;
+        }
+        sizes.extend((level..num_levels).map(|_| 1));
+        sizes
+    }
+
+    /// Replace the groups by the ones given via `sizes` (as returned by
+    /// [`Self::block_sizes()`])
+    ///
+    /// This is useful to update the groups after reordering.
+    pub fn set_block_sizes(&mut self, sizes: &[LevelNo]) {
+        self.groups.clear();
+        let mut level = 0;
+        for &size in sizes {
+            if size > 1 {
+                self.groups.push(level..level + size);
+            }
+            level += size;
+        }
+    }
+}
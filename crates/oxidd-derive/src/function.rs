@@ -214,10 +214,15 @@ pub fn derive_function(input: syn::DeriveInput) -> TokenStream {
 enum Method {
     Terminal(&'static str),
     NewVar(&'static str),
+    /// Constructor taking the manager and a single argument of the given type
+    Constructor(&'static str, &'static str),
     Unary(&'static str),
     UnaryOwned(&'static str),
     Binary(&'static str),
     Ternary(&'static str),
+    /// Binary method with an additional `BooleanOperator` and a set of
+    /// variables
+    ApplyQuant(&'static str),
 }
 
 impl Method {
@@ -263,6 +268,25 @@ impl Method {
                 }
             }
 
+            Method::Constructor(n, arg_ty) => {
+                let method = syn::Ident::new(n, Span::call_site());
+                let method_edge = syn::Ident::new(&format!("{n}_edge"), Span::call_site());
+                let arg_ty: syn::Type = syn::parse_str(arg_ty).unwrap();
+                let func = struct_field
+                    .gen_from_inner(quote!(<#inner as #trait_path>::#method(manager, arg)?));
+
+                quote! {
+                    #[inline]
+                    fn #method<'__id>(manager: &#manager_ty, arg: #arg_ty) -> ::oxidd_core::util::AllocResult<Self> {
+                        ::std::result::Result::Ok(#func)
+                    }
+                    #[inline]
+                    fn #method_edge<'__id>(manager: &#manager_ty, arg: #arg_ty) -> ::oxidd_core::util::AllocResult<#edge_ty> {
+                        <#inner as #trait_path>::#method_edge(manager, arg)
+                    }
+                }
+            }
+
             Method::Unary(n) => {
                 let method = syn::Ident::new(n, Span::call_site());
                 let method_edge = syn::Ident::new(&format!("{n}_edge"), Span::call_site());
@@ -333,6 +357,25 @@ impl Method {
                     }
                 }
             }
+
+            Method::ApplyQuant(n) => {
+                let method = syn::Ident::new(n, Span::call_site());
+                let method_edge = syn::Ident::new(&format!("{n}_edge"), Span::call_site());
+                let func = struct_field.gen_from_inner(
+                    quote!(#trait_path::#method(&self.#field, op, &rhs.#field, &vars.#field)?),
+                );
+
+                quote! {
+                    #[inline]
+                    fn #method(&self, op: ::oxidd_core::function::BooleanOperator, rhs: &Self, vars: &Self) -> ::oxidd_core::util::AllocResult<Self> {
+                        ::std::result::Result::Ok(#func)
+                    }
+                    #[inline]
+                    fn #method_edge<'__id>(manager: &#manager_ty, op: ::oxidd_core::function::BooleanOperator, lhs: &#edge_ty, rhs: &#edge_ty, vars: &#edge_ty) -> ::oxidd_core::util::AllocResult<#edge_ty> {
+                        <#inner as #trait_path>::#method_edge(manager, op, lhs, rhs, vars)
+                    }
+                }
+            }
         }
     }
 }
@@ -436,6 +479,8 @@ pub fn derive_boolean_function(input: syn::DeriveInput) -> TokenStream {
             Terminal("f"),
             Terminal("t"),
             NewVar("new_var"),
+            Constructor("cube", "&[(::oxidd_core::LevelNo, bool)]"),
+            Constructor("clause", "&[(::oxidd_core::LevelNo, bool)]"),
             Unary("not"),
             UnaryOwned("not"),
             Binary("and"),
@@ -447,6 +492,7 @@ pub fn derive_boolean_function(input: syn::DeriveInput) -> TokenStream {
             Binary("imp"),
             Binary("imp_strict"),
             Ternary("ite"),
+            Unary("support"),
         ],
         |ctx| {
             let CustomMethodsCtx {
@@ -462,6 +508,8 @@ pub fn derive_boolean_function(input: syn::DeriveInput) -> TokenStream {
             let from_ff = struct_field.gen_from_inner(quote!(ff));
 
             quote! {
+                const SKIPPED_VAR: ::oxidd_core::util::OptBool = <#inner as #trait_path>::SKIPPED_VAR;
+
                 #[inline]
                 fn cofactors(&self) -> ::std::option::Option<(Self, Self)> {
                     let (ft, ff) = <#inner as #trait_path>::cofactors(&self.#field)?;
@@ -535,6 +583,33 @@ pub fn derive_boolean_function(input: syn::DeriveInput) -> TokenStream {
                     <#inner as #trait_path>::sat_count_edge(manager, edge, vars, cache)
                 }
 
+                #[inline]
+                fn weighted_sat_count<__W, __S>(
+                    &self,
+                    weights: impl ::std::ops::Fn(::oxidd_core::LevelNo) -> (__W, __W),
+                    cache: &mut ::oxidd_core::util::WeightedSatCountCache<__W, __S>,
+                ) -> __W
+                where
+                    __W: ::oxidd_core::util::WeightedCountNumber,
+                    __S: ::std::hash::BuildHasher,
+                {
+                    <#inner as #trait_path>::weighted_sat_count(&self.#field, weights, cache)
+                }
+
+                #[inline]
+                fn weighted_sat_count_edge<'__id, __W, __S>(
+                    manager: &#manager_ty,
+                    edge: &#edge_ty,
+                    weights: impl ::std::ops::Fn(::oxidd_core::LevelNo) -> (__W, __W),
+                    cache: &mut ::oxidd_core::util::WeightedSatCountCache<__W, __S>,
+                ) -> __W
+                where
+                    __W: ::oxidd_core::util::WeightedCountNumber,
+                    __S: ::std::hash::BuildHasher,
+                {
+                    <#inner as #trait_path>::weighted_sat_count_edge(manager, edge, weights, cache)
+                }
+
                 #[inline]
                 fn pick_cube<'__a, __I: ::std::iter::ExactSizeIterator<Item = &'__a Self>>(
                     &'__a self,
@@ -610,11 +685,36 @@ pub fn derive_boolean_function_quant(input: syn::DeriveInput) -> TokenStream {
         "BooleanFunctionQuant",
         &[
             Binary("restrict"),
+            Binary("constrain"),
+            Binary("minimize"),
+            Binary("li_compaction"),
             Binary("forall"),
             Binary("exist"),
             Binary("unique"),
+            ApplyQuant("apply_exist"),
+            ApplyQuant("apply_forall"),
+            ApplyQuant("apply_unique"),
         ],
-        |_| TokenStream::new(),
+        |ctx| {
+            let CustomMethodsCtx {
+                trait_path,
+                manager_ty,
+                edge_ty,
+                struct_field,
+            } = ctx;
+            let inner = &struct_field.ty;
+
+            quote! {
+                #[inline]
+                fn isop_edge<'__id>(
+                    manager: &#manager_ty,
+                    lower: &#edge_ty,
+                    upper: &#edge_ty,
+                ) -> ::oxidd_core::util::AllocResult<#edge_ty> {
+                    <#inner as #trait_path>::isop_edge(manager, lower, upper)
+                }
+            }
+        },
     )
 }
 
@@ -627,6 +727,8 @@ pub fn derive_boolean_vec_set(input: syn::DeriveInput) -> TokenStream {
             NewVar("new_singleton"),
             Terminal("empty"),
             Terminal("base"),
+            Constructor("set", "&[::oxidd_core::LevelNo]"),
+            Constructor("family", "&[&[::oxidd_core::LevelNo]]"),
             Binary("subset0"),
             Binary("subset1"),
             Binary("change"),
@@ -651,6 +753,7 @@ pub fn derive_pseudo_boolean_function(input: syn::DeriveInput) -> TokenStream {
             Binary("div"),
             Binary("min"),
             Binary("max"),
+            Unary("support"),
         ],
         |ctx| {
             let CustomMethodsCtx {
@@ -701,6 +804,7 @@ pub fn derive_tvl_function(input: syn::DeriveInput) -> TokenStream {
             Binary("imp"),
             Binary("imp_strict"),
             Ternary("ite"),
+            Unary("support"),
         ],
         |ctx| {
             let CustomMethodsCtx {
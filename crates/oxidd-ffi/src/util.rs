@@ -1,3 +1,4 @@
+use oxidd_core::util::OptBool;
 use oxidd_core::util::Substitution;
 
 /// Level number type
@@ -20,6 +21,36 @@ pub struct oxidd_assignment_t {
     pub len: usize,
 }
 
+impl oxidd_assignment_t {
+    /// Assignment with `NULL` data pointer
+    pub(crate) const NONE: Self = Self {
+        data: std::ptr::null_mut(),
+        len: 0,
+    };
+}
+
+impl From<Option<Vec<OptBool>>> for oxidd_assignment_t {
+    fn from(value: Option<Vec<OptBool>>) -> Self {
+        match value {
+            Some(v) => {
+                // `OptBool` is `repr(i8)`
+                let mut v = std::mem::ManuallyDrop::new(v.into_boxed_slice());
+                Self {
+                    data: v.as_mut_ptr() as _,
+                    len: v.len(),
+                }
+            }
+            None => Self::NONE,
+        }
+    }
+}
+
+impl From<Option<Vec<bool>>> for oxidd_assignment_t {
+    fn from(value: Option<Vec<bool>>) -> Self {
+        Self::from(value.map(|v| v.into_iter().map(OptBool::from).collect::<Vec<_>>()))
+    }
+}
+
 /// Free the given assignment
 ///
 /// To uphold Rust's invariants, all values in the assignment must be 0, 1, or
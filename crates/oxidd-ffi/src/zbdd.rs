@@ -5,7 +5,9 @@ use rustc_hash::FxHasher;
 
 use oxidd::util::num::F64;
 use oxidd::util::AllocResult;
+use oxidd::util::Assignments;
 use oxidd::util::Borrowed;
+use oxidd::util::Cubes;
 use oxidd::util::OutOfMemory;
 use oxidd::zbdd::ZBDDFunction;
 use oxidd::zbdd::ZBDDManagerRef;
@@ -697,19 +699,127 @@ pub unsafe extern "C" fn oxidd_zbdd_sat_count_double(
 ///           oxidd_assignment_free().
 #[no_mangle]
 pub unsafe extern "C" fn oxidd_zbdd_pick_cube(f: oxidd_zbdd_t) -> oxidd_assignment_t {
-    let res = f.get().expect(FUNC_UNWRAP_MSG).pick_cube([], |_, _| false);
-    match res {
-        Some(mut v) => {
-            v.shrink_to_fit();
-            let len = v.len();
-            let data = v.as_mut_ptr() as _;
-            std::mem::forget(v);
-            oxidd_assignment_t { data, len }
-        }
-        None => oxidd_assignment_t {
-            data: std::ptr::null_mut(),
-            len: 0,
-        },
+    f.get()
+        .expect(FUNC_UNWRAP_MSG)
+        .pick_cube([], |_, _| false)
+        .into()
+}
+
+/// Iterator over the cubes of a ZBDD function
+///
+/// Created by oxidd_zbdd_cubes(), to be freed via oxidd_zbdd_cube_iter_free().
+pub struct oxidd_zbdd_cube_iter_t(Cubes<ZBDDFunction>);
+
+/// Create an iterator over the cubes of `f`
+///
+/// Each cube corresponds to a path from the root to a satisfying terminal. The
+/// i-th entry of a cube refers to the variable currently at level i and is
+/// either 0 (false), 1 (true), or -1 (don't care). The cubes are pairwise
+/// disjoint. The iterator only stores the current path and computes the next
+/// cube on demand via oxidd_zbdd_cube_iter_next().
+///
+/// Reordering the variables while iterating yields unspecified results.
+///
+/// Locking behavior: acquires the manager's lock for shared access.
+///
+/// @param  f  A *valid* ZBDD function
+///
+/// @returns  The iterator, to be freed via oxidd_zbdd_cube_iter_free()
+#[no_mangle]
+pub unsafe extern "C" fn oxidd_zbdd_cubes(f: oxidd_zbdd_t) -> *mut oxidd_zbdd_cube_iter_t {
+    let cubes = f.get().expect(FUNC_UNWRAP_MSG).cubes();
+    Box::into_raw(Box::new(oxidd_zbdd_cube_iter_t(cubes)))
+}
+
+/// Get the next cube from `iter`
+///
+/// Locking behavior: acquires the manager's lock for shared access.
+///
+/// @param  iter  The iterator, must not be `NULL`
+///
+/// @returns  The next cube. If there are no more cubes, the data pointer is
+///           `NULL` and len is 0. In any case, the cube can be deallocated
+///           using oxidd_assignment_free().
+#[no_mangle]
+pub unsafe extern "C" fn oxidd_zbdd_cube_iter_next(
+    iter: *mut oxidd_zbdd_cube_iter_t,
+) -> oxidd_assignment_t {
+    assert!(!iter.is_null(), "iter must not be NULL");
+    (*iter).0.next().into()
+}
+
+/// Free the given cube iterator
+///
+/// If `iter` is `NULL`, this is a no-op.
+#[no_mangle]
+pub unsafe extern "C" fn oxidd_zbdd_cube_iter_free(iter: *mut oxidd_zbdd_cube_iter_t) {
+    if !iter.is_null() {
+        drop(Box::from_raw(iter))
+    }
+}
+
+/// Iterator over the satisfying assignments of a ZBDD function
+///
+/// Created by oxidd_zbdd_assignments(), to be freed via
+/// oxidd_zbdd_assignment_iter_free().
+pub struct oxidd_zbdd_assignment_iter_t(Assignments<ZBDDFunction>);
+
+/// Create an iterator over the satisfying assignments of `f`
+///
+/// The i-th entry of an assignment is the value (0 or 1) of `vars[i]`. This is
+/// like oxidd_zbdd_cubes(), but with the "don't cares" expanded. If `vars`
+/// contains all variables `f` depends on, the assignments are pairwise
+/// distinct.
+///
+/// Locking behavior: acquires the manager's lock for shared access.
+///
+/// @param  f         A *valid* ZBDD function
+/// @param  vars      Array of *valid* ZBDD functions, each referring to an
+///                   inner node (typically the singleton sets)
+/// @param  num_vars  Length of `vars`
+///
+/// @returns  The iterator, to be freed via oxidd_zbdd_assignment_iter_free()
+#[no_mangle]
+pub unsafe extern "C" fn oxidd_zbdd_assignments(
+    f: oxidd_zbdd_t,
+    vars: *const oxidd_zbdd_t,
+    num_vars: usize,
+) -> *mut oxidd_zbdd_assignment_iter_t {
+    let vars: Vec<_> = std::slice::from_raw_parts(vars, num_vars)
+        .iter()
+        .map(|v| v.get().expect(FUNC_UNWRAP_MSG))
+        .collect();
+    let assignments = f
+        .get()
+        .expect(FUNC_UNWRAP_MSG)
+        .assignments(vars.iter().map(|v| &**v));
+    Box::into_raw(Box::new(oxidd_zbdd_assignment_iter_t(assignments)))
+}
+
+/// Get the next assignment from `iter`
+///
+/// Locking behavior: acquires the manager's lock for shared access.
+///
+/// @param  iter  The iterator, must not be `NULL`
+///
+/// @returns  The next assignment. If there are no more assignments, the data
+///           pointer is `NULL` and len is 0. In any case, the assignment can
+///           be deallocated using oxidd_assignment_free().
+#[no_mangle]
+pub unsafe extern "C" fn oxidd_zbdd_assignment_iter_next(
+    iter: *mut oxidd_zbdd_assignment_iter_t,
+) -> oxidd_assignment_t {
+    assert!(!iter.is_null(), "iter must not be NULL");
+    (*iter).0.next().into()
+}
+
+/// Free the given assignment iterator
+///
+/// If `iter` is `NULL`, this is a no-op.
+#[no_mangle]
+pub unsafe extern "C" fn oxidd_zbdd_assignment_iter_free(iter: *mut oxidd_zbdd_assignment_iter_t) {
+    if !iter.is_null() {
+        drop(Box::from_raw(iter))
     }
 }
 
@@ -22,6 +22,8 @@ use std::hash::Hasher;
 use std::iter::FusedIterator;
 use std::marker::PhantomData;
 use std::mem::ManuallyDrop;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::AtomicI32;
 use std::sync::atomic::Ordering::{Acquire, Relaxed};
 use std::sync::Arc;
 
@@ -39,9 +41,12 @@ use rustc_hash::FxHasher;
 
 use oxidd_core::util::AbortOnDrop;
 use oxidd_core::util::AllocResult;
+use oxidd_core::util::AutoReorder;
 use oxidd_core::util::Borrowed;
 use oxidd_core::util::DropWith;
 use oxidd_core::util::OutOfMemory;
+use oxidd_core::util::ReorderHook;
+use oxidd_core::util::VarGroups;
 use oxidd_core::DiagramRules;
 use oxidd_core::InnerNode;
 use oxidd_core::LevelNo;
@@ -99,7 +104,8 @@ pub trait ManagerDataCons<
     type T<'id>: Send
         + Sync
         + DropWith<Edge<'id, NC::T<'id>, ET>>
-        + GCContainer<Manager<'id, NC::T<'id>, ET, TMC::T<'id>, RC::T<'id>, Self::T<'id>, TERMINALS>>;
+        + GCContainer<Manager<'id, NC::T<'id>, ET, TMC::T<'id>, RC::T<'id>, Self::T<'id>, TERMINALS>>
+        + ReorderHook<Manager<'id, NC::T<'id>, ET, TMC::T<'id>, RC::T<'id>, Self::T<'id>, TERMINALS>>;
 }
 
 // === Manager & Edges =========================================================
@@ -123,6 +129,15 @@ where
     terminal_manager: TM,
     state: CachePadded<Mutex<SharedStoreState>>,
     gc_signal: (Mutex<GCSignal>, Condvar),
+    /// Set if the node count exceeded the automatic reordering threshold, see
+    /// [`SharedStoreState::reorder_threshold`]
+    reorder_requested: AtomicBool,
+    /// Local node count change at which a worker reports to the shared state
+    /// immediately (`i32::MAX` if automatic reordering is disabled)
+    ///
+    /// Without automatic reordering, workers only report when requesting a new
+    /// chunk. With small reordering thresholds, this would be too coarse.
+    reorder_sync_delta: AtomicI32,
 }
 
 unsafe impl<'id, N, ET, TM, R, MD, const TERMINALS: usize> Sync
@@ -180,6 +195,10 @@ struct SharedStoreState {
     gc_lwm: u32,
     /// High water mark for background garbage collection (see [`GCState`])
     gc_hwm: u32,
+
+    /// Node count at which automatic reordering is requested (`u32::MAX` if
+    /// automatic reordering is disabled)
+    reorder_threshold: u32,
 }
 
 #[repr(align(64))] // all fields on a single cache line
@@ -275,6 +294,8 @@ where
     /// reference, but this leads to provenance issues.
     store: *const Store<'id, N, ET, TM, R, MD, TERMINALS>,
     reorder_count: u64,
+    var_groups: VarGroups,
+    auto_reorder: Option<AutoReorder<Self>>,
     gc_ongoing: TryLock,
     workers: ThreadPool,
 }
@@ -569,7 +590,7 @@ where
                     // SAFETY: `id` is the ID of a free slot, we have exclusive access
                     let (next_free, slot) = unsafe { self.use_free_slot(id) };
                     state.next_free.set(next_free);
-                    state.node_count_delta.set(delta);
+                    self.set_local_node_count_delta(state, delta);
                     return Ok((id, slot));
                 }
 
@@ -583,7 +604,7 @@ where
                     // access to them.
                     let slot = unsafe { &mut *slots.get_unchecked(index as usize).get() };
                     state.initialized.set(index + 1);
-                    state.node_count_delta.set(delta);
+                    self.set_local_node_count_delta(state, delta);
                     return Ok((index + TERMINALS as u32, slot));
                 }
 
@@ -607,6 +628,67 @@ where
         }
     }
 
+    /// Set the local node count change to `delta`, reporting it to the shared
+    /// state if it reaches [`Self::reorder_sync_delta`]
+    #[inline(always)]
+    fn set_local_node_count_delta(&self, local: &LocalStoreState, delta: i32) {
+        if delta < self.reorder_sync_delta.load(Relaxed) {
+            local.node_count_delta.set(delta);
+        } else {
+            self.report_node_count_delta(local, delta);
+        }
+    }
+
+    #[cold]
+    fn report_node_count_delta(&self, local: &LocalStoreState, delta: i32) {
+        local.node_count_delta.set(0);
+        let mut shared = self.state.lock();
+        shared.node_count = shared.node_count.wrapping_add_signed(delta);
+        self.check_node_count(&mut shared);
+    }
+
+    /// Trigger garbage collection or reordering if the node count exceeds the
+    /// respective thresholds
+    #[inline]
+    fn check_node_count(&self, shared: &mut SharedStoreState) {
+        if shared.gc_state == GCState::Init && shared.node_count >= shared.gc_hwm {
+            shared.gc_state = GCState::Triggered;
+            self.gc_signal.1.notify_one();
+        }
+        if shared.node_count >= shared.reorder_threshold {
+            self.reorder_requested.store(true, Relaxed);
+        }
+    }
+
+    /// Set the node count at which automatic reordering is requested
+    ///
+    /// `None` disables automatic reordering.
+    ///
+    /// Also reports the current thread's local node count change. Reordering
+    /// typically frees many nodes, and the respective local change might
+    /// otherwise stay unreported for a long time.
+    fn set_reorder_threshold(&self, threshold: Option<usize>) {
+        let (threshold, sync_delta) = match threshold {
+            Some(threshold) => {
+                let threshold = std::cmp::min(threshold, u32::MAX as usize - 1) as u32;
+                (threshold, (threshold / 64).clamp(1, CHUNK_SIZE) as i32)
+            }
+            None => (u32::MAX, i32::MAX),
+        };
+        let mut shared = self.state.lock();
+        LOCAL_STORE_STATE.with(|local| {
+            if local.current_store.get() == self.addr() {
+                shared.node_count = shared
+                    .node_count
+                    .wrapping_add_signed(local.node_count_delta.replace(0));
+            }
+        });
+        shared.reorder_threshold = threshold;
+        drop(shared);
+        self.reorder_sync_delta.store(sync_delta, Relaxed);
+        self.reorder_requested.store(false, Relaxed);
+    }
+
     /// Get the free slot with ID `id` and load the `next_free` ID
     ///
     /// SAFETY: `id` must be the ID of a free slot and the current thread must
@@ -635,10 +717,7 @@ where
             let mut shared = self.state.lock();
 
             shared.node_count = shared.node_count.wrapping_add_signed(delta);
-            if shared.gc_state == GCState::Init && shared.node_count >= shared.gc_hwm {
-                shared.gc_state = GCState::Triggered;
-                self.gc_signal.1.notify_one();
-            }
+            self.check_node_count(&mut shared);
 
             if local.current_store.get() == self.addr() {
                 debug_assert_eq!(local.next_free.get(), 0);
@@ -880,12 +959,19 @@ where
         }
 
         LOCAL_STORE_STATE.with(|local| {
-            if self.0.addr() == local.current_store.get()
-                && (local.next_free.get() != 0
-                    || local.initialized.get() % CHUNK_SIZE != 0
-                    || local.node_count_delta.get() != 0)
+            if self.0.addr() != local.current_store.get() {
+                return;
+            }
+            if local.next_free.get() != 0
+                || local.initialized.get() % CHUNK_SIZE != 0
+                || local.node_count_delta.get() != 0
             {
                 drop_slow(&self.0.inner_nodes.slots, &self.0.state, TERMINALS as u32);
+            } else {
+                // Even if there is nothing to synchronize, we must detach the
+                // local state. Otherwise, later operations on this thread would
+                // not get a guard and never give back their reserved slots.
+                local.current_store.set(0);
             }
         });
     }
@@ -906,6 +992,30 @@ where
         // containing `Store`.
         unsafe { &*self.store }
     }
+
+    /// Get the configuration of automatic dynamic reordering
+    #[inline]
+    pub fn auto_reorder(&self) -> Option<&AutoReorder<Self>> {
+        self.auto_reorder.as_ref()
+    }
+
+    /// Enable (`Some`) or disable (`None`) automatic dynamic reordering
+    ///
+    /// Note that the node count used for triggering reordering is only
+    /// eventually consistent: Threads report their allocations to the shared
+    /// state in batches (of at most 1/64 of the threshold). Furthermore, the
+    /// node count includes dead nodes that have not been garbage collected
+    /// yet.
+    ///
+    /// Panics if `config.growth <= 1.0`.
+    pub fn set_auto_reorder(&mut self, config: Option<AutoReorder<Self>>) {
+        if let Some(config) = &config {
+            assert!(config.growth > 1.0, "growth factor must be greater than 1");
+        }
+        self.store()
+            .set_reorder_threshold(config.as_ref().map(|config| config.threshold));
+        self.auto_reorder = config;
+    }
 }
 
 unsafe impl<'id, N, ET, TM, R, MD, const TERMINALS: usize> oxidd_core::Manager
@@ -915,7 +1025,7 @@ where
     ET: Tag,
     TM: TerminalManager<'id, N, ET, TERMINALS>,
     R: oxidd_core::DiagramRules<Edge<'id, N, ET>, N, TM::TerminalNode>,
-    MD: DropWith<Edge<'id, N, ET>> + GCContainer<Self>,
+    MD: DropWith<Edge<'id, N, ET>> + GCContainer<Self> + ReorderHook<Self>,
 {
     type Edge = Edge<'id, N, ET>;
     type EdgeTag = ET;
@@ -1061,6 +1171,7 @@ where
         let guard = AbortOnDrop("Reordering panicked.");
         self.data.pre_gc(self);
         let res = f(self);
+        MD::post_reorder(self);
         // SAFETY: We called `pre_gc`, the reordering is done.
         unsafe { self.data.post_gc(self) };
         guard.defuse();
@@ -1072,6 +1183,39 @@ where
     fn reorder_count(&self) -> u64 {
         self.reorder_count
     }
+
+    #[inline]
+    fn var_groups(&self) -> &VarGroups {
+        &self.var_groups
+    }
+
+    #[inline]
+    fn var_groups_mut(&mut self) -> Option<&mut VarGroups> {
+        Some(&mut self.var_groups)
+    }
+
+    #[inline]
+    fn reorder_requested(&self) -> bool {
+        self.store().reorder_requested.load(Relaxed)
+    }
+
+    fn reorder_if_requested(&mut self) -> bool {
+        if !self.store().reorder_requested.load(Relaxed) {
+            return false;
+        }
+        let Some(config) = self.auto_reorder else {
+            self.store().reorder_requested.store(false, Relaxed);
+            return false;
+        };
+
+        (config.heuristic)(self);
+
+        // This also resets the request (the heuristic may have triggered
+        // another one, but we just reordered).
+        let threshold = config.next_threshold(self.num_inner_nodes());
+        self.store().set_reorder_threshold(Some(threshold));
+        true
+    }
 }
 
 impl<'id, N, ET, TM, R, MD, const TERMINALS: usize> oxidd_core::WorkerManager
@@ -1081,7 +1225,7 @@ where
     ET: Tag + Send + Sync,
     TM: TerminalManager<'id, N, ET, TERMINALS> + Send + Sync,
     R: oxidd_core::DiagramRules<Edge<'id, N, ET>, N, TM::TerminalNode>,
-    MD: DropWith<Edge<'id, N, ET>> + GCContainer<Self> + Send + Sync,
+    MD: DropWith<Edge<'id, N, ET>> + GCContainer<Self> + ReorderHook<Self> + Send + Sync,
 {
     #[inline]
     fn current_num_threads(&self) -> usize {
@@ -1476,7 +1620,7 @@ where
 
     #[inline(always)]
     unsafe fn swap(&mut self, other: &mut Self) {
-        std::mem::swap(&mut self.set, &mut other.set);
+        std::mem::swap(&mut *self.set, &mut *other.set);
     }
 
     #[inline]
@@ -2007,17 +2151,22 @@ pub fn new_manager<
             },
             gc_lwm,
             gc_hwm,
+            reorder_threshold: u32::MAX,
         })),
         manager: RwLock::new(Manager {
             unique_table: Vec::new(),
             data: ManuallyDrop::new(data),
             store: std::ptr::null(),
             reorder_count: 0,
+            var_groups: VarGroups::new(),
+            auto_reorder: None,
             gc_ongoing: TryLock::new(),
             workers,
         }),
         terminal_manager: TMC::T::<'static>::with_capacity(terminal_node_capacity),
         gc_signal: (Mutex::new(GCSignal::RunGc), Condvar::new()),
+        reorder_requested: AtomicBool::new(false),
+        reorder_sync_delta: AtomicI32::new(i32::MAX),
     });
 
     let mut manager = arc.manager.write();
@@ -2397,7 +2546,7 @@ impl<
         ET: Tag,
         TM: TerminalManager<'id, N, ET, TERMINALS>,
         R: DiagramRules<Edge<'id, N, ET>, N, TM::TerminalNode>,
-        MD: oxidd_core::HasApplyCache<Self, O> + GCContainer<Self> + DropWith<Edge<'id, N, ET>>,
+        MD: oxidd_core::HasApplyCache<Self, O> + GCContainer<Self> + ReorderHook<Self> + DropWith<Edge<'id, N, ET>>,
         O: Copy,
         const TERMINALS: usize,
     > oxidd_core::HasApplyCache<Self, O> for Manager<'id, N, ET, TM, R, MD, TERMINALS>
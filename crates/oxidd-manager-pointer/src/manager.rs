@@ -46,6 +46,8 @@ use oxidd_core::util::AbortOnDrop;
 use oxidd_core::util::AllocResult;
 use oxidd_core::util::Borrowed;
 use oxidd_core::util::DropWith;
+use oxidd_core::util::ReorderHook;
+use oxidd_core::util::VarGroups;
 use oxidd_core::DiagramRules;
 use oxidd_core::HasApplyCache;
 use oxidd_core::InnerNode;
@@ -128,6 +130,18 @@ pub trait ManagerDataCons<
                 PAGE_SIZE,
                 TAG_BITS,
             >,
+        >
+        + ReorderHook<
+            Manager<
+                'id,
+                NC::T<'id>,
+                ET,
+                TMC::T<'id>,
+                RC::T<'id>,
+                Self::T<'id>,
+                PAGE_SIZE,
+                TAG_BITS,
+            >,
         >;
 }
 
@@ -178,6 +192,7 @@ where
     store_inner: *const StoreInner<'id, N, ET, TM, R, MD, PAGE_SIZE, TAG_BITS>,
     gc_ongoing: TryLock,
     reorder_count: u64,
+    var_groups: VarGroups,
     workers: rayon::ThreadPool,
     phantom: PhantomData<(TM, R)>,
 }
@@ -298,6 +313,7 @@ where
             store_inner: slot,
             gc_ongoing: TryLock::new(),
             reorder_count: 0,
+            var_groups: VarGroups::new(),
             workers,
             phantom: PhantomData,
         });
@@ -613,7 +629,7 @@ where
     ET: Tag,
     TM: TerminalManager<'id, N, ET, MD, PAGE_SIZE, TAG_BITS>,
     R: DiagramRules<Edge<'id, N, ET, TAG_BITS>, N, TM::TerminalNode>,
-    MD: DropWith<Edge<'id, N, ET, TAG_BITS>> + GCContainer<Self>,
+    MD: DropWith<Edge<'id, N, ET, TAG_BITS>> + GCContainer<Self> + ReorderHook<Self>,
 {
     type Edge = Edge<'id, N, ET, TAG_BITS>;
     type EdgeTag = ET;
@@ -764,6 +780,7 @@ where
         let guard = AbortOnDrop("Reordering panicked.");
         self.data.pre_gc(self);
         let res = f(self);
+        MD::post_reorder(self);
         // SAFETY: We called `pre_gc()` and the reordering is done.
         unsafe { self.data.post_gc(self) };
         guard.defuse();
@@ -775,6 +792,16 @@ where
     fn reorder_count(&self) -> u64 {
         self.reorder_count
     }
+
+    #[inline]
+    fn var_groups(&self) -> &VarGroups {
+        &self.var_groups
+    }
+
+    #[inline]
+    fn var_groups_mut(&mut self) -> Option<&mut VarGroups> {
+        Some(&mut self.var_groups)
+    }
 }
 
 impl<'id, N, ET, TM, R, MD, const PAGE_SIZE: usize, const TAG_BITS: u32> oxidd_core::WorkerManager
@@ -784,7 +811,7 @@ where
     ET: Tag + Send + Sync,
     TM: TerminalManager<'id, N, ET, MD, PAGE_SIZE, TAG_BITS> + Send + Sync,
     R: DiagramRules<Edge<'id, N, ET, TAG_BITS>, N, TM::TerminalNode>,
-    MD: DropWith<Edge<'id, N, ET, TAG_BITS>> + GCContainer<Self> + Send + Sync,
+    MD: DropWith<Edge<'id, N, ET, TAG_BITS>> + GCContainer<Self> + ReorderHook<Self> + Send + Sync,
 {
     fn current_num_threads(&self) -> usize {
         self.workers.current_num_threads()
@@ -1228,7 +1255,8 @@ where
     TM: TerminalManager<'id, N, ET, MD, PAGE_SIZE, TAG_BITS>,
     R: DiagramRules<Edge<'id, N, ET, TAG_BITS>, N, TM::TerminalNode>,
     MD: DropWith<Edge<'id, N, ET, TAG_BITS>>
-        + GCContainer<Manager<'id, N, ET, TM, R, MD, PAGE_SIZE, TAG_BITS>>,
+        + GCContainer<Manager<'id, N, ET, TM, R, MD, PAGE_SIZE, TAG_BITS>>
+        + ReorderHook<Manager<'id, N, ET, TM, R, MD, PAGE_SIZE, TAG_BITS>>,
 {
     type Iterator<'b> = LevelViewIter<'b, 'id, N, ET, TAG_BITS>
     where
@@ -1344,7 +1372,8 @@ where
     TM: TerminalManager<'id, N, ET, MD, PAGE_SIZE, TAG_BITS>,
     R: DiagramRules<Edge<'id, N, ET, TAG_BITS>, N, TM::TerminalNode>,
     MD: DropWith<Edge<'id, N, ET, TAG_BITS>>
-        + GCContainer<Manager<'id, N, ET, TM, R, MD, PAGE_SIZE, TAG_BITS>>,
+        + GCContainer<Manager<'id, N, ET, TM, R, MD, PAGE_SIZE, TAG_BITS>>
+        + ReorderHook<Manager<'id, N, ET, TM, R, MD, PAGE_SIZE, TAG_BITS>>,
 {
     type Iterator<'b> = LevelViewIter<'b, 'id, N, ET, TAG_BITS>
     where
@@ -2113,7 +2142,7 @@ impl<
         ET: Tag,
         TM: TerminalManager<'id, N, ET, MD, PAGE_SIZE, TAG_BITS>,
         R: DiagramRules<Edge<'id, N, ET, TAG_BITS>, N, TM::TerminalNode>,
-        MD: HasApplyCache<Self, O> + GCContainer<Self> + DropWith<Edge<'id, N, ET, TAG_BITS>>,
+        MD: HasApplyCache<Self, O> + GCContainer<Self> + ReorderHook<Self> + DropWith<Edge<'id, N, ET, TAG_BITS>>,
         O: Copy,
         const PAGE_SIZE: usize,
         const TAG_BITS: u32,
@@ -29,6 +29,7 @@ use std::num::NonZeroUsize;
 use derive_builder::Builder;
 
 pub mod dimacs;
+pub mod static_order;
 mod util;
 
 #[cfg(feature = "load-file")]
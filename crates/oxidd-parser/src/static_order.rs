@@ -0,0 +1,716 @@
+//! Static variable order heuristics
+//!
+//! The heuristics in this module compute a variable order from the structure of
+//! a [`Problem`] without building a decision diagram. They view the problem as
+//! a hypergraph: The variables are the vertices, and every clause (or, for
+//! propositional formulas, every subformula below the top-level conjunction) is
+//! a hyperedge connecting the variables it contains. All heuristics try to
+//! place the variables of a hyperedge close to each other.
+
+// spell-checker:ignore Aloul,Sakallah,Fiduccia,Mattheyses,Cuthill
+
+use std::collections::BTreeSet;
+use std::collections::VecDeque;
+
+use crate::{Problem, Prop, Var, VarOrder};
+
+/// Maximal number of iterations of [`VarOrderHeuristic::Force`]
+const FORCE_MAX_ITERATIONS: usize = 256;
+
+/// Maximal number of Fiduccia–Mattheyses passes per bisection in
+/// [`VarOrderHeuristic::Mince`]
+const FM_MAX_PASSES: usize = 16;
+
+/// Static variable order heuristic
+#[non_exhaustive]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum VarOrderHeuristic {
+    /// FORCE heuristic by Aloul, Markov, and Sakallah
+    ///
+    /// Iteratively moves every variable to the average center of gravity of
+    /// its hyperedges until the total span of the hyperedges stops decreasing.
+    Force,
+    /// Recursive min-cut bisection in the spirit of MINCE by Aloul, Markov, and
+    /// Sakallah
+    ///
+    /// Splits the variables into two halves such that few hyperedges are cut
+    /// (using the Fiduccia–Mattheyses heuristic), places the first half above
+    /// the second one, and recurses on both halves.
+    Mince,
+    /// Reverse Cuthill–McKee ordering, aiming for a small bandwidth (i.e., a
+    /// small maximal distance between two variables of a common hyperedge)
+    Bandwidth,
+}
+
+impl VarOrderHeuristic {
+    /// Compute a variable order for `problem`
+    ///
+    /// The variable names are taken from the problem's variable order, if
+    /// present. Otherwise, the name of a variable is its number.
+    pub fn var_order(self, problem: &Problem) -> VarOrder {
+        let (num_vars, var_order) = match problem {
+            Problem::CNF {
+                num_vars,
+                var_order,
+                ..
+            }
+            | Problem::Prop {
+                num_vars,
+                var_order,
+                ..
+            } => (*num_vars, var_order),
+        };
+        let order = self.order(&Hypergraph::from_problem(problem));
+        debug_assert_eq!(order.len(), num_vars as usize);
+
+        let mut names: Vec<Option<&str>> = vec![None; num_vars as usize];
+        for (var, name) in var_order {
+            names[(var.get() - 1) as usize] = Some(name);
+        }
+        order
+            .into_iter()
+            .map(|i| {
+                let var = Var::new(i + 1).unwrap();
+                let name = match names[i as usize] {
+                    Some(name) => name.to_string(),
+                    None => var.to_string(),
+                };
+                (var, name)
+            })
+            .collect()
+    }
+
+    /// Compute a variable order for a problem with `num_vars` variables given
+    /// its hyperedges
+    ///
+    /// Every hyperedge is a list of variables. The result is a permutation of
+    /// all variables `1..=num_vars`, starting with the topmost variable.
+    pub fn order_hyperedges(self, num_vars: u32, hyperedges: &[Vec<Var>]) -> Vec<Var> {
+        let mut graph = Hypergraph::new(num_vars);
+        for edge in hyperedges {
+            graph.add_edge(edge.iter().map(|v| v.get() - 1).collect());
+        }
+        graph.finish();
+        self.order(&graph)
+            .into_iter()
+            .map(|i| Var::new(i + 1).unwrap())
+            .collect()
+    }
+
+    fn order(self, graph: &Hypergraph) -> Vec<u32> {
+        match self {
+            VarOrderHeuristic::Force => force(graph),
+            VarOrderHeuristic::Mince => mince(graph),
+            VarOrderHeuristic::Bandwidth => bandwidth(graph),
+        }
+    }
+}
+
+/// Hypergraph with 0-based variable indices as vertices
+struct Hypergraph {
+    /// Hyperedges, each one with at least two distinct vertices in ascending
+    /// order
+    edges: Vec<Vec<u32>>,
+    /// Indices of the hyperedges incident to each vertex
+    incident: Vec<Vec<u32>>,
+}
+
+impl Hypergraph {
+    fn new(num_vars: u32) -> Self {
+        Self {
+            edges: Vec::new(),
+            incident: vec![Vec::new(); num_vars as usize],
+        }
+    }
+
+    fn from_problem(problem: &Problem) -> Self {
+        match problem {
+            Problem::CNF {
+                num_vars, clauses, ..
+            } => {
+                let mut graph = Self::new(*num_vars);
+                for clause in clauses {
+                    graph.add_edge(clause.iter().map(|(v, _)| v.get() - 1).collect());
+                }
+                graph.finish();
+                graph
+            }
+            Problem::Prop { num_vars, ast, .. } => {
+                let mut graph = Self::new(*num_vars);
+                graph.add_prop(ast);
+                graph.finish();
+                graph
+            }
+        }
+    }
+
+    /// Add the hyperedges for `prop`, treating (nested) conjunctions at the
+    /// top like the clauses of a CNF
+    fn add_prop(&mut self, prop: &Prop) {
+        match prop {
+            Prop::And(ps) => {
+                for p in ps {
+                    self.add_prop(p);
+                }
+            }
+            _ => {
+                self.add_prop_rec(prop);
+            }
+        }
+    }
+
+    /// Add a hyperedge for every subformula of `prop` and return the variables
+    /// of `prop` (in ascending order without duplicates)
+    fn add_prop_rec(&mut self, prop: &Prop) -> Vec<u32> {
+        let ps = match prop {
+            Prop::Lit(v, _) => return vec![v.get() - 1],
+            Prop::Neg(p) => return self.add_prop_rec(p),
+            Prop::And(ps) | Prop::Or(ps) | Prop::Xor(ps) | Prop::Eq(ps) => ps,
+        };
+        let mut vars = Vec::new();
+        for p in ps {
+            vars.extend(self.add_prop_rec(p));
+        }
+        vars.sort_unstable();
+        vars.dedup();
+        self.add_edge(vars.clone());
+        vars
+    }
+
+    fn add_edge(&mut self, mut edge: Vec<u32>) {
+        edge.sort_unstable();
+        edge.dedup();
+        if edge.len() >= 2 {
+            self.edges.push(edge);
+        }
+    }
+
+    /// Compute the incidence lists
+    fn finish(&mut self) {
+        for (i, edge) in self.edges.iter().enumerate() {
+            for &v in edge {
+                self.incident[v as usize].push(i as u32);
+            }
+        }
+    }
+
+    fn num_vars(&self) -> usize {
+        self.incident.len()
+    }
+
+    /// Sum of the spans of all hyperedges, where `pos` maps vertices to their
+    /// positions
+    fn span(&self, pos: &[u32]) -> u64 {
+        self.edges
+            .iter()
+            .map(|edge| {
+                let (min, max) = edge.iter().fold((u32::MAX, 0), |(min, max), &v| {
+                    (min.min(pos[v as usize]), max.max(pos[v as usize]))
+                });
+                (max - min) as u64
+            })
+            .sum()
+    }
+}
+
+// --- FORCE -------------------------------------------------------------------
+
+fn force(graph: &Hypergraph) -> Vec<u32> {
+    let n = graph.num_vars();
+    let mut order: Vec<u32> = (0..n as u32).collect();
+    let mut pos: Vec<u32> = order.clone();
+    let mut best = order.clone();
+    let mut best_span = graph.span(&pos);
+
+    let mut cog = vec![0.0f64; graph.edges.len()];
+    let mut target = vec![0.0f64; n];
+    for _ in 0..FORCE_MAX_ITERATIONS {
+        for (c, edge) in cog.iter_mut().zip(&graph.edges) {
+            let sum: u64 = edge.iter().map(|&v| pos[v as usize] as u64).sum();
+            *c = sum as f64 / edge.len() as f64;
+        }
+        for (v, t) in target.iter_mut().enumerate() {
+            let incident = &graph.incident[v];
+            *t = if incident.is_empty() {
+                pos[v] as f64
+            } else {
+                incident.iter().map(|&e| cog[e as usize]).sum::<f64>() / incident.len() as f64
+            };
+        }
+
+        // `order` is sorted by the current positions, and the sort is stable,
+        // so ties are resolved in favor of the current order.
+        order.sort_by(|&a, &b| target[a as usize].total_cmp(&target[b as usize]));
+        for (i, &v) in order.iter().enumerate() {
+            pos[v as usize] = i as u32;
+        }
+
+        let span = graph.span(&pos);
+        if span >= best_span {
+            break;
+        }
+        best_span = span;
+        best.clone_from(&order);
+    }
+    best
+}
+
+// --- MINCE-style recursive bisection -----------------------------------------
+
+/// Marker for vertices not part of the current bisection
+const NO_SIDE: u8 = 2;
+
+struct Bisection<'a> {
+    graph: &'a Hypergraph,
+    /// Side (0 or 1) of each vertex, [`NO_SIDE`] for vertices not in the
+    /// current subset
+    side: Vec<u8>,
+    /// Current position of each vertex
+    pos: Vec<u32>,
+    /// Number of vertices of each hyperedge on either side
+    count: Vec<[u32; 2]>,
+    /// Number of vertices of each hyperedge outside the current subset that
+    /// are placed above or below the subset
+    fixed: Vec<[u32; 2]>,
+    edge_stamp: Vec<u32>,
+    stamp: u32,
+    gain: Vec<i64>,
+    locked: Vec<bool>,
+}
+
+fn mince(graph: &Hypergraph) -> Vec<u32> {
+    let n = graph.num_vars();
+    let mut order: Vec<u32> = (0..n as u32).collect();
+    let mut bisection = Bisection {
+        graph,
+        side: vec![NO_SIDE; n],
+        pos: order.clone(),
+        count: vec![[0; 2]; graph.edges.len()],
+        fixed: vec![[0; 2]; graph.edges.len()],
+        edge_stamp: vec![0; graph.edges.len()],
+        stamp: 0,
+        gain: vec![0; n],
+        locked: vec![false; n],
+    };
+    bisection.recurse(&mut order, 0);
+    order
+}
+
+impl Bisection<'_> {
+    /// Order `vars`, which are placed at positions `start..start + vars.len()`
+    fn recurse(&mut self, vars: &mut [u32], start: u32) {
+        if vars.len() <= 1 {
+            return;
+        }
+        let mid = self.partition(vars, start);
+        let (upper, lower) = vars.split_at_mut(mid);
+        self.recurse(upper, start);
+        self.recurse(lower, start + mid as u32);
+    }
+
+    /// Split `vars` into two parts of roughly equal size such that few
+    /// hyperedges are cut
+    ///
+    /// Rearranges `vars` such that the first part comes first (keeping the
+    /// relative order within the parts) and returns the size of the first part.
+    /// Vertices outside of `vars` are considered to be fixed on the upper or
+    /// lower side, depending on whether they are placed above or below `vars`
+    /// (terminal propagation).
+    fn partition(&mut self, vars: &mut [u32], start: u32) -> usize {
+        let graph = self.graph;
+        let len = vars.len();
+        let half = len / 2;
+        let tolerance = std::cmp::max(1, len / 10);
+        // Both parts must be non-empty for the recursion to terminate
+        let min_upper = std::cmp::max(1, half.saturating_sub(tolerance));
+        let max_upper = std::cmp::min(len - 1, half + tolerance);
+
+        for (i, &v) in vars.iter().enumerate() {
+            self.side[v as usize] = (i >= half) as u8;
+        }
+        let mut upper_size = half;
+
+        let end = start + len as u32;
+        self.stamp += 1;
+        for &v in &*vars {
+            for &e in &graph.incident[v as usize] {
+                if self.edge_stamp[e as usize] == self.stamp {
+                    continue;
+                }
+                self.edge_stamp[e as usize] = self.stamp;
+                let mut fixed = [0; 2];
+                for &u in &graph.edges[e as usize] {
+                    let pos = self.pos[u as usize];
+                    if pos < start {
+                        fixed[0] += 1;
+                    } else if pos >= end {
+                        fixed[1] += 1;
+                    }
+                }
+                self.fixed[e as usize] = fixed;
+            }
+        }
+
+        for _ in 0..FM_MAX_PASSES {
+            // Initialize counts and gains
+            for &v in &*vars {
+                for &e in &graph.incident[v as usize] {
+                    self.count[e as usize] = self.fixed[e as usize];
+                }
+            }
+            for &v in &*vars {
+                let side = self.side[v as usize] as usize;
+                for &e in &graph.incident[v as usize] {
+                    self.count[e as usize][side] += 1;
+                }
+            }
+            // Free vertices by gain, separately for either side such that
+            // selecting the best vertex allowed to move is logarithmic
+            let mut queues = [BTreeSet::new(), BTreeSet::new()];
+            for &v in &*vars {
+                let from = self.side[v as usize] as usize;
+                let gain = graph.incident[v as usize]
+                    .iter()
+                    .map(|&e| {
+                        let count = self.count[e as usize];
+                        (count[from] == 1) as i64 - (count[1 - from] == 0) as i64
+                    })
+                    .sum();
+                self.gain[v as usize] = gain;
+                self.locked[v as usize] = false;
+                queues[from].insert((gain, v));
+            }
+
+            // Move vertices greedily, remembering the best prefix
+            let mut moves = Vec::with_capacity(len);
+            let (mut total, mut best, mut best_len) = (0i64, 0i64, 0usize);
+            loop {
+                // Temporarily allow an imbalance of one more vertex, otherwise
+                // no vertex could ever move if `min_upper == max_upper`.
+                let movable = [upper_size >= min_upper, upper_size <= max_upper];
+                let Some(from) = (0..2)
+                    .filter(|&side| movable[side])
+                    .max_by_key(|&side| queues[side].last())
+                else {
+                    break;
+                };
+                let Some((gain, v)) = queues[from].pop_last() else {
+                    break;
+                };
+                self.move_vertex(v, &mut queues);
+                if self.side[v as usize] == 0 {
+                    upper_size += 1;
+                } else {
+                    upper_size -= 1;
+                }
+                moves.push(v);
+                total += gain;
+                if total > best && (min_upper..=max_upper).contains(&upper_size) {
+                    best = total;
+                    best_len = moves.len();
+                }
+            }
+
+            // Undo the moves after the best prefix
+            for &v in &moves[best_len..] {
+                let side = &mut self.side[v as usize];
+                *side = 1 - *side;
+                if *side == 0 {
+                    upper_size += 1;
+                } else {
+                    upper_size -= 1;
+                }
+            }
+            if best <= 0 {
+                break;
+            }
+        }
+
+        let mut upper: Vec<u32> = Vec::with_capacity(upper_size);
+        let mut lower: Vec<u32> = Vec::with_capacity(len - upper_size);
+        for &v in &*vars {
+            if self.side[v as usize] == 0 {
+                upper.push(v);
+            } else {
+                lower.push(v);
+            }
+            self.side[v as usize] = NO_SIDE;
+        }
+        debug_assert_eq!(upper.len(), upper_size);
+        vars[..upper_size].copy_from_slice(&upper);
+        vars[upper_size..].copy_from_slice(&lower);
+        for (pos, &v) in (start..).zip(&*vars) {
+            self.pos[v as usize] = pos;
+        }
+        upper_size
+    }
+
+    /// Move `v` to the other side, lock it, and update the gains of the
+    /// adjacent vertices (Fiduccia–Mattheyses)
+    fn move_vertex(&mut self, v: u32, queues: &mut [BTreeSet<(i64, u32)>; 2]) {
+        let from = self.side[v as usize] as usize;
+        let to = 1 - from;
+        let graph = self.graph;
+        self.locked[v as usize] = true;
+        self.side[v as usize] = to as u8;
+
+        // `v` is locked, so the updates below do not affect its gain
+        for &e in &graph.incident[v as usize] {
+            let edge = &graph.edges[e as usize];
+            let count = &mut self.count[e as usize];
+            let [from_count, to_count] = [count[from], count[to]];
+            count[from] -= 1;
+            count[to] += 1;
+
+            if to_count == 0 {
+                self.update_gains(edge, from, 1, queues);
+            } else if to_count == 1 {
+                self.update_gains(edge, to, -1, queues);
+            }
+            if from_count == 1 {
+                self.update_gains(edge, to, -1, queues);
+            } else if from_count == 2 {
+                self.update_gains(edge, from, 1, queues);
+            }
+        }
+    }
+
+    /// Add `delta` to the gains of all free vertices of `edge` on `side`
+    fn update_gains(
+        &mut self,
+        edge: &[u32],
+        side: usize,
+        delta: i64,
+        queues: &mut [BTreeSet<(i64, u32)>; 2],
+    ) {
+        for &u in edge {
+            let u_idx = u as usize;
+            if self.side[u_idx] as usize != side || self.locked[u_idx] {
+                continue;
+            }
+            let gain = &mut self.gain[u_idx];
+            queues[side].remove(&(*gain, u));
+            *gain += delta;
+            queues[side].insert((*gain, u));
+        }
+    }
+}
+
+// --- Reverse Cuthill–McKee ---------------------------------------------------
+
+/// Breadth-first search state, using stamps to avoid clearing the visited
+/// flags between searches
+struct Bfs<'a> {
+    graph: &'a Hypergraph,
+    degree: Vec<u64>,
+    vertex_stamp: Vec<u32>,
+    edge_stamp: Vec<u32>,
+    stamp: u32,
+}
+
+impl Bfs<'_> {
+    /// Run a breadth-first search starting at `root`, appending the visited
+    /// vertices to `out`
+    ///
+    /// Neighbors are visited in ascending order of their degree. Returns the
+    /// number of levels and the index in `out` where the deepest level starts.
+    fn run(&mut self, root: u32, out: &mut Vec<u32>) -> (usize, usize) {
+        self.stamp += 1;
+        let stamp = self.stamp;
+        self.vertex_stamp[root as usize] = stamp;
+        let mut last_level_start = out.len();
+        out.push(root);
+
+        let mut queue = VecDeque::from([(root, 0usize)]);
+        let mut depth = 0;
+        let mut neighbors = Vec::new();
+        while let Some((v, level)) = queue.pop_front() {
+            for &e in &self.graph.incident[v as usize] {
+                if self.edge_stamp[e as usize] == stamp {
+                    continue;
+                }
+                self.edge_stamp[e as usize] = stamp;
+                for &u in &self.graph.edges[e as usize] {
+                    if self.vertex_stamp[u as usize] != stamp {
+                        self.vertex_stamp[u as usize] = stamp;
+                        neighbors.push(u);
+                    }
+                }
+            }
+            if neighbors.is_empty() {
+                continue;
+            }
+            if level + 1 > depth {
+                depth = level + 1;
+                last_level_start = out.len();
+            }
+            neighbors.sort_by_key(|&u| self.degree[u as usize]);
+            queue.extend(neighbors.iter().map(|&u| (u, level + 1)));
+            out.append(&mut neighbors);
+        }
+        (depth + 1, last_level_start)
+    }
+
+    /// Find a pseudo-peripheral vertex in the component of `start`
+    /// (George–Liu)
+    fn pseudo_peripheral(&mut self, start: u32, buf: &mut Vec<u32>) -> u32 {
+        let mut root = start;
+        buf.clear();
+        let (mut levels, mut last_level_start) = self.run(root, buf);
+        loop {
+            let candidate = *buf[last_level_start..]
+                .iter()
+                .min_by_key(|&&u| self.degree[u as usize])
+                .unwrap();
+            buf.clear();
+            let (new_levels, new_last_level_start) = self.run(candidate, buf);
+            if new_levels <= levels {
+                return root;
+            }
+            root = candidate;
+            levels = new_levels;
+            last_level_start = new_last_level_start;
+        }
+    }
+}
+
+fn bandwidth(graph: &Hypergraph) -> Vec<u32> {
+    let n = graph.num_vars();
+    let degree: Vec<u64> = graph
+        .incident
+        .iter()
+        .map(|incident| {
+            incident
+                .iter()
+                .map(|&e| graph.edges[e as usize].len() as u64 - 1)
+                .sum()
+        })
+        .collect();
+    let mut by_degree: Vec<u32> = (0..n as u32).collect();
+    by_degree.sort_by_key(|&v| degree[v as usize]);
+
+    let mut bfs = Bfs {
+        graph,
+        degree,
+        vertex_stamp: vec![0; n],
+        edge_stamp: vec![0; graph.edges.len()],
+        stamp: 0,
+    };
+    let mut placed = vec![false; n];
+    let mut order = Vec::with_capacity(n);
+    let mut buf = Vec::new();
+    for v in by_degree {
+        if placed[v as usize] {
+            continue;
+        }
+        let root = bfs.pseudo_peripheral(v, &mut buf);
+        let start = order.len();
+        bfs.run(root, &mut order);
+        for &u in &order[start..] {
+            placed[u as usize] = true;
+        }
+    }
+    order.reverse();
+    order
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HEURISTICS: [VarOrderHeuristic; 3] = [
+        VarOrderHeuristic::Force,
+        VarOrderHeuristic::Mince,
+        VarOrderHeuristic::Bandwidth,
+    ];
+
+    fn v(v: u32) -> Var {
+        Var::new(v).unwrap()
+    }
+
+    /// Position of every variable in `order`, asserting that `order` is a
+    /// permutation of `1..=num_vars`
+    fn positions(num_vars: u32, order: &[Var]) -> Vec<u32> {
+        assert_eq!(order.len(), num_vars as usize);
+        let mut pos = vec![u32::MAX; num_vars as usize];
+        for (i, var) in order.iter().enumerate() {
+            assert_eq!(pos[var.get() as usize - 1], u32::MAX);
+            pos[var.get() as usize - 1] = i as u32;
+        }
+        pos
+    }
+
+    fn max_span(edges: &[Vec<Var>], pos: &[u32]) -> u32 {
+        edges
+            .iter()
+            .map(|edge| {
+                let p = edge.iter().map(|var| pos[var.get() as usize - 1]);
+                p.clone().max().unwrap() - p.min().unwrap()
+            })
+            .max()
+            .unwrap()
+    }
+
+    #[test]
+    fn pairs() {
+        // x_i and x_{n+i} should be placed next to each other
+        let n = 8;
+        let edges: Vec<Vec<Var>> = (1..=n).map(|i| vec![v(i), v(n + i)]).collect();
+        for heuristic in HEURISTICS {
+            let order = heuristic.order_hyperedges(2 * n, &edges);
+            let pos = positions(2 * n, &order);
+            assert_eq!(max_span(&edges, &pos), 1, "{heuristic:?}");
+        }
+    }
+
+    #[test]
+    fn chain() {
+        // x_3 – x_7 – x_1 – x_5 – x_2 – x_8 – x_4 – x_6, plus the isolated x_9
+        let chain = [3, 7, 1, 5, 2, 8, 4, 6];
+        let edges: Vec<Vec<Var>> = chain.windows(2).map(|w| vec![v(w[0]), v(w[1])]).collect();
+        for heuristic in HEURISTICS {
+            let order = heuristic.order_hyperedges(9, &edges);
+            positions(9, &order);
+        }
+        let order = VarOrderHeuristic::Bandwidth.order_hyperedges(9, &edges);
+        assert_eq!(max_span(&edges, &positions(9, &order)), 1);
+    }
+
+    #[test]
+    fn problem_names() {
+        let problem = Problem::Prop {
+            num_vars: 3,
+            var_order: vec![
+                (v(1), "a".to_string()),
+                (v(2), "b".to_string()),
+                (v(3), "c".to_string()),
+            ],
+            xor: false,
+            eq: false,
+            ast: Prop::And(vec![
+                Prop::Or(vec![Prop::Lit(v(1), false), Prop::Lit(v(3), true)]),
+                Prop::Neg(Box::new(Prop::And(vec![
+                    Prop::Lit(v(2), false),
+                    Prop::Lit(v(3), false),
+                ]))),
+            ]),
+        };
+        for heuristic in HEURISTICS {
+            let order = heuristic.var_order(&problem);
+            let vars: Vec<Var> = order.iter().map(|(var, _)| *var).collect();
+            assert_eq!(positions(3, &vars)[2], 1, "{heuristic:?}");
+            for (var, name) in order {
+                assert_eq!(name, ["a", "b", "c"][var.get() as usize - 1]);
+            }
+        }
+
+        let problem = Problem::CNF {
+            num_vars: 2,
+            var_order: Vec::new(),
+            clauses: vec![vec![(v(2), false), (v(1), true)]],
+            clause_order: Vec::new(),
+        };
+        let order = VarOrderHeuristic::Force.var_order(&problem);
+        assert_eq!(order, [(v(1), "1".to_string()), (v(2), "2".to_string())]);
+    }
+}
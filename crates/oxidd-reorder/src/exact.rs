@@ -0,0 +1,280 @@
+//! Exact minimization of the number of nodes
+
+use oxidd_core::HasLevel;
+use oxidd_core::LevelNo;
+use oxidd_core::LevelView;
+use oxidd_core::Manager;
+
+use crate::bubble_sort;
+use crate::gc_blocks;
+use crate::set_block_sizes;
+use crate::swap_blocks_gc;
+
+/// Maximal number of levels (or variable groups) supported by [`exact()`]
+pub const EXACT_MAX_BLOCKS: u32 = 24;
+
+/// Reorder the variables such that the number of inner nodes is minimal
+///
+/// This is an implementation of the dynamic programming algorithm by Friedman
+/// and Supowit, operating on swaps of adjacent levels only. It relies on the
+/// fact that the number of nodes at the level of a variable `x` only depends on
+/// the set of variables above `x`, not their order. For every set of variables
+/// `S`, the algorithm computes the minimal number of nodes in the top `|S|`
+/// levels of any order beginning with `S`. The time and memory requirements
+/// are exponential in the number of variables, so this is only feasible for
+/// up to about 16–20 variables. [Variable groups][Manager::var_groups] are
+/// treated as a unit, i.e., the order is optimal among all orders that keep
+/// the groups together.
+///
+/// This function garbage collects all unreachable nodes.
+///
+/// The caller must not call [`manager.reorder()`][Manager::reorder]. To apply
+/// this algorithm from inside a reorder operation, use [`exact_in_reorder()`].
+///
+/// Panics if there are more than [`EXACT_MAX_BLOCKS`] levels (or variable
+/// groups).
+pub fn exact<M: Manager>(manager: &mut M)
+where
+    M::InnerNode: HasLevel,
+{
+    if manager.num_levels() <= 1 {
+        return; // nothing to do
+    }
+    // SAFETY: we are inside `reorder()`
+    manager.reorder(|manager| unsafe { exact_in_reorder(manager) });
+}
+
+/// Reorder the variables such that the number of inner nodes is minimal
+///
+/// See [`exact()`] for more details. Returns the number of inner nodes
+/// afterwards.
+///
+/// Panics if there are more than [`EXACT_MAX_BLOCKS`] levels (or variable
+/// groups).
+///
+/// # Safety
+///
+/// Must be called from inside the closure of
+/// [`manager.reorder()`][Manager::reorder].
+pub unsafe fn exact_in_reorder<M: Manager>(manager: &mut M) -> usize
+where
+    M::InnerNode: HasLevel,
+{
+    let m = &*manager;
+    // SAFETY (next 2): guaranteed by the caller
+    let (mut block_sizes, node_counts) = unsafe { gc_blocks(m) };
+    let count =
+        |levels: std::ops::Range<LevelNo>| -> usize { levels.map(|no| m.level(no).len()).sum() };
+    let size = exact_core(&node_counts, |i| {
+        // SAFETY: guaranteed by the caller
+        unsafe { swap_blocks_gc(m, &mut block_sizes, i) };
+        let start: LevelNo = block_sizes[..i as usize].iter().sum();
+        let mid = start + block_sizes[i as usize];
+        let end = mid + block_sizes[i as usize + 1];
+        [count(start..mid), count(mid..end)]
+    });
+    debug_assert_eq!(size, m.num_inner_nodes());
+    set_block_sizes(manager, &block_sizes);
+    size
+}
+
+/// Find and establish an order of the blocks (levels or variable groups) with
+/// the minimal number of nodes
+///
+/// `node_counts` contains the number of nodes for each block. `swap(i)` swaps
+/// the block `i` with the block directly below and returns the new number of
+/// nodes of blocks `i` and `i + 1`.
+///
+/// Returns the total number of nodes afterwards.
+fn exact_core(node_counts: &[usize], swap: impl FnMut(LevelNo) -> [usize; 2]) -> usize {
+    let n = node_counts.len();
+    assert!(
+        n <= EXACT_MAX_BLOCKS as usize,
+        "exact reordering supports at most {EXACT_MAX_BLOCKS} levels or variable groups"
+    );
+    if n <= 1 {
+        return node_counts.iter().sum();
+    }
+
+    let mut state = State {
+        swap,
+        pos: (0..n as LevelNo).collect(),
+        block_at: (0..n as LevelNo).collect(),
+        widths: node_counts.to_vec(),
+    };
+
+    // Any order that is extended from a set with more nodes than the initial
+    // order cannot be better than the initial order.
+    let upper_bound: usize = node_counts.iter().sum();
+
+    // `cost[set]` is the minimal number of nodes in the top `|set|` positions
+    // when the blocks in `set` are placed there, `last[set]` is the block at
+    // position `|set| - 1` in such an optimal order.
+    let full = (1u32 << n) - 1;
+    let mut cost = vec![usize::MAX; full as usize + 1];
+    let mut last = vec![0u8; full as usize + 1];
+    cost[0] = 0;
+
+    let mut top = Vec::with_capacity(n);
+    for k in 0..n as u32 {
+        for set in (0..full).filter(|set| set.count_ones() == k) {
+            let set_cost = cost[set as usize];
+            if set_cost > upper_bound {
+                continue; // also skips unreachable sets
+            }
+            reconstruct(&last, set, &mut top);
+            state.arrange_top(&top);
+
+            for block in 0..n as LevelNo {
+                if set & (1 << block) != 0 {
+                    continue;
+                }
+                state.move_up(block, k);
+                let new_set = (set | (1 << block)) as usize;
+                let new_cost = set_cost + state.widths[k as usize];
+                if new_cost < cost[new_set] {
+                    cost[new_set] = new_cost;
+                    last[new_set] = block as u8;
+                }
+            }
+        }
+    }
+
+    reconstruct(&last, full, &mut top);
+    state.arrange_top(&top);
+    let size = state.widths.iter().sum();
+    debug_assert_eq!(size, cost[full as usize]);
+    size
+}
+
+/// Reconstruct the optimal order of the blocks in `set` (from top to bottom)
+/// into `order`
+fn reconstruct(last: &[u8], mut set: u32, order: &mut Vec<LevelNo>) {
+    order.clear();
+    while set != 0 {
+        let block = last[set as usize];
+        order.push(block as LevelNo);
+        set &= !(1 << block);
+    }
+    order.reverse();
+}
+
+struct State<S> {
+    swap: S,
+    /// Mapping from blocks (identified by their initial position) to their
+    /// current position
+    pos: Vec<LevelNo>,
+    /// Inverse of `pos`
+    block_at: Vec<LevelNo>,
+    /// Number of nodes for each position
+    widths: Vec<usize>,
+}
+
+impl<S: FnMut(LevelNo) -> [usize; 2]> State<S> {
+    /// Swap the block at position `upper` with the block directly below
+    fn swap(&mut self, upper: LevelNo) {
+        let i = upper as usize;
+        [self.widths[i], self.widths[i + 1]] = (self.swap)(upper);
+        self.block_at.swap(i, i + 1);
+        self.pos[self.block_at[i] as usize] = upper;
+        self.pos[self.block_at[i + 1] as usize] = upper + 1;
+    }
+
+    /// Move `block` up to position `to`
+    fn move_up(&mut self, block: LevelNo, to: LevelNo) {
+        let mut from = self.pos[block as usize];
+        debug_assert!(from >= to);
+        while from > to {
+            self.swap(from - 1);
+            from -= 1;
+        }
+    }
+
+    /// Place the blocks in `top` at the top positions (in the given order),
+    /// keeping the relative order of the remaining blocks
+    fn arrange_top(&mut self, top: &[LevelNo]) {
+        let n = self.block_at.len();
+        let mut target = vec![LevelNo::MAX; n];
+        for (i, &block) in top.iter().enumerate() {
+            target[block as usize] = i as LevelNo;
+        }
+        let mut next = top.len() as LevelNo;
+        let mut seq: Vec<LevelNo> = self
+            .block_at
+            .iter()
+            .map(|&block| {
+                let t = &mut target[block as usize];
+                if *t == LevelNo::MAX {
+                    *t = next;
+                    next += 1;
+                }
+                *t
+            })
+            .collect();
+        bubble_sort(&mut seq, |i| self.swap(i));
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Width of the position holding `var` given the variables above, which
+    /// only depends on the set of variables above (as for decision diagrams)
+    fn width(above: &[LevelNo], var: LevelNo) -> usize {
+        let set: u32 = above.iter().map(|&v| 1 << v).sum();
+        // Some arbitrary but deterministic function
+        ((set.wrapping_mul(2654435761) >> 7) ^ (var * 7919)) as usize % 13
+    }
+
+    fn cost(order: &[LevelNo]) -> usize {
+        (0..order.len()).map(|i| width(&order[..i], order[i])).sum()
+    }
+
+    fn simulate(vars: &[LevelNo]) -> (Vec<LevelNo>, usize) {
+        let mut order = vars.to_vec();
+        let node_counts: Vec<usize> = (0..order.len())
+            .map(|i| width(&order[..i], order[i]))
+            .collect();
+        let res = exact_core(&node_counts, |upper| {
+            let i = upper as usize;
+            order.swap(i, i + 1);
+            [
+                width(&order[..i], order[i]),
+                width(&order[..i + 1], order[i + 1]),
+            ]
+        });
+        assert_eq!(res, cost(&order));
+        (order, res)
+    }
+
+    fn min_cost(order: &mut [LevelNo], k: usize) -> usize {
+        if k == order.len() {
+            return cost(order);
+        }
+        let mut min = usize::MAX;
+        for i in k..order.len() {
+            order.swap(k, i);
+            min = min.min(min_cost(order, k + 1));
+            order.swap(k, i);
+        }
+        min
+    }
+
+    #[test]
+    fn test_exact_core() {
+        for vars in [
+            &[0, 1][..],
+            &[2, 0, 1],
+            &[3, 1, 4, 0, 2],
+            &[5, 2, 0, 4, 1, 3],
+        ] {
+            let (_, size) = simulate(vars);
+            assert_eq!(size, min_cost(&mut vars.to_vec(), 0));
+        }
+
+        let (order, size) = simulate(&[0]);
+        assert_eq!(order, [0]);
+        assert_eq!(size, width(&[], 0));
+    }
+}
@@ -7,6 +7,9 @@ use is_sorted::IsSorted;
 use oxidd_core::util::OutOfMemory;
 use oxidd_core::WorkerManager;
 use smallvec::SmallVec;
+use std::ops::Deref;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering::Relaxed;
 
 use oxidd_core::function::Function;
 use oxidd_core::util::AbortOnDrop;
@@ -21,6 +24,40 @@ use oxidd_core::LevelView;
 use oxidd_core::Manager;
 use oxidd_core::ReducedOrNew;
 
+mod exact;
+pub use exact::exact;
+pub use exact::exact_in_reorder;
+pub use exact::EXACT_MAX_BLOCKS;
+mod sift;
+pub use sift::sift;
+pub use sift::sift_in_reorder;
+pub use sift::SiftConfig;
+mod window;
+pub use window::window_permutation;
+pub use window::window_permutation_in_reorder;
+
+/// Cofactor of a node's child in [`level_down()`]
+///
+/// Most cofactors are borrowed from existing nodes, only cofactors with
+/// respect to skipped levels may need to be created (see
+/// [`DiagramRules::skipped_cofactor()`]).
+enum Cofactor<'a, E> {
+    Borrowed(Borrowed<'a, E>),
+    Owned(E),
+}
+
+impl<E> Deref for Cofactor<'_, E> {
+    type Target = E;
+
+    #[inline]
+    fn deref(&self) -> &E {
+        match self {
+            Cofactor::Borrowed(e) => e,
+            Cofactor::Owned(e) => e,
+        }
+    }
+}
+
 /// Swap the level given by `upper_no` with the level directly below.
 ///
 /// # Safety
@@ -69,7 +106,7 @@ where
 
         if children
             .iter()
-            .all(|c| manager.get_node(c).unwrap_inner().level() > lower_no)
+            .all(|c| manager.get_node(c).level() > lower_no)
         {
             // All children are below the lower level, we just move the node to
             // the lower level.
@@ -85,19 +122,33 @@ where
         // lower level, and keep the original node at the upper level (with the
         // children replaced by the newly created ones).
 
-        let grandchildren: SmallVec<[_; 2]> = children
+        let grandchildren: SmallVec<[SmallVec<[_; 2]>; 2]> = children
             .iter()
             .map(|c| {
-                let node = manager.get_node(c).unwrap_inner();
-                if node.level() == lower_no {
+                let node = manager.get_node(c);
+                // Nodes from the old lower level already have their new level
+                // number `upper_no`.
+                if node.level() == upper_no {
+                    let node = node.unwrap_inner();
                     // We have exclusive access to the node
-                    let children: SmallVec<[_; 2]> = M::Rules::cofactors(c.tag(), node).collect();
+                    let children: SmallVec<[_; 2]> = M::Rules::cofactors(c.tag(), node)
+                        .map(Cofactor::Borrowed)
+                        .collect();
                     debug_assert_eq!(children.len(), M::InnerNode::ARITY);
                     children
                 } else {
-                    // The child is below the lower level, so we always have
-                    // this child
-                    (0..M::InnerNode::ARITY).map(|_| c.borrowed()).collect()
+                    // The child is below the lower level, so it skips the
+                    // lower level's variable.
+                    (0..M::InnerNode::ARITY)
+                        .map(|i| match M::Rules::skipped_cofactor(manager, c, i) {
+                            Ok(None) => Cofactor::Borrowed(c.borrowed()),
+                            Ok(Some(e)) => Cofactor::Owned(e),
+                            Err(OutOfMemory) => {
+                                eprintln!("Out of memory");
+                                std::process::abort();
+                            }
+                        })
+                        .collect()
                 }
             })
             .collect();
@@ -128,38 +179,231 @@ where
             })
             .collect();
 
-        drop(grandchildren);
-        for child in children {
-            // Revisit the "old" children of `e`. If these are the only
-            // children, we may remove them, if they are on the current upper
-            // level. (A child might also be at some lower level, in which case
-            // the node could also be removed. However we must not access such
-            // a node.)
-            let child_node = manager.get_node(&*child).unwrap_inner();
-            if child_node.level() == upper_no && child_node.ref_count() == 1 {
-                // The main reference corresponds to the old node, which is
-                // deleted below. The weak reference is the one in the unique
-                // table. Hence, we can remove the node.
-                unsafe { upper.remove(child_node) };
+        for c in grandchildren.into_iter().flatten() {
+            if let Cofactor::Owned(e) = c {
+                manager.drop_edge(e);
             }
         }
-
-        upper.insert(manager.clone_edge(e));
+        drop(children);
         for (i, child) in new_children.into_iter().enumerate() {
             // SAFETY: we have exclusive access to all nodes at the old upper
             // level and no child is borrowed.
             manager.drop_edge(unsafe { node.set_child(i, child) });
         }
+        // Insert the node only after updating the children, since the unique
+        // table hashes the children.
+        upper.insert(manager.clone_edge(e));
     }
 
+    // The "old" children of nodes that stayed at the upper level may now be
+    // dead. Dropping them from the unique table is only possible once there
+    // are no other references, so we collect them here.
+    // SAFETY: we are inside `reorder()` and have exclusive access to the level
+    unsafe { upper.gc() };
+
     abort_on_panic.defuse();
 }
 
+/// Update the variable groups of `manager` after reordering, `block_sizes` as
+/// returned by [`VarGroups::block_sizes()`]
+///
+/// Does nothing if the manager does not support variable groups.
+///
+/// [`VarGroups::block_sizes()`]: oxidd_core::util::VarGroups::block_sizes
+fn set_block_sizes<M: Manager>(manager: &mut M, block_sizes: &[LevelNo]) {
+    if let Some(groups) = manager.var_groups_mut() {
+        groups.set_block_sizes(block_sizes);
+    }
+}
+
+/// Garbage collect all levels from top to bottom and return the sizes of the
+/// variable groups (in levels) along with the number of nodes in each group
+///
+/// # Safety
+///
+/// Must be called from inside the closure of
+/// [`manager.reorder()`][Manager::reorder]. There must not be any concurrent
+/// modification of any nodes.
+unsafe fn gc_blocks<M: Manager>(manager: &M) -> (Vec<LevelNo>, Vec<usize>) {
+    // Going from top to bottom, we also remove nodes that become dead during
+    // this process.
+    let level_sizes: Vec<usize> = (0..manager.num_levels())
+        .map(|no| {
+            let mut level = manager.level(no);
+            // SAFETY: guaranteed by the caller
+            unsafe { level.gc() };
+            level.len()
+        })
+        .collect();
+
+    let block_sizes = manager.var_groups().block_sizes(manager.num_levels());
+    let mut level = 0;
+    let node_counts = block_sizes
+        .iter()
+        .map(|&size| {
+            let count = level_sizes[level..level + size as usize].iter().sum();
+            level += size as usize;
+            count
+        })
+        .collect();
+    (block_sizes, node_counts)
+}
+
+/// [`level_down()`] followed by a garbage collection of the two levels
+///
+/// Returns the change in the number of nodes.
+///
+/// # Safety
+///
+/// See [`level_down()`]
+unsafe fn level_down_gc<M: Manager>(manager: &M, upper_no: LevelNo) -> isize
+where
+    M::InnerNode: HasLevel,
+{
+    let before = manager.level(upper_no).len() + manager.level(upper_no + 1).len();
+    // SAFETY: guaranteed by the caller
+    unsafe { level_down(manager, upper_no) };
+    let mut upper = manager.level(upper_no);
+    let mut lower = manager.level(upper_no + 1);
+    // SAFETY: we are inside `reorder()`. Collecting the upper level first may
+    // lead to further dead nodes in the lower level.
+    unsafe {
+        upper.gc();
+        lower.gc();
+    }
+    (upper.len() + lower.len()) as isize - before as isize
+}
+
+/// Swap the blocks `i` and `i + 1` (e.g. variable groups), where
+/// `block_sizes` contains the number of levels of each block, by calling
+/// `swap(upper_no)` for adjacent levels. Updates `block_sizes` accordingly.
+fn swap_blocks(block_sizes: &mut [LevelNo], i: LevelNo, swap: impl FnMut(LevelNo)) {
+    let i = i as usize;
+    let start: LevelNo = block_sizes[..i].iter().sum();
+    swap_block_levels(start, block_sizes[i], block_sizes[i + 1], swap);
+    block_sizes.swap(i, i + 1);
+}
+
+/// Swap the block of `upper_size` levels starting at level `start` with the
+/// block of `lower_size` levels directly below by calling `swap(upper_no)` for
+/// adjacent levels
+fn swap_block_levels(
+    start: LevelNo,
+    upper_size: LevelNo,
+    lower_size: LevelNo,
+    mut swap: impl FnMut(LevelNo),
+) {
+    // Move every level of the lower block up through the upper block
+    for j in 0..lower_size {
+        for upper_no in (start + j..start + j + upper_size).rev() {
+            swap(upper_no);
+        }
+    }
+}
+
+/// [`swap_blocks()`] using [`level_down_gc()`]
+///
+/// Returns the change in the number of nodes.
+///
+/// # Safety
+///
+/// See [`level_down()`]
+unsafe fn swap_blocks_gc<M: Manager>(manager: &M, block_sizes: &mut [LevelNo], i: LevelNo) -> isize
+where
+    M::InnerNode: HasLevel,
+{
+    let mut delta = 0;
+    swap_blocks(block_sizes, i, |upper_no| {
+        // SAFETY: guaranteed by the caller
+        delta += unsafe { level_down_gc(manager, upper_no) };
+    });
+    delta
+}
+
+/// Compute the sorting keys for the variable groups of `manager` such that
+/// the variables at the levels in `order` are placed as given by this sequence
+/// (as far as possible)
+///
+/// The key of each group is the minimal position in `order` of its levels. If
+/// none of the group's levels occurs in `order`, the group is placed with the
+/// minimal number of swaps, just like in [`sort_order()`].
+///
+/// Returns the block sizes along with the keys.
+fn group_keys<M: Manager>(manager: &M, order: &[LevelNo]) -> (Vec<LevelNo>, Vec<LevelNo>) {
+    let num_levels = manager.num_levels();
+    let target_order = sort_order(num_levels, order.iter().copied());
+    let mut in_order = vec![false; num_levels as usize];
+    for &level in order {
+        in_order[level as usize] = true;
+    }
+
+    // Levels not in `order` get the target position of the level following
+    // them in `order`, which is why we sort them in front. To this end, we
+    // double the keys and add 1 for levels in `order`.
+    let block_sizes = manager.var_groups().block_sizes(num_levels);
+    let mut level = 0;
+    let keys = block_sizes
+        .iter()
+        .map(|&size| {
+            let range = level..level + size as usize;
+            level += size as usize;
+            let key = |l: usize| 2 * target_order[l] + in_order[l] as LevelNo;
+            match range.clone().filter(|&l| in_order[l]).map(key).min() {
+                Some(key) => key,
+                None => range.map(key).min().unwrap(),
+            }
+        })
+        .collect();
+    (block_sizes, keys)
+}
+
+/// Sort the variable groups of `manager` according to [`group_keys()`]
+///
+/// # Safety
+///
+/// Must be called from inside the closure of
+/// [`manager.reorder()`][Manager::reorder].
+unsafe fn sort_groups<M: Manager>(manager: &mut M, order: &[LevelNo])
+where
+    M::InnerNode: HasLevel,
+{
+    let (mut block_sizes, mut keys) = group_keys(manager, order);
+    let m = &*manager;
+    bubble_sort(&mut keys, |i| {
+        swap_blocks(&mut block_sizes, i, |upper_no| unsafe {
+            level_down(m, upper_no)
+        })
+    });
+    set_block_sizes(manager, &block_sizes);
+}
+
+/// Concurrent version of [`sort_groups()`]
+///
+/// # Safety
+///
+/// Must be called from inside the closure of
+/// [`manager.reorder()`][Manager::reorder].
+unsafe fn sort_groups_concurrent<M: Manager + WorkerManager>(manager: &mut M, order: &[LevelNo])
+where
+    M::InnerNode: HasLevel,
+{
+    let (mut block_sizes, mut keys) = group_keys(manager, order);
+    let m = &*manager;
+    odd_even_sort_blocks(m, &mut keys, &mut block_sizes, |upper_no| unsafe {
+        level_down(m, upper_no)
+    });
+    set_block_sizes(manager, &block_sizes);
+}
+
 /// Reorder the variables such that the edges in `order` are sorted by their
 /// levels.
 ///
 /// Sequential version of [`set_var_order()`].
 ///
+/// If there are [variable groups][Manager::var_groups], the groups are kept
+/// contiguous and ordered by the first position of any of their variables in
+/// `order`. The order inside a group is preserved.
+///
 /// The caller must not call [`manager.reorder()`][Manager::reorder].
 pub fn set_var_order_seq<'id, F: Function>(manager: &mut F::Manager<'id>, order: &[F])
 where
@@ -169,27 +413,37 @@ where
         return; // nothing to do
     }
 
-    let mut target_order = sort_order(
-        manager.num_levels(),
-        order.iter().map(|f| {
+    let order_levels: Vec<LevelNo> = order
+        .iter()
+        .map(|f| {
             manager
                 .get_node(f.as_edge(manager))
                 .expect_inner("order must not contain (const) terminals")
                 .level()
-        }),
-    );
+        })
+        .collect();
+    let mut target_order = sort_order(manager.num_levels(), order_levels.iter().copied());
 
+    let groups = !manager.var_groups().is_empty();
     manager.reorder(|manager| {
+        if groups {
+            // SAFETY: we are inside `reorder()`
+            unsafe { sort_groups(manager, &order_levels) };
+            return;
+        }
         // Finally a use case for bubble sort :)
         bubble_sort(&mut target_order, |upper_no| unsafe {
             level_down(manager, upper_no)
         });
     });
 
-    debug_assert!(IsSorted::is_sorted(&mut order.iter().map(|f| {
-        let edge = f.as_edge(manager);
-        manager.get_node(edge).unwrap_inner().level()
-    })));
+    debug_assert!(
+        groups
+            || IsSorted::is_sorted(&mut order.iter().map(|f| {
+                let edge = f.as_edge(manager);
+                manager.get_node(edge).unwrap_inner().level()
+            }))
+    );
 }
 
 /// Reorder the variables such that the edges in `order` are sorted by their
@@ -197,6 +451,12 @@ where
 ///
 /// Like [`set_var_order_seq()`] but with concurrent swap operations.
 ///
+/// If there are [variable groups][Manager::var_groups], the groups are kept
+/// contiguous and ordered by the first position of any of their variables in
+/// `order`. The order inside a group is preserved. Groups are moved using
+/// odd-even transposition sort, where the disjoint group swaps of each round
+/// run concurrently.
+///
 /// The caller must not call [`manager.reorder()`][Manager::reorder].
 pub fn set_var_order<'id, F: Function>(manager: &mut F::Manager<'id>, order: &[F])
 where
@@ -208,18 +468,27 @@ where
     }
 
     let num_levels = manager.num_levels();
-    let mut target_order = sort_order(
-        num_levels,
-        order.iter().map(|f| {
+    let order_levels: Vec<LevelNo> = order
+        .iter()
+        .map(|f| {
             manager
                 .get_node(f.as_edge(manager))
                 .expect_inner("order must not contain (const) terminals")
                 .level()
-        }),
-    );
+        })
+        .collect();
+    let mut target_order = sort_order(num_levels, order_levels.iter().copied());
 
+    let groups = !manager.var_groups().is_empty();
     manager.reorder(|manager| {
-        if num_levels <= 8 {
+        if groups {
+            // SAFETY (next 2): we are inside `reorder()`
+            if num_levels <= 8 {
+                unsafe { sort_groups(manager, &order_levels) };
+            } else {
+                unsafe { sort_groups_concurrent(manager, &order_levels) };
+            }
+        } else if num_levels <= 8 {
             bubble_sort(&mut target_order, |upper_no| unsafe {
                 level_down(manager, upper_no)
             });
@@ -230,10 +499,13 @@ where
         }
     });
 
-    debug_assert!(IsSorted::is_sorted(&mut order.iter().map(|f| {
-        let edge = f.as_edge(manager);
-        manager.get_node(edge).unwrap_inner().level()
-    })));
+    debug_assert!(
+        groups
+            || IsSorted::is_sorted(&mut order.iter().map(|f| {
+                let edge = f.as_edge(manager);
+                manager.get_node(edge).unwrap_inner().level()
+            }))
+    );
 }
 
 /// Transform the `input_order` into a target order suitable for sorting, that
@@ -519,9 +791,71 @@ where
     });
 }
 
+/// Sorts the blocks (e.g. variable groups) with the given `keys` using odd-even
+/// transposition sort. `block_sizes` contains the number of levels of each
+/// block and is updated accordingly. For every swap of adjacent levels, `swap`
+/// is called with the smaller level number.
+///
+/// The sort proceeds in rounds, alternately comparing the blocks at even and
+/// odd positions with their successors. The block swaps of one round are
+/// disjoint and therefore executed concurrently on the workers of `manager`.
+fn odd_even_sort_blocks<M, F>(
+    manager: &M,
+    keys: &mut [LevelNo],
+    block_sizes: &mut [LevelNo],
+    swap: F,
+) where
+    M: WorkerManager,
+    F: Fn(LevelNo) + Sync,
+{
+    debug_assert_eq!(keys.len(), block_sizes.len());
+    // `(start, upper_size, lower_size)` for every block swap of a round
+    let mut tasks: Vec<(LevelNo, LevelNo, LevelNo)> = Vec::new();
+    let mut sorted_rounds = 0;
+    let mut parity = 0;
+    while sorted_rounds < 2 {
+        tasks.clear();
+        let mut start: LevelNo = block_sizes.iter().take(parity).sum();
+        let mut i = parity;
+        while i + 1 < keys.len() {
+            let (upper_size, lower_size) = (block_sizes[i], block_sizes[i + 1]);
+            if keys[i] > keys[i + 1] {
+                tasks.push((start, upper_size, lower_size));
+                keys.swap(i, i + 1);
+                block_sizes.swap(i, i + 1);
+            }
+            start += upper_size + lower_size;
+            i += 2;
+        }
+
+        match tasks.len() {
+            0 => sorted_rounds += 1,
+            1 => {
+                sorted_rounds = 0;
+                let (start, upper_size, lower_size) = tasks[0];
+                swap_block_levels(start, upper_size, lower_size, &swap);
+            }
+            _ => {
+                sorted_rounds = 0;
+                let next = AtomicUsize::new(0);
+                manager.broadcast(|_| {
+                    while let Some(&(start, upper_size, lower_size)) =
+                        tasks.get(next.fetch_add(1, Relaxed))
+                    {
+                        swap_block_levels(start, upper_size, lower_size, &swap);
+                    }
+                });
+            }
+        }
+        parity ^= 1;
+    }
+    debug_assert!(IsSorted::is_sorted(&mut keys.iter()));
+}
+
 #[cfg(test)]
 mod test {
     use oxidd_test_utils::edge::DummyManager;
+    use std::sync::atomic::AtomicU32;
     use std::sync::atomic::Ordering::Relaxed;
 
     use super::*;
@@ -648,6 +982,22 @@ mod test {
         };
     }
 
+    #[test]
+    fn test_swap_blocks() {
+        let mut seq = [0, 1, 2, 3, 4, 5];
+        let mut block_sizes = [1, 2, 3];
+        swap_blocks(&mut block_sizes, 1, |i| {
+            seq.swap(i as usize, i as usize + 1)
+        });
+        assert_eq!(seq, [0, 3, 4, 5, 1, 2]);
+        assert_eq!(block_sizes, [1, 3, 2]);
+        swap_blocks(&mut block_sizes, 0, |i| {
+            seq.swap(i as usize, i as usize + 1)
+        });
+        assert_eq!(seq, [3, 4, 5, 0, 1, 2]);
+        assert_eq!(block_sizes, [3, 1, 2]);
+    }
+
     #[test]
     fn test_bubble_sort() {
         bubble_sort_test_case![];
@@ -697,4 +1047,27 @@ mod test {
             panic!("sort is unstable")
         });
     }
+
+    #[test]
+    fn test_odd_even_sort_blocks() {
+        let seq: &[AtomicU32] = &atomic_u32_array![0, 1, 2, 3, 4, 5, 6, 7];
+        let swap = |i: LevelNo| {
+            let a = seq[i as usize].load(Relaxed);
+            let b = seq[(i + 1) as usize].swap(a, Relaxed);
+            seq[i as usize].store(b, Relaxed);
+        };
+
+        let mut keys = [3, 0, 2, 1];
+        let mut block_sizes = [2, 1, 3, 2];
+        odd_even_sort_blocks(&DummyManager, &mut keys, &mut block_sizes, swap);
+        assert_eq!(keys, [0, 1, 2, 3]);
+        assert_eq!(block_sizes, [1, 2, 3, 2]);
+        let seq: Vec<u32> = seq.iter().map(|x| x.load(Relaxed)).collect();
+        assert_eq!(seq, [2, 6, 7, 3, 4, 5, 0, 1]);
+
+        odd_even_sort_blocks(&DummyManager, &mut [], &mut [], |_| unreachable!());
+        odd_even_sort_blocks(&DummyManager, &mut [0, 0, 1], &mut [1, 2, 1], |_| {
+            panic!("sort is unstable")
+        });
+    }
 }
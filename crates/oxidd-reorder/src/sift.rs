@@ -0,0 +1,291 @@
+//! Rudell's sifting algorithm
+
+use oxidd_core::HasLevel;
+use oxidd_core::LevelNo;
+use oxidd_core::Manager;
+
+use crate::gc_blocks;
+use crate::set_block_sizes;
+use crate::swap_blocks_gc;
+
+/// Configuration for [`sift()`]
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct SiftConfig {
+    /// Maximal factor by which the number of nodes may grow (relative to the
+    /// smallest size seen so far) while moving a single variable before giving
+    /// up this direction
+    ///
+    /// Values below `1.0` are treated as `1.0`.
+    pub max_growth: f64,
+    /// Maximal number of swaps (of adjacent levels or variable groups) for the
+    /// entire sifting run
+    ///
+    /// Once the limit is reached, the variable currently being sifted is moved
+    /// to the best position found so far and sifting stops. The swaps needed
+    /// for this final move are not limited.
+    pub max_swaps: usize,
+}
+
+impl Default for SiftConfig {
+    fn default() -> Self {
+        Self {
+            max_growth: 1.2,
+            max_swaps: 2_000_000,
+        }
+    }
+}
+
+/// Reorder the variables using Rudell's sifting algorithm
+///
+/// Each variable is moved through all levels by swapping it with its
+/// neighbors, starting with the variables whose levels contain the most nodes.
+/// The variable is then left at the position where the number of inner nodes
+/// was smallest. [Variable groups][Manager::var_groups] are sifted as a unit.
+/// Moving a variable into one direction stops early if the number of nodes
+/// grows by more than [`SiftConfig::max_growth`]. The total number of swaps is
+/// bounded by [`SiftConfig::max_swaps`].
+///
+/// This function garbage collects all unreachable nodes.
+///
+/// The caller must not call [`manager.reorder()`][Manager::reorder]. To sift
+/// from inside a reorder operation, use [`sift_in_reorder()`].
+pub fn sift<M: Manager>(manager: &mut M, config: &SiftConfig)
+where
+    M::InnerNode: HasLevel,
+{
+    if manager.num_levels() <= 1 {
+        return; // nothing to do
+    }
+    // SAFETY: we are inside `reorder()`
+    manager.reorder(|manager| unsafe { sift_in_reorder(manager, config) });
+}
+
+/// Reorder the variables using Rudell's sifting algorithm
+///
+/// See [`sift()`] for more details. Returns the number of inner nodes after
+/// sifting.
+///
+/// # Safety
+///
+/// Must be called from inside the closure of
+/// [`manager.reorder()`][Manager::reorder].
+pub unsafe fn sift_in_reorder<M: Manager>(manager: &mut M, config: &SiftConfig) -> usize
+where
+    M::InnerNode: HasLevel,
+{
+    let m = &*manager;
+    // SAFETY (next 2): guaranteed by the caller
+    let (mut block_sizes, node_counts) = unsafe { gc_blocks(m) };
+    let size = node_counts.iter().sum();
+    let size = sift_core(&node_counts, size, config, |i| unsafe {
+        swap_blocks_gc(m, &mut block_sizes, i)
+    });
+    debug_assert_eq!(size, m.num_inner_nodes());
+    set_block_sizes(manager, &block_sizes);
+    size
+}
+
+/// Sift all blocks (levels or variable groups)
+///
+/// `node_counts` contains the number of nodes for each block, `size` is the
+/// total number of nodes. `swap(i)` swaps the block `i` with the block
+/// directly below and returns the change in the number of nodes.
+///
+/// Returns the total number of nodes after sifting.
+fn sift_core(
+    node_counts: &[usize],
+    size: usize,
+    config: &SiftConfig,
+    swap: impl FnMut(LevelNo) -> isize,
+) -> usize {
+    let num_blocks = node_counts.len() as LevelNo;
+    if num_blocks <= 1 {
+        return size;
+    }
+
+    // Sift the blocks with the most nodes first
+    let mut blocks: Vec<LevelNo> = (0..num_blocks).collect();
+    blocks.sort_by_key(|&block| std::cmp::Reverse(node_counts[block as usize]));
+
+    let mut sifter = Sifter {
+        swap,
+        pos: (0..num_blocks).collect(),
+        block_at: (0..num_blocks).collect(),
+        size,
+        swaps: 0,
+        max_growth: config.max_growth.max(1.0),
+        max_swaps: config.max_swaps,
+    };
+    for block in blocks {
+        if sifter.swaps >= sifter.max_swaps {
+            break;
+        }
+        sifter.sift_block(block);
+    }
+    sifter.size
+}
+
+struct Sifter<S> {
+    swap: S,
+    /// Mapping from blocks (identified by their position before sifting) to
+    /// their current position
+    pos: Vec<LevelNo>,
+    /// Inverse of `pos`
+    block_at: Vec<LevelNo>,
+    /// Current number of nodes
+    size: usize,
+    /// Number of swaps performed so far
+    swaps: usize,
+    max_growth: f64,
+    max_swaps: usize,
+}
+
+impl<S: FnMut(LevelNo) -> isize> Sifter<S> {
+    /// Swap the block at position `upper` with the block directly below
+    fn swap(&mut self, upper: LevelNo) {
+        let delta = (self.swap)(upper);
+        self.size = self.size.checked_add_signed(delta).unwrap();
+        self.swaps += 1;
+
+        let i = upper as usize;
+        self.block_at.swap(i, i + 1);
+        self.pos[self.block_at[i] as usize] = upper;
+        self.pos[self.block_at[i + 1] as usize] = upper + 1;
+    }
+
+    /// Move the block at position `from` towards position `to` until either
+    /// `to` is reached, the number of nodes exceeds the growth limit, or the
+    /// swap limit is reached. `best` is the smallest `(size, position)` seen so
+    /// far.
+    ///
+    /// Returns the position the block ended up at.
+    fn move_block(
+        &mut self,
+        mut from: LevelNo,
+        to: LevelNo,
+        best: &mut (usize, LevelNo),
+    ) -> LevelNo {
+        while from != to && self.swaps < self.max_swaps {
+            if from < to {
+                self.swap(from);
+                from += 1;
+            } else {
+                self.swap(from - 1);
+                from -= 1;
+            }
+
+            if self.size < best.0 {
+                *best = (self.size, from);
+            } else if self.size as f64 > best.0 as f64 * self.max_growth {
+                break;
+            }
+        }
+        from
+    }
+
+    /// Move the block at position `from` to position `to` regardless of any
+    /// limit
+    fn move_block_unbounded(&mut self, mut from: LevelNo, to: LevelNo) {
+        while from < to {
+            self.swap(from);
+            from += 1;
+        }
+        while from > to {
+            self.swap(from - 1);
+            from -= 1;
+        }
+    }
+
+    fn sift_block(&mut self, block: LevelNo) {
+        let start = self.pos[block as usize];
+        let last = self.block_at.len() as LevelNo - 1;
+        let mut best = (self.size, start);
+
+        // Move towards the closer end first
+        let pos = if start < last - start {
+            let pos = self.move_block(start, 0, &mut best);
+            self.move_block(pos, last, &mut best)
+        } else {
+            let pos = self.move_block(start, last, &mut best);
+            self.move_block(pos, 0, &mut best)
+        };
+        self.move_block_unbounded(pos, best.1);
+        debug_assert_eq!(self.size, best.0);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Simulate sifting where the number of nodes is given by `cost` applied
+    /// to the current level → variable mapping. Returns the final mapping.
+    fn simulate(
+        vars: &[LevelNo],
+        config: &SiftConfig,
+        cost: impl Fn(&[LevelNo]) -> usize,
+    ) -> (Vec<LevelNo>, usize) {
+        let mut order = vars.to_vec();
+        let level_sizes = vec![1; vars.len()];
+        let size = cost(&order);
+        let res = sift_core(&level_sizes, size, config, |upper| {
+            let before = cost(&order) as isize;
+            order.swap(upper as usize, upper as usize + 1);
+            cost(&order) as isize - before
+        });
+        assert_eq!(res, cost(&order));
+        (order, res)
+    }
+
+    fn inversions(order: &[LevelNo]) -> usize {
+        let mut count = 0;
+        for (i, &a) in order.iter().enumerate() {
+            count += order[i + 1..].iter().filter(|&&b| b < a).count();
+        }
+        count
+    }
+
+    #[test]
+    fn test_sift_core() {
+        let config = SiftConfig {
+            max_growth: f64::INFINITY,
+            ..Default::default()
+        };
+        let (order, size) = simulate(&[3, 1, 4, 0, 2], &config, inversions);
+        assert!(size < inversions(&[3, 1, 4, 0, 2]));
+        assert_eq!(size, inversions(&order));
+
+        let (order, size) = simulate(&[0, 1, 2, 3], &config, inversions);
+        assert_eq!(order, [0, 1, 2, 3]);
+        assert_eq!(size, 0);
+
+        // Variable 0 should end up at the top
+        let (order, _) = simulate(&[1, 2, 3, 0], &config, |order| {
+            order.iter().position(|&v| v == 0).unwrap() + 1
+        });
+        assert_eq!(order[0], 0);
+    }
+
+    #[test]
+    fn test_sift_core_limits() {
+        let config = SiftConfig {
+            max_growth: f64::INFINITY,
+            max_swaps: 0,
+        };
+        let (order, _) = simulate(&[3, 2, 1, 0], &config, inversions);
+        assert_eq!(order, [3, 2, 1, 0]);
+
+        let config = SiftConfig {
+            max_growth: 1.0,
+            max_swaps: usize::MAX,
+        };
+        // Moving variable 0 up increases the cost, so the search is aborted
+        // immediately. Moving it down is fine.
+        let (order, size) = simulate(&[1, 0, 2], &config, |order| {
+            let pos = order.iter().position(|&v| v == 0).unwrap();
+            [5, 3, 1][pos]
+        });
+        assert_eq!(order[2], 0);
+        assert_eq!(size, 1);
+    }
+}
@@ -0,0 +1,238 @@
+//! Window permutation reordering
+
+use smallvec::SmallVec;
+
+use oxidd_core::HasLevel;
+use oxidd_core::LevelNo;
+use oxidd_core::Manager;
+
+use crate::bubble_sort;
+use crate::gc_blocks;
+use crate::set_block_sizes;
+use crate::swap_blocks_gc;
+
+/// Reorder the variables by trying all permutations of `window` adjacent
+/// levels
+///
+/// The window slides from the top to the bottom. For every position, all
+/// `window!` permutations are tried (by swapping adjacent levels), and the one
+/// with the smallest number of inner nodes is kept. If `converge` is true, the
+/// process is repeated until there is no further improvement. In presence of
+/// [variable groups][Manager::var_groups], the window consists of `window`
+/// adjacent groups.
+///
+/// This function garbage collects all unreachable nodes.
+///
+/// The caller must not call [`manager.reorder()`][Manager::reorder]. To apply
+/// this heuristic from inside a reorder operation, use
+/// [`window_permutation_in_reorder()`].
+///
+/// Panics if `window` is not in the range `2..=4`.
+pub fn window_permutation<M: Manager>(manager: &mut M, window: u32, converge: bool)
+where
+    M::InnerNode: HasLevel,
+{
+    assert!((2..=4).contains(&window), "window size must be 2, 3, or 4");
+    if manager.num_levels() <= 1 {
+        return; // nothing to do
+    }
+    // SAFETY: we are inside `reorder()`
+    manager.reorder(|manager| unsafe { window_permutation_in_reorder(manager, window, converge) });
+}
+
+/// Reorder the variables by trying all permutations of `window` adjacent
+/// levels
+///
+/// See [`window_permutation()`] for more details. Returns the number of inner
+/// nodes afterwards.
+///
+/// Panics if `window` is not in the range `2..=4`.
+///
+/// # Safety
+///
+/// Must be called from inside the closure of
+/// [`manager.reorder()`][Manager::reorder].
+pub unsafe fn window_permutation_in_reorder<M: Manager>(
+    manager: &mut M,
+    window: u32,
+    converge: bool,
+) -> usize
+where
+    M::InnerNode: HasLevel,
+{
+    let m = &*manager;
+    // SAFETY (next 2): guaranteed by the caller
+    let (mut block_sizes, node_counts) = unsafe { gc_blocks(m) };
+    let size = node_counts.iter().sum();
+    let size = window_core(
+        block_sizes.len() as LevelNo,
+        size,
+        window,
+        converge,
+        |i| unsafe { swap_blocks_gc(m, &mut block_sizes, i) },
+    );
+    debug_assert_eq!(size, m.num_inner_nodes());
+    set_block_sizes(manager, &block_sizes);
+    size
+}
+
+/// Apply the window permutation algorithm
+///
+/// `num_blocks` is the number of levels or variable groups, `size` is the
+/// total number of nodes. `swap(i)` swaps the block `i` with the block directly
+/// below and returns the change in the number of nodes.
+///
+/// Returns the total number of nodes afterwards.
+fn window_core(
+    num_blocks: LevelNo,
+    mut size: usize,
+    window: u32,
+    converge: bool,
+    mut swap: impl FnMut(LevelNo) -> isize,
+) -> usize {
+    assert!((2..=4).contains(&window), "window size must be 2, 3, or 4");
+    let window = window.min(num_blocks);
+    if window <= 1 {
+        return size;
+    }
+    let changes = plain_changes(window);
+
+    let mut do_swap = |upper: LevelNo, size: &mut usize| {
+        *size = size.checked_add_signed(swap(upper)).unwrap();
+    };
+
+    loop {
+        let mut improved = false;
+        for start in 0..=num_blocks - window {
+            // `perm[i]` is the variable (relative to the window) at position
+            // `start + i`
+            let mut perm: SmallVec<[LevelNo; 4]> = (0..window).collect();
+            let initial_size = size;
+            let mut best = (size, perm.clone());
+
+            for &i in &changes {
+                do_swap(start + i, &mut size);
+                perm.swap(i as usize, i as usize + 1);
+                if size < best.0 {
+                    best = (size, perm.clone());
+                }
+            }
+
+            // Move back to the best permutation
+            let mut target: SmallVec<[LevelNo; 4]> = perm
+                .iter()
+                .map(|v| best.1.iter().position(|w| w == v).unwrap() as LevelNo)
+                .collect();
+            bubble_sort(&mut target, |i| do_swap(start + i, &mut size));
+
+            if best.0 < initial_size {
+                improved = true;
+            }
+        }
+
+        if !converge || !improved {
+            return size;
+        }
+    }
+}
+
+/// Compute a sequence of adjacent transpositions that visits all permutations
+/// of `n` elements exactly once (Steinhaus–Johnson–Trotter algorithm)
+///
+/// Every entry `i` of the returned sequence denotes a swap of the elements at
+/// positions `i` and `i + 1`.
+fn plain_changes(n: u32) -> Vec<u32> {
+    let n = n as usize;
+    let mut perm: Vec<usize> = (0..n).collect();
+    // Direction of the value `v`: `true` means right, `false` left
+    let mut right = vec![false; n];
+    let mut changes = Vec::new();
+
+    loop {
+        // Find the largest mobile value, i.e. a value whose neighbor in its
+        // direction is smaller
+        let mut mobile: Option<(usize, usize)> = None;
+        for (i, &v) in perm.iter().enumerate() {
+            let j = if right[v] { i + 1 } else { i.wrapping_sub(1) };
+            if j < n && perm[j] < v && !matches!(mobile, Some((_, m)) if m > v) {
+                mobile = Some((i, v));
+            }
+        }
+        let Some((i, v)) = mobile else {
+            return changes;
+        };
+
+        let j = if right[v] { i + 1 } else { i - 1 };
+        perm.swap(i, j);
+        changes.push(std::cmp::min(i, j) as u32);
+        for dir in &mut right[v + 1..] {
+            *dir = !*dir;
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_plain_changes() {
+        for n in 1..=5 {
+            let changes = plain_changes(n);
+            let mut perm: Vec<u32> = (0..n).collect();
+            let mut seen = vec![perm.clone()];
+            for &i in &changes {
+                perm.swap(i as usize, i as usize + 1);
+                seen.push(perm.clone());
+            }
+            let num_perms: usize = (1..=n as usize).product();
+            assert_eq!(seen.len(), num_perms);
+            seen.sort();
+            seen.dedup();
+            assert_eq!(seen.len(), num_perms);
+        }
+    }
+
+    /// Cost of an order: number of pairs `(a, b)` with `a` above `b` but
+    /// `a > b`
+    fn inversions(order: &[LevelNo]) -> usize {
+        let mut count = 0;
+        for (i, &a) in order.iter().enumerate() {
+            count += order[i + 1..].iter().filter(|&&b| b < a).count();
+        }
+        count
+    }
+
+    fn simulate(vars: &[LevelNo], window: u32, converge: bool) -> (Vec<LevelNo>, usize) {
+        let mut order = vars.to_vec();
+        let size = inversions(&order);
+        let res = window_core(vars.len() as LevelNo, size, window, converge, |upper| {
+            let before = inversions(&order) as isize;
+            order.swap(upper as usize, upper as usize + 1);
+            inversions(&order) as isize - before
+        });
+        assert_eq!(res, inversions(&order));
+        (order, res)
+    }
+
+    #[test]
+    fn test_window_core() {
+        for window in 2..=4 {
+            let (order, size) = simulate(&[5, 4, 3, 2, 1, 0], window, true);
+            assert_eq!(order, [0, 1, 2, 3, 4, 5]);
+            assert_eq!(size, 0);
+
+            let (_, size) = simulate(&[5, 4, 3, 2, 1, 0], window, false);
+            assert!(size < inversions(&[5, 4, 3, 2, 1, 0]));
+
+            let (order, size) = simulate(&[1, 0], window, false);
+            assert_eq!(order, [0, 1]);
+            assert_eq!(size, 0);
+        }
+
+        let (order, _) = simulate(&[0], 3, true);
+        assert_eq!(order, [0]);
+        let (order, _) = simulate(&[], 2, true);
+        assert_eq!(order, []);
+    }
+}
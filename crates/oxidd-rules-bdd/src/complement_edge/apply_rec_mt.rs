@@ -2,15 +2,19 @@
 
 use oxidd_core::function::BooleanFunction;
 use oxidd_core::function::BooleanFunctionQuant;
+use oxidd_core::function::BooleanOperator;
 use oxidd_core::function::EdgeOfFunc;
 use oxidd_core::function::Function;
 use oxidd_core::function::FunctionSubst;
+use oxidd_core::util::sort_literals;
 use oxidd_core::util::AllocResult;
 use oxidd_core::util::Borrowed;
 use oxidd_core::util::EdgeDropGuard;
 use oxidd_core::util::OptBool;
 use oxidd_core::util::SatCountCache;
 use oxidd_core::util::SatCountNumber;
+use oxidd_core::util::WeightedCountNumber;
+use oxidd_core::util::WeightedSatCountCache;
 use oxidd_core::ApplyCache;
 use oxidd_core::Edge;
 use oxidd_core::HasApplyCache;
@@ -27,11 +31,14 @@ use oxidd_dump::dot::DotStyle;
 use crate::stat;
 
 use super::apply_rec_st;
+use super::clause;
 use super::collect_cofactors;
+use super::cube;
 use super::get_terminal;
 use super::not;
 use super::not_owned;
 use super::reduce;
+use super::ApplyQuantOperation;
 use super::BCDDOp;
 use super::BCDDTerminal;
 use super::EdgeTag;
@@ -576,6 +583,159 @@ where
     Ok(res)
 }
 
+/// Recursively apply the binary operator `OP` to `f` and `g` and quantify the
+/// result over `vars` using `Q`
+///
+/// `depth` is decremented for each recursive call. If it reaches 0, this
+/// function simply calls [`apply_rec_st::apply_quant()`].
+fn apply_quant<M, const Q: u8, const OP: u8>(
+    manager: &M,
+    depth: u32,
+    f: Borrowed<M::Edge>,
+    g: Borrowed<M::Edge>,
+    vars: Borrowed<M::Edge>,
+) -> AllocResult<M::Edge>
+where
+    M: Manager<Terminal = BCDDTerminal, EdgeTag = EdgeTag>
+        + HasApplyCache<M, BCDDOp>
+        + WorkerManager,
+    M::InnerNode: HasLevel,
+    M::Edge: Send + Sync,
+{
+    if depth == 0 {
+        return apply_rec_st::apply_quant::<M, Q, OP>(manager, f, g, vars);
+    }
+    let operator = const { BCDDOp::from_apply_quant(Q, OP) };
+
+    stat!(call operator);
+    let rec = match super::terminal_apply_quant::<M, Q, OP>(manager, &f, &g, &vars) {
+        ApplyQuantOperation::Rec(rec) => rec,
+        ApplyQuantOperation::Quant(h) => {
+            let h = EdgeDropGuard::new(manager, h);
+            return quant::<M, Q>(manager, depth, h.borrowed(), vars);
+        }
+        ApplyQuantOperation::Apply(f, g) => return apply_bin::<M, OP>(manager, depth, f, g),
+        ApplyQuantOperation::Done(h) => return Ok(h),
+    };
+
+    // Query apply cache
+    stat!(cache_query operator);
+    if let Some(res) = manager.apply_cache().get(
+        manager,
+        operator,
+        &[rec.f.borrowed(), rec.g.borrowed(), rec.vars.borrowed()],
+    ) {
+        stat!(cache_hit operator);
+        return Ok(res);
+    }
+
+    let ([(ft, gt), (fe, ge)], vt) = rec.cofactors();
+    let d = depth - 1;
+    let (t, e) = manager.join(
+        || {
+            let t = apply_quant::<M, Q, OP>(manager, d, ft, gt, vt.borrowed())?;
+            Ok(EdgeDropGuard::new(manager, t))
+        },
+        || {
+            let e = apply_quant::<M, Q, OP>(manager, d, fe, ge, vt.borrowed())?;
+            Ok(EdgeDropGuard::new(manager, e))
+        },
+    );
+    let (t, e) = (t?, e?);
+
+    let res = if !rec.quantify() {
+        reduce(manager, rec.level, t.into_edge(), e.into_edge(), operator)?
+    } else if super::quant_absorbing::<M, Q>(manager, &t) {
+        t.into_edge()
+    } else if super::quant_absorbing::<M, Q>(manager, &e) {
+        e.into_edge()
+    } else if Q == BCDDOp::Forall as u8 {
+        apply_and(manager, d, t.borrowed(), e.borrowed())?
+    } else if Q == BCDDOp::Exist as u8 {
+        not_owned(apply_and(manager, d, not(&t), not(&e))?)
+    } else {
+        apply_bin::<M, { BCDDOp::Xor as u8 }>(manager, d, t.borrowed(), e.borrowed())?
+    };
+
+    manager.apply_cache().add(
+        manager,
+        operator,
+        &[rec.f.borrowed(), rec.g.borrowed(), rec.vars.borrowed()],
+        res.borrowed(),
+    );
+
+    Ok(res)
+}
+
+/// Compute `Q vars. f <op> g`
+///
+/// `Q` is one of `BCDDOp::Forall`, `BCDDOp::Exist`, or `BCDDOp::Unique` as
+/// `u8`. Using complement edges, we reduce all operators to the combinations
+/// supported by [`BCDDOp::from_apply_quant()`].
+fn apply_quant_dispatch<M, const Q: u8>(
+    manager: &M,
+    depth: u32,
+    op: BooleanOperator,
+    f: Borrowed<M::Edge>,
+    g: Borrowed<M::Edge>,
+    vars: Borrowed<M::Edge>,
+) -> AllocResult<M::Edge>
+where
+    M: Manager<Terminal = BCDDTerminal, EdgeTag = EdgeTag>
+        + HasApplyCache<M, BCDDOp>
+        + WorkerManager,
+    M::InnerNode: HasLevel,
+    M::Edge: Send + Sync,
+{
+    const FORALL: u8 = BCDDOp::Forall as u8;
+    const EXIST: u8 = BCDDOp::Exist as u8;
+    const UNIQUE: u8 = BCDDOp::Unique as u8;
+    const AND: u8 = BCDDOp::And as u8;
+    const XOR: u8 = BCDDOp::Xor as u8;
+
+    // `f <op> g ≡ ¬ⁿʰ(¬ⁿᶠf ∘ ¬ⁿᵍg)`, where `∘` is `∧` or `⊕`
+    let (xor, nf, ng, nh) = match op {
+        BooleanOperator::And => (false, false, false, false),
+        BooleanOperator::Or => (false, true, true, true),
+        BooleanOperator::Xor => (true, false, false, false),
+        BooleanOperator::Equiv => (true, true, false, false),
+        BooleanOperator::Nand => (false, false, false, true),
+        BooleanOperator::Nor => (false, true, true, false),
+        BooleanOperator::Imp => (false, false, true, true),
+        BooleanOperator::ImpStrict => (false, true, false, false),
+    };
+    let f = if nf { not(&*f) } else { f.borrowed() };
+    let g = if ng { not(&*g) } else { g.borrowed() };
+
+    if Q == UNIQUE {
+        // ∃!x. ¬h ≡ ∃!x. h, but only if there is at least one variable
+        let nh = nh && manager.get_node(&vars).is_any_terminal();
+        let res = if xor {
+            apply_quant::<M, UNIQUE, XOR>(manager, depth, f, g, vars)?
+        } else {
+            apply_quant::<M, UNIQUE, AND>(manager, depth, f, g, vars)?
+        };
+        return Ok(if nh { not_owned(res) } else { res });
+    }
+
+    // ∀x. ¬h ≡ ¬∃x. h
+    let exist = (Q == EXIST) != nh;
+    let res = match (xor, exist) {
+        (false, false) => apply_quant::<M, FORALL, AND>(manager, depth, f, g, vars)?,
+        (false, true) => apply_quant::<M, EXIST, AND>(manager, depth, f, g, vars)?,
+        // ∀x. f ⊕ g ≡ ¬∃x. ¬f ⊕ g (note that `nh` is false for `xor`)
+        (true, false) => not_owned(apply_quant::<M, EXIST, XOR>(
+            manager,
+            depth,
+            not(&f),
+            g,
+            vars,
+        )?),
+        (true, true) => apply_quant::<M, EXIST, XOR>(manager, depth, f, g, vars)?,
+    };
+    Ok(if nh { not_owned(res) } else { res })
+}
+
 // --- Function Interface ------------------------------------------------------
 
 /// Boolean function backed by a complement edge binary decision diagram
@@ -657,6 +817,27 @@ where
         get_terminal(manager, true)
     }
 
+    #[inline]
+    fn cube_edge<'id>(
+        manager: &Self::Manager<'id>,
+        literals: &[(LevelNo, bool)],
+    ) -> AllocResult<EdgeOfFunc<'id, Self>> {
+        match sort_literals(literals) {
+            Some(literals) => cube(manager, literals),
+            None => Ok(Self::f_edge(manager)),
+        }
+    }
+    #[inline]
+    fn clause_edge<'id>(
+        manager: &Self::Manager<'id>,
+        literals: &[(LevelNo, bool)],
+    ) -> AllocResult<EdgeOfFunc<'id, Self>> {
+        match sort_literals(literals) {
+            Some(literals) => clause(manager, literals),
+            None => Ok(Self::t_edge(manager)),
+        }
+    }
+
     #[inline]
     fn not_edge<'id>(
         manager: &Self::Manager<'id>,
@@ -757,6 +938,15 @@ where
         )
     }
 
+    #[inline]
+    fn support_edge<'id>(
+        manager: &Self::Manager<'id>,
+        edge: &EdgeOfFunc<'id, Self>,
+    ) -> AllocResult<EdgeOfFunc<'id, Self>> {
+        let levels = Self::support_levels_edge(manager, edge);
+        cube(manager, levels.into_iter().map(|level| (level, true)))
+    }
+
     #[inline]
     fn sat_count_edge<'id, N: SatCountNumber, S: std::hash::BuildHasher>(
         manager: &Self::Manager<'id>,
@@ -767,6 +957,16 @@ where
         apply_rec_st::BCDDFunction::<F>::sat_count_edge(manager, edge, vars, cache)
     }
 
+    #[inline]
+    fn weighted_sat_count_edge<'id, W: WeightedCountNumber, S: std::hash::BuildHasher>(
+        manager: &Self::Manager<'id>,
+        edge: &EdgeOfFunc<'id, Self>,
+        weights: impl Fn(LevelNo) -> (W, W),
+        cache: &mut WeightedSatCountCache<W, S>,
+    ) -> W {
+        apply_rec_st::BCDDFunction::<F>::weighted_sat_count_edge(manager, edge, weights, cache)
+    }
+
     #[inline]
     fn pick_cube_edge<'id, 'a, I>(
         manager: &'a Self::Manager<'id>,
@@ -808,6 +1008,50 @@ where
         restrict(manager, d, root.borrowed(), vars.borrowed())
     }
 
+    #[inline]
+    fn constrain_edge<'id>(
+        manager: &Self::Manager<'id>,
+        root: &EdgeOfFunc<'id, Self>,
+        care: &EdgeOfFunc<'id, Self>,
+    ) -> AllocResult<EdgeOfFunc<'id, Self>> {
+        apply_rec_st::gen_cofactor::<_, { BCDDOp::Constrain as u8 }>(
+            manager,
+            root.borrowed(),
+            care.borrowed(),
+        )
+    }
+
+    #[inline]
+    fn minimize_edge<'id>(
+        manager: &Self::Manager<'id>,
+        root: &EdgeOfFunc<'id, Self>,
+        care: &EdgeOfFunc<'id, Self>,
+    ) -> AllocResult<EdgeOfFunc<'id, Self>> {
+        apply_rec_st::gen_cofactor::<_, { BCDDOp::Minimize as u8 }>(
+            manager,
+            root.borrowed(),
+            care.borrowed(),
+        )
+    }
+
+    #[inline]
+    fn li_compaction_edge<'id>(
+        manager: &Self::Manager<'id>,
+        root: &EdgeOfFunc<'id, Self>,
+        care: &EdgeOfFunc<'id, Self>,
+    ) -> AllocResult<EdgeOfFunc<'id, Self>> {
+        apply_rec_st::li_compaction(manager, root.borrowed(), care.borrowed())
+    }
+
+    #[inline]
+    fn isop_edge<'id>(
+        manager: &Self::Manager<'id>,
+        lower: &EdgeOfFunc<'id, Self>,
+        upper: &EdgeOfFunc<'id, Self>,
+    ) -> AllocResult<EdgeOfFunc<'id, Self>> {
+        apply_rec_st::isop(manager, lower.borrowed(), upper.borrowed())
+    }
+
     #[inline]
     fn forall_edge<'id>(
         manager: &Self::Manager<'id>,
@@ -837,6 +1081,63 @@ where
         let d = Self::init_depth(manager);
         quant::<_, { BCDDOp::Unique as u8 }>(manager, d, root.borrowed(), vars.borrowed())
     }
+
+    #[inline]
+    fn apply_exist_edge<'id>(
+        manager: &Self::Manager<'id>,
+        op: BooleanOperator,
+        lhs: &EdgeOfFunc<'id, Self>,
+        rhs: &EdgeOfFunc<'id, Self>,
+        vars: &EdgeOfFunc<'id, Self>,
+    ) -> AllocResult<EdgeOfFunc<'id, Self>> {
+        let d = Self::init_depth(manager);
+        apply_quant_dispatch::<_, { BCDDOp::Exist as u8 }>(
+            manager,
+            d,
+            op,
+            lhs.borrowed(),
+            rhs.borrowed(),
+            vars.borrowed(),
+        )
+    }
+
+    #[inline]
+    fn apply_forall_edge<'id>(
+        manager: &Self::Manager<'id>,
+        op: BooleanOperator,
+        lhs: &EdgeOfFunc<'id, Self>,
+        rhs: &EdgeOfFunc<'id, Self>,
+        vars: &EdgeOfFunc<'id, Self>,
+    ) -> AllocResult<EdgeOfFunc<'id, Self>> {
+        let d = Self::init_depth(manager);
+        apply_quant_dispatch::<_, { BCDDOp::Forall as u8 }>(
+            manager,
+            d,
+            op,
+            lhs.borrowed(),
+            rhs.borrowed(),
+            vars.borrowed(),
+        )
+    }
+
+    #[inline]
+    fn apply_unique_edge<'id>(
+        manager: &Self::Manager<'id>,
+        op: BooleanOperator,
+        lhs: &EdgeOfFunc<'id, Self>,
+        rhs: &EdgeOfFunc<'id, Self>,
+        vars: &EdgeOfFunc<'id, Self>,
+    ) -> AllocResult<EdgeOfFunc<'id, Self>> {
+        let d = Self::init_depth(manager);
+        apply_quant_dispatch::<_, { BCDDOp::Unique as u8 }>(
+            manager,
+            d,
+            op,
+            lhs.borrowed(),
+            rhs.borrowed(),
+            vars.borrowed(),
+        )
+    }
 }
 
 impl<F: Function, T: Tag> DotStyle<T> for BCDDFunctionMT<F> {}
@@ -1,14 +1,17 @@
 //! Recursive single-threaded apply algorithms
 
+use std::collections::HashMap;
 use std::hash::BuildHasher;
 
 use bitvec::vec::BitVec;
 
 use oxidd_core::function::BooleanFunction;
 use oxidd_core::function::BooleanFunctionQuant;
+use oxidd_core::function::BooleanOperator;
 use oxidd_core::function::EdgeOfFunc;
 use oxidd_core::function::Function;
 use oxidd_core::function::FunctionSubst;
+use oxidd_core::util::sort_literals;
 use oxidd_core::util::AllocResult;
 use oxidd_core::util::Borrowed;
 use oxidd_core::util::EdgeDropGuard;
@@ -16,6 +19,8 @@ use oxidd_core::util::EdgeVecDropGuard;
 use oxidd_core::util::OptBool;
 use oxidd_core::util::SatCountCache;
 use oxidd_core::util::SatCountNumber;
+use oxidd_core::util::WeightedCountNumber;
+use oxidd_core::util::WeightedSatCountCache;
 use oxidd_core::ApplyCache;
 use oxidd_core::Edge;
 use oxidd_core::HasApplyCache;
@@ -31,11 +36,14 @@ use oxidd_dump::dot::DotStyle;
 
 use crate::stat;
 
+use super::clause;
 use super::collect_cofactors;
+use super::cube;
 use super::get_terminal;
 use super::not;
 use super::not_owned;
 use super::reduce;
+use super::ApplyQuantOperation;
 use super::BCDDOp;
 use super::BCDDTerminal;
 use super::EdgeTag;
@@ -589,6 +597,368 @@ where
     }
 }
 
+/// Compute the generalized cofactor of `f` with respect to the care set `c`
+///
+/// `OP` is one of `BCDDOp::Constrain` or `BCDDOp::Minimize` as `u8`. In case
+/// of `BCDDOp::Minimize`, variables of `c` above the top-most variable of `f`
+/// are existentially quantified before descending (Coudert and Madre's
+/// `restrict` operator).
+pub(super) fn gen_cofactor<M, const OP: u8>(
+    manager: &M,
+    f: Borrowed<M::Edge>,
+    c: Borrowed<M::Edge>,
+) -> AllocResult<M::Edge>
+where
+    M: Manager<Terminal = BCDDTerminal, EdgeTag = EdgeTag> + HasApplyCache<M, BCDDOp>,
+    M::InnerNode: HasLevel,
+{
+    let operator = match () {
+        _ if OP == BCDDOp::Constrain as u8 => BCDDOp::Constrain,
+        _ if OP == BCDDOp::Minimize as u8 => BCDDOp::Minimize,
+        _ => unreachable!("invalid operator"),
+    };
+
+    stat!(call operator);
+    // Terminal cases
+    let cnode = match manager.get_node(&c) {
+        Node::Inner(n) => n,
+        Node::Terminal(_) if c.tag() == EdgeTag::Complemented => {
+            return Ok(get_terminal(manager, false))
+        }
+        Node::Terminal(_) => return Ok(manager.clone_edge(&f)),
+    };
+    let fnode = match manager.get_node(&f) {
+        Node::Inner(n) => n,
+        Node::Terminal(_) => return Ok(manager.clone_edge(&f)),
+    };
+    if f.node_id() == c.node_id() {
+        return Ok(get_terminal(manager, f.tag() == c.tag()));
+    }
+    let flevel = fnode.level();
+    let clevel = cnode.level();
+
+    if operator == BCDDOp::Minimize && clevel < flevel {
+        // `f` does not depend on the top-most variable of `c`
+        let (ct, ce) = collect_cofactors(c.tag(), cnode);
+        let c = EdgeDropGuard::new(manager, not_owned(apply_and(manager, not(&ct), not(&ce))?));
+        return gen_cofactor::<M, OP>(manager, f, c.borrowed());
+    }
+
+    // Both operators commute with negation of `f`, so we only need to store
+    // results for non-complemented `f` in the apply cache.
+    let f_untagged = f.with_tag(EdgeTag::None);
+    let f_tag = f.tag();
+
+    // Query apply cache
+    stat!(cache_query operator);
+    if let Some(res) =
+        manager
+            .apply_cache()
+            .get(manager, operator, &[f_untagged.borrowed(), c.borrowed()])
+    {
+        stat!(cache_hit operator);
+        let res_tag = res.tag();
+        return Ok(res.with_tag_owned(res_tag ^ f_tag));
+    }
+
+    let level = std::cmp::min(flevel, clevel);
+    let (ft, fe) = if flevel == level {
+        collect_cofactors(EdgeTag::None, fnode)
+    } else {
+        (f_untagged.borrowed(), f_untagged.borrowed())
+    };
+    let (ct, ce) = if clevel == level {
+        collect_cofactors(c.tag(), cnode)
+    } else {
+        (c.borrowed(), c.borrowed())
+    };
+
+    let is_false =
+        |e: &M::Edge| e.tag() == EdgeTag::Complemented && manager.get_node(e).is_any_terminal();
+    let res = if is_false(&ct) {
+        gen_cofactor::<M, OP>(manager, fe, ce)?
+    } else if is_false(&ce) {
+        gen_cofactor::<M, OP>(manager, ft, ct)?
+    } else {
+        let t = EdgeDropGuard::new(manager, gen_cofactor::<M, OP>(manager, ft, ct)?);
+        let e = EdgeDropGuard::new(manager, gen_cofactor::<M, OP>(manager, fe, ce)?);
+        reduce(manager, level, t.into_edge(), e.into_edge(), operator)?
+    };
+
+    manager
+        .apply_cache()
+        .add(manager, operator, &[f_untagged, c], res.borrowed());
+
+    let res_tag = res.tag();
+    Ok(res.with_tag_owned(res_tag ^ f_tag))
+}
+
+/// Marks for leaf-identifying compaction: the terminals reachable from an edge
+/// under the care set (`LIC_TRUE`, `LIC_FALSE`, or both); `0` means that the
+/// edge is never taken under the care set.
+const LIC_TRUE: u8 = 0b01;
+const LIC_FALSE: u8 = 0b10;
+
+/// Mark phase of the leaf-identifying compaction of `f` with respect to `c`
+///
+/// For every inner node of `f` taken by a valuation satisfying `c`, this
+/// records in `marks` which terminals are reached via the node's outgoing
+/// edges under the care set. The marks refer to the node's children as stored,
+/// i.e., they do not depend on the tag of the incoming edge. `visited`
+/// memoizes the result per pair `(f, c)` with `f` not being complemented.
+fn li_compaction_mark<M>(
+    manager: &M,
+    f: Borrowed<M::Edge>,
+    c: Borrowed<M::Edge>,
+    visited: &mut HashMap<(NodeID, NodeID, bool), u8>,
+    marks: &mut HashMap<NodeID, [u8; 2]>,
+) -> u8
+where
+    M: Manager<Terminal = BCDDTerminal, EdgeTag = EdgeTag>,
+    M::InnerNode: HasLevel,
+{
+    let cnode = match manager.get_node(&c) {
+        Node::Inner(n) => Some(n),
+        Node::Terminal(_) if c.tag() == EdgeTag::Complemented => return 0,
+        Node::Terminal(_) => None,
+    };
+    let fnode = match manager.get_node(&f) {
+        Node::Inner(n) => n,
+        Node::Terminal(_) if f.tag() == EdgeTag::Complemented => return LIC_FALSE,
+        Node::Terminal(_) => return LIC_TRUE,
+    };
+
+    let key = (f.node_id(), c.node_id(), c.tag() == EdgeTag::Complemented);
+    let res = if let Some(&res) = visited.get(&key) {
+        res
+    } else {
+        let f_untagged = f.with_tag(EdgeTag::None);
+        let flevel = fnode.level();
+        let clevel = cnode.map_or(LevelNo::MAX, |n| n.level());
+        let level = std::cmp::min(flevel, clevel);
+        let (ft, fe) = if flevel == level {
+            collect_cofactors(EdgeTag::None, fnode)
+        } else {
+            (f_untagged.borrowed(), f_untagged.borrowed())
+        };
+        let (ct, ce) = match cnode {
+            Some(cnode) if clevel == level => collect_cofactors(c.tag(), cnode),
+            _ => (c.borrowed(), c.borrowed()),
+        };
+
+        let t = li_compaction_mark(manager, ft, ct, visited, marks);
+        let e = li_compaction_mark(manager, fe, ce, visited, marks);
+        if flevel == level {
+            let m = marks.entry(f.node_id()).or_default();
+            m[0] |= t;
+            m[1] |= e;
+        }
+
+        visited.insert(key, t | e);
+        t | e
+    };
+
+    if f.tag() == EdgeTag::Complemented {
+        ((res & LIC_TRUE) << 1) | ((res & LIC_FALSE) >> 1)
+    } else {
+        res
+    }
+}
+
+/// Build phase of the leaf-identifying compaction
+///
+/// Every node of `f` is replaced by at most one node in the result, hence the
+/// result is never larger than `f`. `results` maps the nodes to the results
+/// for the non-complemented incoming edge.
+fn li_compaction_build<M>(
+    manager: &M,
+    f: Borrowed<M::Edge>,
+    marks: &HashMap<NodeID, [u8; 2]>,
+    results: &mut HashMap<NodeID, M::Edge>,
+) -> AllocResult<M::Edge>
+where
+    M: Manager<Terminal = BCDDTerminal, EdgeTag = EdgeTag>,
+    M::InnerNode: HasLevel,
+{
+    let fnode = match manager.get_node(&f) {
+        Node::Inner(n) => n,
+        Node::Terminal(_) => return Ok(manager.clone_edge(&f)),
+    };
+    let res = if let Some(res) = results.get(&f.node_id()) {
+        manager.clone_edge(res)
+    } else {
+        let [mt, me] = marks[&f.node_id()];
+        let (ft, fe) = collect_cofactors(EdgeTag::None, fnode);
+        let mut build = |child: Borrowed<M::Edge>, mark: u8| match mark {
+            LIC_TRUE => Ok(Some(get_terminal(manager, true))),
+            LIC_FALSE => Ok(Some(get_terminal(manager, false))),
+            0 => Ok(None),
+            _ => li_compaction_build(manager, child, marks, results).map(Some),
+        };
+        let t = build(ft, mt)?;
+        let t = t.map(|t| EdgeDropGuard::new(manager, t));
+        let e = build(fe, me)?;
+        let res = match (t, e) {
+            (Some(t), Some(e)) => reduce(
+                manager,
+                fnode.level(),
+                t.into_edge(),
+                e,
+                BCDDOp::LICompaction,
+            )?,
+            (Some(t), None) => t.into_edge(),
+            (None, Some(e)) => e,
+            (None, None) => get_terminal(manager, false),
+        };
+
+        results.insert(f.node_id(), manager.clone_edge(&res));
+        res
+    };
+
+    if f.tag() == EdgeTag::Complemented {
+        Ok(not_owned(res))
+    } else {
+        Ok(res)
+    }
+}
+
+/// Compute the safe leaf-identifying compaction of `f` with respect to the care
+/// set `c` (Hong et al., 1997)
+pub(super) fn li_compaction<M>(
+    manager: &M,
+    f: Borrowed<M::Edge>,
+    c: Borrowed<M::Edge>,
+) -> AllocResult<M::Edge>
+where
+    M: Manager<Terminal = BCDDTerminal, EdgeTag = EdgeTag> + HasApplyCache<M, BCDDOp>,
+    M::InnerNode: HasLevel,
+{
+    stat!(call BCDDOp::LICompaction);
+    if manager.get_node(&c).is_any_terminal() {
+        return Ok(if c.tag() == EdgeTag::Complemented {
+            get_terminal(manager, false)
+        } else {
+            manager.clone_edge(&f)
+        });
+    }
+    if manager.get_node(&f).is_any_terminal() {
+        return Ok(manager.clone_edge(&f));
+    }
+
+    // Query apply cache
+    stat!(cache_query BCDDOp::LICompaction);
+    if let Some(res) =
+        manager
+            .apply_cache()
+            .get(manager, BCDDOp::LICompaction, &[f.borrowed(), c.borrowed()])
+    {
+        stat!(cache_hit BCDDOp::LICompaction);
+        return Ok(res);
+    }
+
+    let mut marks = HashMap::new();
+    li_compaction_mark(
+        manager,
+        f.borrowed(),
+        c.borrowed(),
+        &mut HashMap::new(),
+        &mut marks,
+    );
+    let mut results = HashMap::new();
+    let res = li_compaction_build(manager, f.borrowed(), &marks, &mut results);
+    for (_, e) in results.drain() {
+        manager.drop_edge(e);
+    }
+    let res = res?;
+
+    manager
+        .apply_cache()
+        .add(manager, BCDDOp::LICompaction, &[f, c], res.borrowed());
+
+    Ok(res)
+}
+
+/// Compute the function of an irredundant sum-of-products cover of the
+/// interval `[lower, upper]` (Minato–Morreale algorithm)
+///
+/// `lower` must imply `upper`, otherwise the result is unspecified.
+pub(super) fn isop<M>(
+    manager: &M,
+    lower: Borrowed<M::Edge>,
+    upper: Borrowed<M::Edge>,
+) -> AllocResult<M::Edge>
+where
+    M: Manager<Terminal = BCDDTerminal, EdgeTag = EdgeTag> + HasApplyCache<M, BCDDOp>,
+    M::InnerNode: HasLevel,
+{
+    stat!(call BCDDOp::Isop);
+    // Terminal cases
+    let llevel = match manager.get_node(&lower) {
+        Node::Inner(n) => n.level(),
+        Node::Terminal(_) if lower.tag() == EdgeTag::Complemented => {
+            return Ok(get_terminal(manager, false))
+        }
+        Node::Terminal(_) => LevelNo::MAX,
+    };
+    let unode = match manager.get_node(&upper) {
+        Node::Inner(n) => n,
+        Node::Terminal(_) => return Ok(manager.clone_edge(&upper)),
+    };
+
+    // Query apply cache
+    stat!(cache_query BCDDOp::Isop);
+    if let Some(res) =
+        manager
+            .apply_cache()
+            .get(manager, BCDDOp::Isop, &[lower.borrowed(), upper.borrowed()])
+    {
+        stat!(cache_hit BCDDOp::Isop);
+        return Ok(res);
+    }
+
+    let ulevel = unode.level();
+    let level = std::cmp::min(llevel, ulevel);
+    let (lt, le) = if llevel == level {
+        collect_cofactors(lower.tag(), manager.get_node(&lower).unwrap_inner())
+    } else {
+        (lower.borrowed(), lower.borrowed())
+    };
+    let (ut, ue) = if ulevel == level {
+        collect_cofactors(upper.tag(), unode)
+    } else {
+        (upper.borrowed(), upper.borrowed())
+    };
+
+    let guard = |e| EdgeDropGuard::new(manager, e);
+    // `¬f ∧ g`
+    let imp_strict = |f: &M::Edge, g| apply_and(manager, not(f), g);
+    // `f ∨ g`
+    let or = |f: &M::Edge, g: &M::Edge| Ok(not_owned(apply_and(manager, not(f), not(g))?));
+
+    // Cubes containing the positive literal
+    let lt_pos = guard(imp_strict(&ue, lt.borrowed())?);
+    let ft = guard(isop(manager, lt_pos.borrowed(), ut.borrowed())?);
+    // Cubes containing the negative literal
+    let le_neg = guard(imp_strict(&ut, le.borrowed())?);
+    let fe = guard(isop(manager, le_neg.borrowed(), ue.borrowed())?);
+    // Cubes containing neither of the two literals
+    let lt_dc = guard(imp_strict(&ft, lt.borrowed())?);
+    let le_dc = guard(imp_strict(&fe, le.borrowed())?);
+    let ld = guard(or(&lt_dc, &le_dc)?);
+    let ud = guard(apply_and(manager, ut, ue)?);
+    let fd = guard(isop(manager, ld.borrowed(), ud.borrowed())?);
+
+    let t = guard(or(&ft, &fd)?);
+    let e = or(&fe, &fd)?;
+    let res = reduce(manager, level, t.into_edge(), e, BCDDOp::Isop)?;
+
+    manager
+        .apply_cache()
+        .add(manager, BCDDOp::Isop, &[lower, upper], res.borrowed());
+
+    Ok(res)
+}
+
 /// Compute the quantification `Q` over `vars`
 ///
 /// `Q` is one of `BCDDOp::Forall`, `BCDDOp::Exist`, or `BCDDOp::Forall` as
@@ -688,6 +1058,137 @@ where
     Ok(res)
 }
 
+/// Recursively apply the binary operator `OP` to `f` and `g` and quantify the
+/// result over `vars` using `Q`
+///
+/// `Q` and `OP` are restricted as described in [`BCDDOp::from_apply_quant()`].
+/// In contrast to applying `OP` first and quantifying afterwards, this does not
+/// construct the (possibly large) intermediate result.
+pub(super) fn apply_quant<M, const Q: u8, const OP: u8>(
+    manager: &M,
+    f: Borrowed<M::Edge>,
+    g: Borrowed<M::Edge>,
+    vars: Borrowed<M::Edge>,
+) -> AllocResult<M::Edge>
+where
+    M: Manager<Terminal = BCDDTerminal, EdgeTag = EdgeTag> + HasApplyCache<M, BCDDOp>,
+    M::InnerNode: HasLevel,
+{
+    let operator = const { BCDDOp::from_apply_quant(Q, OP) };
+
+    stat!(call operator);
+    let rec = match super::terminal_apply_quant::<M, Q, OP>(manager, &f, &g, &vars) {
+        ApplyQuantOperation::Rec(rec) => rec,
+        ApplyQuantOperation::Quant(h) => {
+            let h = EdgeDropGuard::new(manager, h);
+            return quant::<M, Q>(manager, h.borrowed(), vars);
+        }
+        ApplyQuantOperation::Apply(f, g) => return apply_bin::<M, OP>(manager, f, g),
+        ApplyQuantOperation::Done(h) => return Ok(h),
+    };
+
+    // Query apply cache
+    stat!(cache_query operator);
+    if let Some(res) = manager.apply_cache().get(
+        manager,
+        operator,
+        &[rec.f.borrowed(), rec.g.borrowed(), rec.vars.borrowed()],
+    ) {
+        stat!(cache_hit operator);
+        return Ok(res);
+    }
+
+    let ([(ft, gt), (fe, ge)], vt) = rec.cofactors();
+    let t = EdgeDropGuard::new(
+        manager,
+        apply_quant::<M, Q, OP>(manager, ft, gt, vt.borrowed())?,
+    );
+    let res = if !rec.quantify() {
+        let e = apply_quant::<M, Q, OP>(manager, fe, ge, vt)?;
+        reduce(manager, rec.level, t.into_edge(), e, operator)?
+    } else if super::quant_absorbing::<M, Q>(manager, &t) {
+        // No need to compute the other cofactor
+        t.into_edge()
+    } else {
+        let e = EdgeDropGuard::new(manager, apply_quant::<M, Q, OP>(manager, fe, ge, vt)?);
+        if Q == BCDDOp::Forall as u8 {
+            apply_and(manager, t.borrowed(), e.borrowed())?
+        } else if Q == BCDDOp::Exist as u8 {
+            not_owned(apply_and(manager, not(&t), not(&e))?)
+        } else {
+            apply_bin::<M, { BCDDOp::Xor as u8 }>(manager, t.borrowed(), e.borrowed())?
+        }
+    };
+
+    manager.apply_cache().add(
+        manager,
+        operator,
+        &[rec.f.borrowed(), rec.g.borrowed(), rec.vars.borrowed()],
+        res.borrowed(),
+    );
+
+    Ok(res)
+}
+
+/// Compute `Q vars. f <op> g`
+///
+/// `Q` is one of `BCDDOp::Forall`, `BCDDOp::Exist`, or `BCDDOp::Unique` as
+/// `u8`. Using complement edges, we reduce all operators to the combinations
+/// supported by [`BCDDOp::from_apply_quant()`].
+pub(super) fn apply_quant_dispatch<M, const Q: u8>(
+    manager: &M,
+    op: BooleanOperator,
+    f: Borrowed<M::Edge>,
+    g: Borrowed<M::Edge>,
+    vars: Borrowed<M::Edge>,
+) -> AllocResult<M::Edge>
+where
+    M: Manager<Terminal = BCDDTerminal, EdgeTag = EdgeTag> + HasApplyCache<M, BCDDOp>,
+    M::InnerNode: HasLevel,
+{
+    const FORALL: u8 = BCDDOp::Forall as u8;
+    const EXIST: u8 = BCDDOp::Exist as u8;
+    const UNIQUE: u8 = BCDDOp::Unique as u8;
+    const AND: u8 = BCDDOp::And as u8;
+    const XOR: u8 = BCDDOp::Xor as u8;
+
+    // `f <op> g ≡ ¬ⁿʰ(¬ⁿᶠf ∘ ¬ⁿᵍg)`, where `∘` is `∧` or `⊕`
+    let (xor, nf, ng, nh) = match op {
+        BooleanOperator::And => (false, false, false, false),
+        BooleanOperator::Or => (false, true, true, true),
+        BooleanOperator::Xor => (true, false, false, false),
+        BooleanOperator::Equiv => (true, true, false, false),
+        BooleanOperator::Nand => (false, false, false, true),
+        BooleanOperator::Nor => (false, true, true, false),
+        BooleanOperator::Imp => (false, false, true, true),
+        BooleanOperator::ImpStrict => (false, true, false, false),
+    };
+    let f = if nf { not(&*f) } else { f.borrowed() };
+    let g = if ng { not(&*g) } else { g.borrowed() };
+
+    if Q == UNIQUE {
+        // ∃!x. ¬h ≡ ∃!x. h, but only if there is at least one variable
+        let nh = nh && manager.get_node(&vars).is_any_terminal();
+        let res = if xor {
+            apply_quant::<M, UNIQUE, XOR>(manager, f, g, vars)?
+        } else {
+            apply_quant::<M, UNIQUE, AND>(manager, f, g, vars)?
+        };
+        return Ok(if nh { not_owned(res) } else { res });
+    }
+
+    // ∀x. ¬h ≡ ¬∃x. h
+    let exist = (Q == EXIST) != nh;
+    let res = match (xor, exist) {
+        (false, false) => apply_quant::<M, FORALL, AND>(manager, f, g, vars)?,
+        (false, true) => apply_quant::<M, EXIST, AND>(manager, f, g, vars)?,
+        // ∀x. f ⊕ g ≡ ¬∃x. ¬f ⊕ g (note that `nh` is false for `xor`)
+        (true, false) => not_owned(apply_quant::<M, EXIST, XOR>(manager, not(&f), g, vars)?),
+        (true, true) => apply_quant::<M, EXIST, XOR>(manager, f, g, vars)?,
+    };
+    Ok(if nh { not_owned(res) } else { res })
+}
+
 // --- Function Interface ------------------------------------------------------
 
 /// Boolean function backed by a complement edge binary decision diagram
@@ -752,6 +1253,27 @@ where
         get_terminal(manager, true)
     }
 
+    #[inline]
+    fn cube_edge<'id>(
+        manager: &Self::Manager<'id>,
+        literals: &[(LevelNo, bool)],
+    ) -> AllocResult<EdgeOfFunc<'id, Self>> {
+        match sort_literals(literals) {
+            Some(literals) => cube(manager, literals),
+            None => Ok(Self::f_edge(manager)),
+        }
+    }
+    #[inline]
+    fn clause_edge<'id>(
+        manager: &Self::Manager<'id>,
+        literals: &[(LevelNo, bool)],
+    ) -> AllocResult<EdgeOfFunc<'id, Self>> {
+        match sort_literals(literals) {
+            Some(literals) => clause(manager, literals),
+            None => Ok(Self::t_edge(manager)),
+        }
+    }
+
     #[inline]
     fn not_edge<'id>(
         manager: &Self::Manager<'id>,
@@ -847,6 +1369,15 @@ where
         )
     }
 
+    #[inline]
+    fn support_edge<'id>(
+        manager: &Self::Manager<'id>,
+        edge: &EdgeOfFunc<'id, Self>,
+    ) -> AllocResult<EdgeOfFunc<'id, Self>> {
+        let levels = Self::support_levels_edge(manager, edge);
+        cube(manager, levels.into_iter().map(|level| (level, true)))
+    }
+
     #[inline]
     fn sat_count_edge<'id, N: SatCountNumber, S: BuildHasher>(
         manager: &Self::Manager<'id>,
@@ -945,6 +1476,79 @@ where
         }
     }
 
+    fn weighted_sat_count_edge<'id, W: WeightedCountNumber, S: BuildHasher>(
+        manager: &Self::Manager<'id>,
+        edge: &EdgeOfFunc<'id, Self>,
+        weights: impl Fn(LevelNo) -> (W, W),
+        cache: &mut WeightedSatCountCache<W, S>,
+    ) -> W {
+        fn inner<M, W, S>(
+            manager: &M,
+            e: Borrowed<M::Edge>,
+            weights: &[(W, W)],
+            skip: &[W],
+            cache: &mut WeightedSatCountCache<W, S>,
+        ) -> W
+        where
+            M: Manager<EdgeTag = EdgeTag, Terminal = BCDDTerminal>,
+            M::InnerNode: HasLevel,
+            W: WeightedCountNumber,
+            S: BuildHasher,
+        {
+            let tag = e.tag();
+            let node = match manager.get_node(&e) {
+                Node::Inner(node) => node,
+                Node::Terminal(_) if tag == EdgeTag::None => return W::from(1u32),
+                Node::Terminal(_) => return W::from(0u32),
+            };
+            // MSB of NodeIDs is reserved [for us :)]
+            let node_id = e.node_id() | ((tag as NodeID) << (NodeID::BITS - 1));
+            if let Some(w) = cache.map.get(&node_id) {
+                return w.clone();
+            }
+
+            let level = node.level() as usize;
+            let (e0, e1) = collect_cofactors(tag, node);
+            let mut res = W::from(0u32);
+            for (child, weight) in [(e0, &weights[level].0), (e1, &weights[level].1)] {
+                let mut w = inner(manager, child.borrowed(), weights, skip, cache);
+                w *= weight;
+                let child_level = match manager.get_node(&child) {
+                    Node::Inner(node) => node.level() as usize,
+                    Node::Terminal(_) => weights.len(),
+                };
+                for s in &skip[level + 1..child_level] {
+                    w *= s;
+                }
+                res += &w;
+            }
+            cache.map.insert(node_id, res.clone());
+            res
+        }
+
+        cache.clear_if_invalid(manager);
+        let weights: Vec<(W, W)> = (0..manager.num_levels()).map(weights).collect();
+        // Weight of a level not occurring on a path: the variable is a "don't care"
+        let skip: Vec<W> = weights
+            .iter()
+            .map(|(pos, neg)| {
+                let mut w = pos.clone();
+                w += neg;
+                w
+            })
+            .collect();
+
+        let mut res = inner(manager, edge.borrowed(), &weights, &skip, cache);
+        let level = match manager.get_node(edge) {
+            Node::Inner(node) => node.level() as usize,
+            Node::Terminal(_) => weights.len(),
+        };
+        for s in &skip[..level] {
+            res *= s;
+        }
+        res
+    }
+
     #[inline]
     fn pick_cube_edge<'id, 'a, I>(
         manager: &'a Self::Manager<'id>,
@@ -1055,6 +1659,42 @@ where
         restrict(manager, root.borrowed(), vars.borrowed())
     }
 
+    #[inline]
+    fn constrain_edge<'id>(
+        manager: &Self::Manager<'id>,
+        root: &EdgeOfFunc<'id, Self>,
+        care: &EdgeOfFunc<'id, Self>,
+    ) -> AllocResult<EdgeOfFunc<'id, Self>> {
+        gen_cofactor::<_, { BCDDOp::Constrain as u8 }>(manager, root.borrowed(), care.borrowed())
+    }
+
+    #[inline]
+    fn minimize_edge<'id>(
+        manager: &Self::Manager<'id>,
+        root: &EdgeOfFunc<'id, Self>,
+        care: &EdgeOfFunc<'id, Self>,
+    ) -> AllocResult<EdgeOfFunc<'id, Self>> {
+        gen_cofactor::<_, { BCDDOp::Minimize as u8 }>(manager, root.borrowed(), care.borrowed())
+    }
+
+    #[inline]
+    fn li_compaction_edge<'id>(
+        manager: &Self::Manager<'id>,
+        root: &EdgeOfFunc<'id, Self>,
+        care: &EdgeOfFunc<'id, Self>,
+    ) -> AllocResult<EdgeOfFunc<'id, Self>> {
+        li_compaction(manager, root.borrowed(), care.borrowed())
+    }
+
+    #[inline]
+    fn isop_edge<'id>(
+        manager: &Self::Manager<'id>,
+        lower: &EdgeOfFunc<'id, Self>,
+        upper: &EdgeOfFunc<'id, Self>,
+    ) -> AllocResult<EdgeOfFunc<'id, Self>> {
+        isop(manager, lower.borrowed(), upper.borrowed())
+    }
+
     #[inline]
     fn forall_edge<'id>(
         manager: &Self::Manager<'id>,
@@ -1081,6 +1721,57 @@ where
     ) -> AllocResult<EdgeOfFunc<'id, Self>> {
         quant::<_, { BCDDOp::Unique as u8 }>(manager, root.borrowed(), vars.borrowed())
     }
+
+    #[inline]
+    fn apply_exist_edge<'id>(
+        manager: &Self::Manager<'id>,
+        op: BooleanOperator,
+        lhs: &EdgeOfFunc<'id, Self>,
+        rhs: &EdgeOfFunc<'id, Self>,
+        vars: &EdgeOfFunc<'id, Self>,
+    ) -> AllocResult<EdgeOfFunc<'id, Self>> {
+        apply_quant_dispatch::<_, { BCDDOp::Exist as u8 }>(
+            manager,
+            op,
+            lhs.borrowed(),
+            rhs.borrowed(),
+            vars.borrowed(),
+        )
+    }
+
+    #[inline]
+    fn apply_forall_edge<'id>(
+        manager: &Self::Manager<'id>,
+        op: BooleanOperator,
+        lhs: &EdgeOfFunc<'id, Self>,
+        rhs: &EdgeOfFunc<'id, Self>,
+        vars: &EdgeOfFunc<'id, Self>,
+    ) -> AllocResult<EdgeOfFunc<'id, Self>> {
+        apply_quant_dispatch::<_, { BCDDOp::Forall as u8 }>(
+            manager,
+            op,
+            lhs.borrowed(),
+            rhs.borrowed(),
+            vars.borrowed(),
+        )
+    }
+
+    #[inline]
+    fn apply_unique_edge<'id>(
+        manager: &Self::Manager<'id>,
+        op: BooleanOperator,
+        lhs: &EdgeOfFunc<'id, Self>,
+        rhs: &EdgeOfFunc<'id, Self>,
+        vars: &EdgeOfFunc<'id, Self>,
+    ) -> AllocResult<EdgeOfFunc<'id, Self>> {
+        apply_quant_dispatch::<_, { BCDDOp::Unique as u8 }>(
+            manager,
+            op,
+            lhs.borrowed(),
+            rhs.borrowed(),
+            vars.borrowed(),
+        )
+    }
 }
 
 impl<F: Function, T: Tag> DotStyle<T> for BCDDFunction<F> {}
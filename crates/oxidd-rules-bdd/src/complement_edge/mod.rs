@@ -8,6 +8,7 @@ use std::marker::PhantomData;
 
 use oxidd_core::util::AllocResult;
 use oxidd_core::util::Borrowed;
+use oxidd_core::util::OutOfMemory;
 use oxidd_core::DiagramRules;
 use oxidd_core::Edge;
 use oxidd_core::HasApplyCache;
@@ -212,6 +213,30 @@ fn reduce<M>(
     e: M::Edge,
     _op: BCDDOp,
 ) -> AllocResult<M::Edge>
+where
+    M: Manager<Terminal = BCDDTerminal, EdgeTag = EdgeTag>,
+{
+    if manager.reorder_requested() {
+        // Abort the operation, it will be restarted after reordering
+        manager.drop_edge(t);
+        manager.drop_edge(e);
+        return Err(OutOfMemory);
+    }
+    reduce_no_abort(manager, level, t, e, _op)
+}
+
+/// Like [`reduce()`], but without aborting if a reordering is requested
+///
+/// Only used for the literal chains of [`cube()`] and [`clause()`], see the
+/// respective function in the simple BDD module.
+#[inline(always)]
+fn reduce_no_abort<M>(
+    manager: &M,
+    level: LevelNo,
+    t: M::Edge,
+    e: M::Edge,
+    _op: BCDDOp,
+) -> AllocResult<M::Edge>
 where
     M: Manager<Terminal = BCDDTerminal, EdgeTag = EdgeTag>,
 {
@@ -359,6 +384,54 @@ pub enum BCDDOp {
     Exist,
     /// Unique quantification
     Unique,
+
+    /// Conjunction with subsequent universal quantification
+    ForallAnd,
+    /// Conjunction with subsequent existential quantification
+    ExistAnd,
+    /// Exclusive disjunction with subsequent existential quantification
+    ExistXor,
+    /// Conjunction with subsequent unique quantification
+    UniqueAnd,
+    /// Exclusive disjunction with subsequent unique quantification
+    UniqueXor,
+
+    /// Generalized cofactor (Coudert and Madre's `constrain`)
+    Constrain,
+    /// Coudert and Madre's `restrict` heuristic
+    Minimize,
+    /// Safe leaf-identifying compaction
+    LICompaction,
+    /// Irredundant sum-of-products (Minato–Morreale)
+    Isop,
+}
+
+impl BCDDOp {
+    /// Cache tag for applying the binary operator `op` and quantifying the
+    /// result using `q`
+    ///
+    /// `q` is one of `BCDDOp::Forall`, `BCDDOp::Exist`, or `BCDDOp::Unique`,
+    /// `op` is one of `BCDDOp::And` or `BCDDOp::Xor`, both as `u8`. Universal
+    /// quantification of an exclusive disjunction is not supported, use
+    /// `∀x. f ⊕ g ≡ ¬∃x. ¬f ⊕ g` instead. Callers evaluate this function in a
+    /// const context, so unsupported combinations are rejected at compile
+    /// time.
+    const fn from_apply_quant(q: u8, op: u8) -> Self {
+        const FORALL: u8 = BCDDOp::Forall as u8;
+        const EXIST: u8 = BCDDOp::Exist as u8;
+        const UNIQUE: u8 = BCDDOp::Unique as u8;
+        const AND: u8 = BCDDOp::And as u8;
+        const XOR: u8 = BCDDOp::Xor as u8;
+
+        match (q, op) {
+            (FORALL, AND) => BCDDOp::ForallAnd,
+            (EXIST, AND) => BCDDOp::ExistAnd,
+            (EXIST, XOR) => BCDDOp::ExistXor,
+            (UNIQUE, AND) => BCDDOp::UniqueAnd,
+            (UNIQUE, XOR) => BCDDOp::UniqueXor,
+            _ => panic!("invalid quantifier or operator"),
+        }
+    }
 }
 
 enum NodesOrDone<'a, E, N> {
@@ -366,6 +439,137 @@ enum NodesOrDone<'a, E, N> {
     Done(E),
 }
 
+/// Terminal cases of applying a binary operator and quantifying the result,
+/// see [`terminal_apply_quant()`]
+enum ApplyQuantOperation<'a, E: 'a + Edge, N> {
+    /// Quantify the given function
+    Quant(E),
+    /// There are no variables to quantify, just apply the operator
+    Apply(Borrowed<'a, E>, Borrowed<'a, E>),
+    Done(E),
+    Rec(ApplyQuantRec<'a, E, N>),
+}
+
+/// Operands of the recursive case of applying a binary operator and
+/// quantifying the result
+struct ApplyQuantRec<'a, E: 'a + Edge, N> {
+    f: Borrowed<'a, E>,
+    fnode: &'a N,
+    g: Borrowed<'a, E>,
+    gnode: &'a N,
+    vars: Borrowed<'a, E>,
+    vnode: &'a N,
+    /// Top-most level of `f` and `g`
+    level: LevelNo,
+}
+
+impl<'a, E: 'a + Edge<Tag = EdgeTag>, N: InnerNode<E> + HasLevel> ApplyQuantRec<'a, E, N> {
+    /// Whether the variable at [`Self::level`] is quantified
+    #[inline]
+    fn quantify(&self) -> bool {
+        self.vnode.level() == self.level
+    }
+
+    /// Cofactors of `f` and `g` for the variable at [`Self::level`] (then
+    /// case first) along with the remaining variables to quantify
+    #[inline]
+    #[allow(clippy::type_complexity)]
+    fn cofactors(&self) -> ([(Borrowed<'_, E>, Borrowed<'_, E>); 2], Borrowed<'_, E>) {
+        let (ft, fe) = if self.fnode.level() == self.level {
+            collect_cofactors(self.f.tag(), self.fnode)
+        } else {
+            (self.f.borrowed(), self.f.borrowed())
+        };
+        let (gt, ge) = if self.gnode.level() == self.level {
+            collect_cofactors(self.g.tag(), self.gnode)
+        } else {
+            (self.g.borrowed(), self.g.borrowed())
+        };
+        let vt = if self.quantify() {
+            self.vnode.child(0)
+        } else {
+            self.vars.borrowed()
+        };
+        ([(ft, gt), (fe, ge)], vt)
+    }
+}
+
+/// Terminal cases for applying the binary operator `OP` to `f` and `g` and
+/// quantifying the result over `vars` using `Q`
+///
+/// `Q` and `OP` are restricted as described in [`BCDDOp::from_apply_quant()`].
+/// In the recursive case, the variables above the top-most node of `f` and
+/// `g` are removed from `vars` (except for unique quantification).
+#[inline]
+fn terminal_apply_quant<'a, M, const Q: u8, const OP: u8>(
+    manager: &'a M,
+    f: &'a M::Edge,
+    g: &'a M::Edge,
+    vars: &'a M::Edge,
+) -> ApplyQuantOperation<'a, M::Edge, M::InnerNode>
+where
+    M: Manager<Terminal = BCDDTerminal, EdgeTag = EdgeTag>,
+    M::InnerNode: HasLevel,
+{
+    use ApplyQuantOperation::*;
+
+    let nodes = if OP == BCDDOp::And as u8 {
+        terminal_and(manager, f, g)
+    } else {
+        terminal_xor(manager, f, g)
+    };
+    let (f, fnode, g, gnode) = match nodes {
+        NodesOrDone::Nodes(fnode, gnode) if f < g => (f, fnode, g, gnode),
+        // Both operators are commutative
+        NodesOrDone::Nodes(fnode, gnode) => (g, gnode, f, fnode),
+        NodesOrDone::Done(h) => return Quant(h),
+    };
+    let level = std::cmp::min(fnode.level(), gnode.level());
+
+    let vars = if Q != BCDDOp::Unique as u8 {
+        // Variables above the top-most node do not occur in the result of
+        // `OP`, so we can ignore them.
+        crate::set_pop(manager, vars.borrowed(), level)
+    } else {
+        // For unique quantification, such a variable yields `h ⊕ h ≡ ⊥`. We
+        // handle this below.
+        vars.borrowed()
+    };
+    let vnode = match manager.get_node(&vars) {
+        Node::Inner(n) => n,
+        Node::Terminal(_) => return Apply(f.borrowed(), g.borrowed()),
+    };
+    if Q == BCDDOp::Unique as u8 && vnode.level() < level {
+        return Done(get_terminal(manager, false));
+    }
+
+    Rec(ApplyQuantRec {
+        f: f.borrowed(),
+        fnode,
+        g: g.borrowed(),
+        gnode,
+        vars,
+        vnode,
+        level,
+    })
+}
+
+/// Returns `true` iff `h` is the absorbing element of the quantifier `Q` (see
+/// [`BCDDOp::from_apply_quant()`]), i.e., `⊥` for universal and `⊤` for
+/// existential quantification
+///
+/// If this holds for one cofactor with respect to a quantified variable, the
+/// other cofactor does not matter.
+#[inline]
+fn quant_absorbing<M, const Q: u8>(manager: &M, h: &M::Edge) -> bool
+where
+    M: Manager<Terminal = BCDDTerminal, EdgeTag = EdgeTag>,
+{
+    Q != BCDDOp::Unique as u8
+        && manager.get_node(h).is_any_terminal()
+        && (h.tag() == EdgeTag::None) == (Q == BCDDOp::Exist as u8)
+}
+
 #[cfg(feature = "statistics")]
 static STAT_COUNTERS: [crate::StatCounters; <BCDDOp as oxidd_core::Countable>::MAX_VALUE + 1] =
     [crate::StatCounters::INIT; <BCDDOp as oxidd_core::Countable>::MAX_VALUE + 1];
@@ -406,6 +610,42 @@ where
     node.level()
 }
 
+/// Build the conjunction of `literals`
+///
+/// `literals` must be sorted by level and must not contain a variable twice.
+fn cube<M, I>(manager: &M, literals: I) -> AllocResult<M::Edge>
+where
+    M: Manager<Terminal = BCDDTerminal, EdgeTag = EdgeTag>,
+    I: IntoIterator<Item = (LevelNo, bool)>,
+    I::IntoIter: DoubleEndedIterator,
+{
+    let mut cube = get_terminal(manager, true);
+    for (level, positive) in literals.into_iter().rev() {
+        let f = get_terminal(manager, false);
+        let (t, e) = if positive { (cube, f) } else { (f, cube) };
+        cube = reduce_no_abort(manager, level, t, e, BCDDOp::And)?;
+    }
+    Ok(cube)
+}
+
+/// Build the disjunction of `literals`
+///
+/// `literals` must be sorted by level and must not contain a variable twice.
+fn clause<M, I>(manager: &M, literals: I) -> AllocResult<M::Edge>
+where
+    M: Manager<Terminal = BCDDTerminal, EdgeTag = EdgeTag>,
+    I: IntoIterator<Item = (LevelNo, bool)>,
+    I::IntoIter: DoubleEndedIterator,
+{
+    let mut clause = get_terminal(manager, false);
+    for (level, positive) in literals.into_iter().rev() {
+        let t = get_terminal(manager, true);
+        let (t, e) = if positive { (t, clause) } else { (clause, t) };
+        clause = reduce_no_abort(manager, level, t, e, BCDDOp::And)?;
+    }
+    Ok(clause)
+}
+
 // --- Function Interface ------------------------------------------------------
 
 /// Workaround for https://github.com/rust-lang/rust/issues/49601
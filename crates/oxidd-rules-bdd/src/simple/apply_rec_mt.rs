@@ -4,15 +4,19 @@ use std::borrow::Borrow;
 
 use oxidd_core::function::BooleanFunction;
 use oxidd_core::function::BooleanFunctionQuant;
+use oxidd_core::function::BooleanOperator;
 use oxidd_core::function::EdgeOfFunc;
 use oxidd_core::function::Function;
 use oxidd_core::function::FunctionSubst;
+use oxidd_core::util::sort_literals;
 use oxidd_core::util::AllocResult;
 use oxidd_core::util::Borrowed;
 use oxidd_core::util::EdgeDropGuard;
 use oxidd_core::util::OptBool;
 use oxidd_core::util::SatCountCache;
 use oxidd_core::util::SatCountNumber;
+use oxidd_core::util::WeightedCountNumber;
+use oxidd_core::util::WeightedSatCountCache;
 use oxidd_core::ApplyCache;
 use oxidd_core::Edge;
 use oxidd_core::HasApplyCache;
@@ -29,8 +33,11 @@ use oxidd_dump::dot::DotStyle;
 use crate::stat;
 
 use super::apply_rec_st;
+use super::clause;
 use super::collect_children;
+use super::cube;
 use super::reduce;
+use super::ApplyQuantOperation;
 use super::BDDOp;
 use super::BDDTerminal;
 use super::Operation;
@@ -520,6 +527,115 @@ where
     Ok(res)
 }
 
+/// Recursively apply the binary operator `OP` to `f` and `g` and quantify the
+/// result over `vars` using `Q`
+///
+/// `depth` is decremented for each recursive call. If it reaches 0, this
+/// function simply calls [`apply_rec_st::apply_quant()`].
+fn apply_quant<M, const Q: u8, const OP: u8>(
+    manager: &M,
+    depth: u32,
+    f: Borrowed<M::Edge>,
+    g: Borrowed<M::Edge>,
+    vars: Borrowed<M::Edge>,
+) -> AllocResult<M::Edge>
+where
+    M: Manager<Terminal = BDDTerminal> + HasApplyCache<M, BDDOp> + WorkerManager,
+    M::InnerNode: HasLevel,
+    M::Edge: Send + Sync,
+{
+    if depth == 0 {
+        return apply_rec_st::apply_quant::<M, Q, OP>(manager, f, g, vars);
+    }
+    let operator = const { BDDOp::from_apply_quant(Q, OP) };
+
+    stat!(call operator);
+    let rec = match super::terminal_apply_quant::<M, Q, OP>(manager, &f, &g, &vars) {
+        ApplyQuantOperation::Rec(rec) => rec,
+        ApplyQuantOperation::QuantNot(h) => {
+            let h = EdgeDropGuard::new(manager, apply_not(manager, depth, h)?);
+            return quant::<M, Q>(manager, depth, h.borrowed(), vars);
+        }
+        ApplyQuantOperation::Quant(h) => {
+            let h = EdgeDropGuard::new(manager, h);
+            return quant::<M, Q>(manager, depth, h.borrowed(), vars);
+        }
+        ApplyQuantOperation::Apply(f, g) => return apply_bin::<M, OP>(manager, depth, f, g),
+        ApplyQuantOperation::Done(h) => return Ok(h),
+    };
+
+    // Query apply cache
+    stat!(cache_query operator);
+    if let Some(res) = manager.apply_cache().get(
+        manager,
+        operator,
+        &[rec.f.borrowed(), rec.g.borrowed(), rec.vars.borrowed()],
+    ) {
+        stat!(cache_hit operator);
+        return Ok(res);
+    }
+
+    let ([(ft, gt), (fe, ge)], vt) = rec.cofactors();
+    let d = depth - 1;
+    let (t, e) = manager.join(
+        || {
+            let t = apply_quant::<M, Q, OP>(manager, d, ft, gt, vt.borrowed())?;
+            Ok(EdgeDropGuard::new(manager, t))
+        },
+        || {
+            let e = apply_quant::<M, Q, OP>(manager, d, fe, ge, vt.borrowed())?;
+            Ok(EdgeDropGuard::new(manager, e))
+        },
+    );
+    let (t, e) = (t?, e?);
+
+    let res = if !rec.quantify() {
+        reduce(manager, rec.level, t.into_edge(), e.into_edge(), operator)?
+    } else if super::quant_absorbing::<M, Q>(manager, &t) {
+        t.into_edge()
+    } else if super::quant_absorbing::<M, Q>(manager, &e) {
+        e.into_edge()
+    } else {
+        apply_bin::<M, Q>(manager, d, t.borrowed(), e.borrowed())?
+    };
+
+    manager.apply_cache().add(
+        manager,
+        operator,
+        &[rec.f.borrowed(), rec.g.borrowed(), rec.vars.borrowed()],
+        res.borrowed(),
+    );
+
+    Ok(res)
+}
+
+/// Call [`apply_quant()`] with the const parameter `OP` matching `op`
+fn apply_quant_dispatch<M, const Q: u8>(
+    manager: &M,
+    depth: u32,
+    op: BooleanOperator,
+    f: Borrowed<M::Edge>,
+    g: Borrowed<M::Edge>,
+    vars: Borrowed<M::Edge>,
+) -> AllocResult<M::Edge>
+where
+    M: Manager<Terminal = BDDTerminal> + HasApplyCache<M, BDDOp> + WorkerManager,
+    M::InnerNode: HasLevel,
+    M::Edge: Send + Sync,
+{
+    use BooleanOperator::*;
+    match op {
+        And => apply_quant::<M, Q, { BDDOp::And as u8 }>(manager, depth, f, g, vars),
+        Or => apply_quant::<M, Q, { BDDOp::Or as u8 }>(manager, depth, f, g, vars),
+        Xor => apply_quant::<M, Q, { BDDOp::Xor as u8 }>(manager, depth, f, g, vars),
+        Equiv => apply_quant::<M, Q, { BDDOp::Equiv as u8 }>(manager, depth, f, g, vars),
+        Nand => apply_quant::<M, Q, { BDDOp::Nand as u8 }>(manager, depth, f, g, vars),
+        Nor => apply_quant::<M, Q, { BDDOp::Nor as u8 }>(manager, depth, f, g, vars),
+        Imp => apply_quant::<M, Q, { BDDOp::Imp as u8 }>(manager, depth, f, g, vars),
+        ImpStrict => apply_quant::<M, Q, { BDDOp::ImpStrict as u8 }>(manager, depth, f, g, vars),
+    }
+}
+
 // --- Function Interface ------------------------------------------------------
 
 /// Boolean function backed by a binary decision diagram, multi-threaded version
@@ -601,6 +717,27 @@ where
         manager.get_terminal(BDDTerminal::True).unwrap()
     }
 
+    #[inline]
+    fn cube_edge<'id>(
+        manager: &Self::Manager<'id>,
+        literals: &[(LevelNo, bool)],
+    ) -> AllocResult<EdgeOfFunc<'id, Self>> {
+        match sort_literals(literals) {
+            Some(literals) => cube(manager, literals),
+            None => Ok(Self::f_edge(manager)),
+        }
+    }
+    #[inline]
+    fn clause_edge<'id>(
+        manager: &Self::Manager<'id>,
+        literals: &[(LevelNo, bool)],
+    ) -> AllocResult<EdgeOfFunc<'id, Self>> {
+        match sort_literals(literals) {
+            Some(literals) => clause(manager, literals),
+            None => Ok(Self::t_edge(manager)),
+        }
+    }
+
     #[inline]
     fn not_edge<'id>(
         manager: &Self::Manager<'id>,
@@ -730,6 +867,15 @@ where
         )
     }
 
+    #[inline]
+    fn support_edge<'id>(
+        manager: &Self::Manager<'id>,
+        edge: &EdgeOfFunc<'id, Self>,
+    ) -> AllocResult<EdgeOfFunc<'id, Self>> {
+        let levels = Self::support_levels_edge(manager, edge);
+        cube(manager, levels.into_iter().map(|level| (level, true)))
+    }
+
     #[inline]
     fn sat_count_edge<'id, N: SatCountNumber, S: std::hash::BuildHasher>(
         manager: &Self::Manager<'id>,
@@ -740,6 +886,16 @@ where
         apply_rec_st::BDDFunction::<F>::sat_count_edge(manager, edge, vars, cache)
     }
 
+    #[inline]
+    fn weighted_sat_count_edge<'id, W: WeightedCountNumber, S: std::hash::BuildHasher>(
+        manager: &Self::Manager<'id>,
+        edge: &EdgeOfFunc<'id, Self>,
+        weights: impl Fn(LevelNo) -> (W, W),
+        cache: &mut WeightedSatCountCache<W, S>,
+    ) -> W {
+        apply_rec_st::BDDFunction::<F>::weighted_sat_count_edge(manager, edge, weights, cache)
+    }
+
     #[inline]
     fn pick_cube_edge<'id, 'a, I>(
         manager: &'a Self::Manager<'id>,
@@ -785,6 +941,50 @@ where
         )
     }
 
+    #[inline]
+    fn constrain_edge<'id>(
+        manager: &Self::Manager<'id>,
+        root: &EdgeOfFunc<'id, Self>,
+        care: &EdgeOfFunc<'id, Self>,
+    ) -> AllocResult<EdgeOfFunc<'id, Self>> {
+        apply_rec_st::gen_cofactor::<_, { BDDOp::Constrain as u8 }>(
+            manager,
+            root.borrowed(),
+            care.borrowed(),
+        )
+    }
+
+    #[inline]
+    fn minimize_edge<'id>(
+        manager: &Self::Manager<'id>,
+        root: &EdgeOfFunc<'id, Self>,
+        care: &EdgeOfFunc<'id, Self>,
+    ) -> AllocResult<EdgeOfFunc<'id, Self>> {
+        apply_rec_st::gen_cofactor::<_, { BDDOp::Minimize as u8 }>(
+            manager,
+            root.borrowed(),
+            care.borrowed(),
+        )
+    }
+
+    #[inline]
+    fn li_compaction_edge<'id>(
+        manager: &Self::Manager<'id>,
+        root: &EdgeOfFunc<'id, Self>,
+        care: &EdgeOfFunc<'id, Self>,
+    ) -> AllocResult<EdgeOfFunc<'id, Self>> {
+        apply_rec_st::li_compaction(manager, root.borrowed(), care.borrowed())
+    }
+
+    #[inline]
+    fn isop_edge<'id>(
+        manager: &Self::Manager<'id>,
+        lower: &EdgeOfFunc<'id, Self>,
+        upper: &EdgeOfFunc<'id, Self>,
+    ) -> AllocResult<EdgeOfFunc<'id, Self>> {
+        apply_rec_st::isop(manager, lower.borrowed(), upper.borrowed())
+    }
+
     #[inline]
     fn forall_edge<'id>(
         manager: &Self::Manager<'id>,
@@ -826,6 +1026,60 @@ where
             vars.borrowed(),
         )
     }
+
+    #[inline]
+    fn apply_exist_edge<'id>(
+        manager: &Self::Manager<'id>,
+        op: BooleanOperator,
+        lhs: &EdgeOfFunc<'id, Self>,
+        rhs: &EdgeOfFunc<'id, Self>,
+        vars: &EdgeOfFunc<'id, Self>,
+    ) -> AllocResult<EdgeOfFunc<'id, Self>> {
+        apply_quant_dispatch::<_, { BDDOp::Or as u8 }>(
+            manager,
+            Self::init_depth(manager),
+            op,
+            lhs.borrowed(),
+            rhs.borrowed(),
+            vars.borrowed(),
+        )
+    }
+
+    #[inline]
+    fn apply_forall_edge<'id>(
+        manager: &Self::Manager<'id>,
+        op: BooleanOperator,
+        lhs: &EdgeOfFunc<'id, Self>,
+        rhs: &EdgeOfFunc<'id, Self>,
+        vars: &EdgeOfFunc<'id, Self>,
+    ) -> AllocResult<EdgeOfFunc<'id, Self>> {
+        apply_quant_dispatch::<_, { BDDOp::And as u8 }>(
+            manager,
+            Self::init_depth(manager),
+            op,
+            lhs.borrowed(),
+            rhs.borrowed(),
+            vars.borrowed(),
+        )
+    }
+
+    #[inline]
+    fn apply_unique_edge<'id>(
+        manager: &Self::Manager<'id>,
+        op: BooleanOperator,
+        lhs: &EdgeOfFunc<'id, Self>,
+        rhs: &EdgeOfFunc<'id, Self>,
+        vars: &EdgeOfFunc<'id, Self>,
+    ) -> AllocResult<EdgeOfFunc<'id, Self>> {
+        apply_quant_dispatch::<_, { BDDOp::Xor as u8 }>(
+            manager,
+            Self::init_depth(manager),
+            op,
+            lhs.borrowed(),
+            rhs.borrowed(),
+            vars.borrowed(),
+        )
+    }
 }
 
 impl<F: Function, T: Tag> DotStyle<T> for BDDFunctionMT<F> {}
@@ -1,15 +1,18 @@
 //! Recursive single-threaded apply algorithms
 
 use std::borrow::Borrow;
+use std::collections::HashMap;
 use std::hash::BuildHasher;
 
 use bitvec::vec::BitVec;
 
 use oxidd_core::function::BooleanFunction;
 use oxidd_core::function::BooleanFunctionQuant;
+use oxidd_core::function::BooleanOperator;
 use oxidd_core::function::EdgeOfFunc;
 use oxidd_core::function::Function;
 use oxidd_core::function::FunctionSubst;
+use oxidd_core::util::sort_literals;
 use oxidd_core::util::AllocResult;
 use oxidd_core::util::Borrowed;
 use oxidd_core::util::EdgeDropGuard;
@@ -17,6 +20,8 @@ use oxidd_core::util::EdgeVecDropGuard;
 use oxidd_core::util::OptBool;
 use oxidd_core::util::SatCountCache;
 use oxidd_core::util::SatCountNumber;
+use oxidd_core::util::WeightedCountNumber;
+use oxidd_core::util::WeightedSatCountCache;
 use oxidd_core::ApplyCache;
 use oxidd_core::Edge;
 use oxidd_core::HasApplyCache;
@@ -25,14 +30,18 @@ use oxidd_core::InnerNode;
 use oxidd_core::LevelNo;
 use oxidd_core::Manager;
 use oxidd_core::Node;
+use oxidd_core::NodeID;
 use oxidd_core::Tag;
 use oxidd_derive::Function;
 use oxidd_dump::dot::DotStyle;
 
 use crate::stat;
 
+use super::clause;
 use super::collect_children;
+use super::cube;
 use super::reduce;
+use super::ApplyQuantOperation;
 use super::BDDOp;
 use super::BDDTerminal;
 use super::Operation;
@@ -494,6 +503,345 @@ where
     }
 }
 
+/// Compute the generalized cofactor of `f` with respect to the care set `c`
+///
+/// `OP` is one of `BDDOp::Constrain` or `BDDOp::Minimize` as `u8`. In case of
+/// `BDDOp::Minimize`, variables of `c` above the top-most variable of `f` are
+/// existentially quantified before descending (Coudert and Madre's `restrict`
+/// operator).
+pub(super) fn gen_cofactor<M, const OP: u8>(
+    manager: &M,
+    f: Borrowed<M::Edge>,
+    c: Borrowed<M::Edge>,
+) -> AllocResult<M::Edge>
+where
+    M: Manager<Terminal = BDDTerminal> + HasApplyCache<M, BDDOp>,
+    M::InnerNode: HasLevel,
+{
+    let operator = match () {
+        _ if OP == BDDOp::Constrain as u8 => BDDOp::Constrain,
+        _ if OP == BDDOp::Minimize as u8 => BDDOp::Minimize,
+        _ => unreachable!("invalid operator"),
+    };
+
+    stat!(call operator);
+    // Terminal cases
+    let cnode = match manager.get_node(&c) {
+        Node::Inner(n) => n,
+        Node::Terminal(t) => {
+            return match *t.borrow() {
+                BDDTerminal::False => manager.get_terminal(BDDTerminal::False),
+                BDDTerminal::True => Ok(manager.clone_edge(&f)),
+            };
+        }
+    };
+    let fnode = match manager.get_node(&f) {
+        Node::Inner(n) => n,
+        Node::Terminal(_) => return Ok(manager.clone_edge(&f)),
+    };
+    if *f == *c {
+        return manager.get_terminal(BDDTerminal::True);
+    }
+    let flevel = fnode.level();
+    let clevel = cnode.level();
+
+    if operator == BDDOp::Minimize && clevel < flevel {
+        // `f` does not depend on the top-most variable of `c`
+        let (ct, ce) = collect_children(cnode);
+        let c = EdgeDropGuard::new(
+            manager,
+            apply_bin::<M, { BDDOp::Or as u8 }>(manager, ct, ce)?,
+        );
+        return gen_cofactor::<M, OP>(manager, f, c.borrowed());
+    }
+
+    // Query apply cache
+    stat!(cache_query operator);
+    if let Some(res) = manager
+        .apply_cache()
+        .get(manager, operator, &[f.borrowed(), c.borrowed()])
+    {
+        stat!(cache_hit operator);
+        return Ok(res);
+    }
+
+    let level = std::cmp::min(flevel, clevel);
+    let (ft, fe) = if flevel == level {
+        collect_children(fnode)
+    } else {
+        (f.borrowed(), f.borrowed())
+    };
+    let (ct, ce) = if clevel == level {
+        collect_children(cnode)
+    } else {
+        (c.borrowed(), c.borrowed())
+    };
+
+    let res = if manager.get_node(&ct).is_terminal(&BDDTerminal::False) {
+        gen_cofactor::<M, OP>(manager, fe, ce)?
+    } else if manager.get_node(&ce).is_terminal(&BDDTerminal::False) {
+        gen_cofactor::<M, OP>(manager, ft, ct)?
+    } else {
+        let t = EdgeDropGuard::new(manager, gen_cofactor::<M, OP>(manager, ft, ct)?);
+        let e = EdgeDropGuard::new(manager, gen_cofactor::<M, OP>(manager, fe, ce)?);
+        reduce(manager, level, t.into_edge(), e.into_edge(), operator)?
+    };
+
+    manager
+        .apply_cache()
+        .add(manager, operator, &[f, c], res.borrowed());
+
+    Ok(res)
+}
+
+/// Marks for leaf-identifying compaction: the terminals reachable from an edge
+/// under the care set (`LIC_TRUE`, `LIC_FALSE`, or both); `0` means that the
+/// edge is never taken under the care set.
+const LIC_TRUE: u8 = 0b01;
+const LIC_FALSE: u8 = 0b10;
+
+/// Mark phase of the leaf-identifying compaction of `f` with respect to `c`
+///
+/// For every inner node of `f` taken by a valuation satisfying `c`, this
+/// records in `marks` which terminals are reached via the node's outgoing
+/// edges under the care set. `visited` memoizes the result per pair `(f, c)`.
+fn li_compaction_mark<M>(
+    manager: &M,
+    f: Borrowed<M::Edge>,
+    c: Borrowed<M::Edge>,
+    visited: &mut HashMap<(NodeID, NodeID), u8>,
+    marks: &mut HashMap<NodeID, [u8; 2]>,
+) -> u8
+where
+    M: Manager<Terminal = BDDTerminal>,
+    M::InnerNode: HasLevel,
+{
+    let cnode = match manager.get_node(&c) {
+        Node::Inner(n) => Some(n),
+        Node::Terminal(t) if *t.borrow() == BDDTerminal::False => return 0,
+        Node::Terminal(_) => None,
+    };
+    let fnode = match manager.get_node(&f) {
+        Node::Inner(n) => n,
+        Node::Terminal(t) if *t.borrow() == BDDTerminal::True => return LIC_TRUE,
+        Node::Terminal(_) => return LIC_FALSE,
+    };
+
+    let key = (f.node_id(), c.node_id());
+    if let Some(&res) = visited.get(&key) {
+        return res;
+    }
+
+    let flevel = fnode.level();
+    let clevel = cnode.map_or(LevelNo::MAX, |n| n.level());
+    let level = std::cmp::min(flevel, clevel);
+    let (ft, fe) = if flevel == level {
+        collect_children(fnode)
+    } else {
+        (f.borrowed(), f.borrowed())
+    };
+    let (ct, ce) = match cnode {
+        Some(cnode) if clevel == level => collect_children(cnode),
+        _ => (c.borrowed(), c.borrowed()),
+    };
+
+    let t = li_compaction_mark(manager, ft, ct, visited, marks);
+    let e = li_compaction_mark(manager, fe, ce, visited, marks);
+    if flevel == level {
+        let m = marks.entry(f.node_id()).or_default();
+        m[0] |= t;
+        m[1] |= e;
+    }
+
+    visited.insert(key, t | e);
+    t | e
+}
+
+/// Build phase of the leaf-identifying compaction
+///
+/// Every node of `f` is replaced by at most one node in the result, hence the
+/// result is never larger than `f`.
+fn li_compaction_build<M>(
+    manager: &M,
+    f: Borrowed<M::Edge>,
+    marks: &HashMap<NodeID, [u8; 2]>,
+    results: &mut HashMap<NodeID, M::Edge>,
+) -> AllocResult<M::Edge>
+where
+    M: Manager<Terminal = BDDTerminal>,
+    M::InnerNode: HasLevel,
+{
+    let fnode = match manager.get_node(&f) {
+        Node::Inner(n) => n,
+        Node::Terminal(_) => return Ok(manager.clone_edge(&f)),
+    };
+    if let Some(res) = results.get(&f.node_id()) {
+        return Ok(manager.clone_edge(res));
+    }
+
+    let [mt, me] = marks[&f.node_id()];
+    let (ft, fe) = collect_children(fnode);
+    let mut build = |child: Borrowed<M::Edge>, mark: u8| match mark {
+        LIC_TRUE => manager.get_terminal(BDDTerminal::True).map(Some),
+        LIC_FALSE => manager.get_terminal(BDDTerminal::False).map(Some),
+        0 => Ok(None),
+        _ => li_compaction_build(manager, child, marks, results).map(Some),
+    };
+    let t = build(ft, mt)?;
+    let t = t.map(|t| EdgeDropGuard::new(manager, t));
+    let e = build(fe, me)?;
+    let res = match (t, e) {
+        (Some(t), Some(e)) => reduce(
+            manager,
+            fnode.level(),
+            t.into_edge(),
+            e,
+            BDDOp::LICompaction,
+        )?,
+        (Some(t), None) => t.into_edge(),
+        (None, Some(e)) => e,
+        (None, None) => manager.get_terminal(BDDTerminal::False)?,
+    };
+
+    results.insert(f.node_id(), manager.clone_edge(&res));
+    Ok(res)
+}
+
+/// Compute the safe leaf-identifying compaction of `f` with respect to the care
+/// set `c` (Hong et al., 1997)
+pub(super) fn li_compaction<M>(
+    manager: &M,
+    f: Borrowed<M::Edge>,
+    c: Borrowed<M::Edge>,
+) -> AllocResult<M::Edge>
+where
+    M: Manager<Terminal = BDDTerminal> + HasApplyCache<M, BDDOp>,
+    M::InnerNode: HasLevel,
+{
+    stat!(call BDDOp::LICompaction);
+    match manager.get_node(&c) {
+        Node::Terminal(t) if *t.borrow() == BDDTerminal::False => {
+            return manager.get_terminal(BDDTerminal::False)
+        }
+        Node::Terminal(_) => return Ok(manager.clone_edge(&f)),
+        Node::Inner(_) => {}
+    }
+    if manager.get_node(&f).is_any_terminal() {
+        return Ok(manager.clone_edge(&f));
+    }
+
+    // Query apply cache
+    stat!(cache_query BDDOp::LICompaction);
+    if let Some(res) =
+        manager
+            .apply_cache()
+            .get(manager, BDDOp::LICompaction, &[f.borrowed(), c.borrowed()])
+    {
+        stat!(cache_hit BDDOp::LICompaction);
+        return Ok(res);
+    }
+
+    let mut marks = HashMap::new();
+    li_compaction_mark(
+        manager,
+        f.borrowed(),
+        c.borrowed(),
+        &mut HashMap::new(),
+        &mut marks,
+    );
+    let mut results = HashMap::new();
+    let res = li_compaction_build(manager, f.borrowed(), &marks, &mut results);
+    for (_, e) in results.drain() {
+        manager.drop_edge(e);
+    }
+    let res = res?;
+
+    manager
+        .apply_cache()
+        .add(manager, BDDOp::LICompaction, &[f, c], res.borrowed());
+
+    Ok(res)
+}
+
+/// Compute the function of an irredundant sum-of-products cover of the
+/// interval `[lower, upper]` (Minato–Morreale algorithm)
+///
+/// `lower` must imply `upper`, otherwise the result is unspecified.
+pub(super) fn isop<M>(
+    manager: &M,
+    lower: Borrowed<M::Edge>,
+    upper: Borrowed<M::Edge>,
+) -> AllocResult<M::Edge>
+where
+    M: Manager<Terminal = BDDTerminal> + HasApplyCache<M, BDDOp>,
+    M::InnerNode: HasLevel,
+{
+    stat!(call BDDOp::Isop);
+    // Terminal cases
+    let llevel = match manager.get_node(&lower) {
+        Node::Inner(n) => n.level(),
+        Node::Terminal(t) if *t.borrow() == BDDTerminal::False => {
+            return manager.get_terminal(BDDTerminal::False)
+        }
+        Node::Terminal(_) => LevelNo::MAX,
+    };
+    let unode = match manager.get_node(&upper) {
+        Node::Inner(n) => n,
+        Node::Terminal(t) => return manager.get_terminal(*t.borrow()),
+    };
+
+    // Query apply cache
+    stat!(cache_query BDDOp::Isop);
+    if let Some(res) =
+        manager
+            .apply_cache()
+            .get(manager, BDDOp::Isop, &[lower.borrowed(), upper.borrowed()])
+    {
+        stat!(cache_hit BDDOp::Isop);
+        return Ok(res);
+    }
+
+    let ulevel = unode.level();
+    let level = std::cmp::min(llevel, ulevel);
+    let (lt, le) = if llevel == level {
+        collect_children(manager.get_node(&lower).unwrap_inner())
+    } else {
+        (lower.borrowed(), lower.borrowed())
+    };
+    let (ut, ue) = if ulevel == level {
+        collect_children(unode)
+    } else {
+        (upper.borrowed(), upper.borrowed())
+    };
+
+    const IMP_STRICT: u8 = BDDOp::ImpStrict as u8;
+    const OR: u8 = BDDOp::Or as u8;
+    let guard = |e| EdgeDropGuard::new(manager, e);
+
+    // Cubes containing the positive literal
+    let lt_pos = guard(apply_bin::<M, IMP_STRICT>(manager, ue.borrowed(), lt.borrowed())?);
+    let ft = guard(isop(manager, lt_pos.borrowed(), ut.borrowed())?);
+    // Cubes containing the negative literal
+    let le_neg = guard(apply_bin::<M, IMP_STRICT>(manager, ut.borrowed(), le.borrowed())?);
+    let fe = guard(isop(manager, le_neg.borrowed(), ue.borrowed())?);
+    // Cubes containing neither of the two literals
+    let lt_dc = guard(apply_bin::<M, IMP_STRICT>(manager, ft.borrowed(), lt)?);
+    let le_dc = guard(apply_bin::<M, IMP_STRICT>(manager, fe.borrowed(), le)?);
+    let ld = guard(apply_bin::<M, OR>(manager, lt_dc.borrowed(), le_dc.borrowed())?);
+    let ud = guard(apply_bin::<M, { BDDOp::And as u8 }>(manager, ut, ue)?);
+    let fd = guard(isop(manager, ld.borrowed(), ud.borrowed())?);
+
+    let t = guard(apply_bin::<M, OR>(manager, ft.borrowed(), fd.borrowed())?);
+    let e = apply_bin::<M, OR>(manager, fe.borrowed(), fd.borrowed())?;
+    let res = reduce(manager, level, t.into_edge(), e, BDDOp::Isop)?;
+
+    manager
+        .apply_cache()
+        .add(manager, BDDOp::Isop, &[lower, upper], res.borrowed());
+
+    Ok(res)
+}
+
 /// Compute the quantification `Q` over `vars`
 ///
 /// Note that `Q` is one of `BDDOp::And`, `BDDOp::Or`, or `BDDOp::Xor` as `u8`.
@@ -585,6 +933,102 @@ where
     Ok(res)
 }
 
+/// Recursively apply the binary operator `OP` to `f` and `g` and quantify the
+/// result over `vars` using `Q`
+///
+/// `Q` is one of `BDDOp::And`, `BDDOp::Or`, or `BDDOp::Xor` as `u8` for
+/// universal, existential, or unique quantification, respectively. In contrast
+/// to applying `OP` first and quantifying afterwards, this does not construct
+/// the (possibly large) intermediate result.
+pub(super) fn apply_quant<M, const Q: u8, const OP: u8>(
+    manager: &M,
+    f: Borrowed<M::Edge>,
+    g: Borrowed<M::Edge>,
+    vars: Borrowed<M::Edge>,
+) -> AllocResult<M::Edge>
+where
+    M: Manager<Terminal = BDDTerminal> + HasApplyCache<M, BDDOp>,
+    M::InnerNode: HasLevel,
+{
+    let operator = const { BDDOp::from_apply_quant(Q, OP) };
+
+    stat!(call operator);
+    let rec = match super::terminal_apply_quant::<M, Q, OP>(manager, &f, &g, &vars) {
+        ApplyQuantOperation::Rec(rec) => rec,
+        ApplyQuantOperation::QuantNot(h) => {
+            let h = EdgeDropGuard::new(manager, apply_not(manager, h)?);
+            return quant::<M, Q>(manager, h.borrowed(), vars);
+        }
+        ApplyQuantOperation::Quant(h) => {
+            let h = EdgeDropGuard::new(manager, h);
+            return quant::<M, Q>(manager, h.borrowed(), vars);
+        }
+        ApplyQuantOperation::Apply(f, g) => return apply_bin::<M, OP>(manager, f, g),
+        ApplyQuantOperation::Done(h) => return Ok(h),
+    };
+
+    // Query apply cache
+    stat!(cache_query operator);
+    if let Some(res) = manager.apply_cache().get(
+        manager,
+        operator,
+        &[rec.f.borrowed(), rec.g.borrowed(), rec.vars.borrowed()],
+    ) {
+        stat!(cache_hit operator);
+        return Ok(res);
+    }
+
+    let ([(ft, gt), (fe, ge)], vt) = rec.cofactors();
+    let t = EdgeDropGuard::new(
+        manager,
+        apply_quant::<M, Q, OP>(manager, ft, gt, vt.borrowed())?,
+    );
+    let res = if !rec.quantify() {
+        let e = apply_quant::<M, Q, OP>(manager, fe, ge, vt)?;
+        reduce(manager, rec.level, t.into_edge(), e, operator)?
+    } else if super::quant_absorbing::<M, Q>(manager, &t) {
+        // No need to compute the other cofactor
+        t.into_edge()
+    } else {
+        let e = EdgeDropGuard::new(manager, apply_quant::<M, Q, OP>(manager, fe, ge, vt)?);
+        apply_bin::<M, Q>(manager, t.borrowed(), e.borrowed())?
+    };
+
+    manager.apply_cache().add(
+        manager,
+        operator,
+        &[rec.f.borrowed(), rec.g.borrowed(), rec.vars.borrowed()],
+        res.borrowed(),
+    );
+
+    Ok(res)
+}
+
+/// Call [`apply_quant()`] with the const parameter `OP` matching `op`
+pub(super) fn apply_quant_dispatch<M, const Q: u8>(
+    manager: &M,
+    op: BooleanOperator,
+    f: Borrowed<M::Edge>,
+    g: Borrowed<M::Edge>,
+    vars: Borrowed<M::Edge>,
+) -> AllocResult<M::Edge>
+where
+    M: Manager<Terminal = BDDTerminal> + HasApplyCache<M, BDDOp>,
+    M::InnerNode: HasLevel,
+{
+    use BooleanOperator::*;
+    match op {
+        And => apply_quant::<M, Q, { BDDOp::And as u8 }>(manager, f, g, vars),
+        Or => apply_quant::<M, Q, { BDDOp::Or as u8 }>(manager, f, g, vars),
+        Xor => apply_quant::<M, Q, { BDDOp::Xor as u8 }>(manager, f, g, vars),
+        Equiv => apply_quant::<M, Q, { BDDOp::Equiv as u8 }>(manager, f, g, vars),
+        Nand => apply_quant::<M, Q, { BDDOp::Nand as u8 }>(manager, f, g, vars),
+        Nor => apply_quant::<M, Q, { BDDOp::Nor as u8 }>(manager, f, g, vars),
+        Imp => apply_quant::<M, Q, { BDDOp::Imp as u8 }>(manager, f, g, vars),
+        ImpStrict => apply_quant::<M, Q, { BDDOp::ImpStrict as u8 }>(manager, f, g, vars),
+    }
+}
+
 // --- Function Interface ------------------------------------------------------
 
 /// Boolean function backed by a binary decision diagram
@@ -649,6 +1093,27 @@ where
         manager.get_terminal(BDDTerminal::True).unwrap()
     }
 
+    #[inline]
+    fn cube_edge<'id>(
+        manager: &Self::Manager<'id>,
+        literals: &[(LevelNo, bool)],
+    ) -> AllocResult<EdgeOfFunc<'id, Self>> {
+        match sort_literals(literals) {
+            Some(literals) => cube(manager, literals),
+            None => Ok(Self::f_edge(manager)),
+        }
+    }
+    #[inline]
+    fn clause_edge<'id>(
+        manager: &Self::Manager<'id>,
+        literals: &[(LevelNo, bool)],
+    ) -> AllocResult<EdgeOfFunc<'id, Self>> {
+        match sort_literals(literals) {
+            Some(literals) => clause(manager, literals),
+            None => Ok(Self::t_edge(manager)),
+        }
+    }
+
     #[inline]
     fn not_edge<'id>(
         manager: &Self::Manager<'id>,
@@ -737,6 +1202,15 @@ where
         )
     }
 
+    #[inline]
+    fn support_edge<'id>(
+        manager: &Self::Manager<'id>,
+        edge: &EdgeOfFunc<'id, Self>,
+    ) -> AllocResult<EdgeOfFunc<'id, Self>> {
+        let levels = Self::support_levels_edge(manager, edge);
+        cube(manager, levels.into_iter().map(|level| (level, true)))
+    }
+
     fn sat_count_edge<'id, N: SatCountNumber, S: BuildHasher>(
         manager: &Self::Manager<'id>,
         edge: &EdgeOfFunc<'id, Self>,
@@ -778,6 +1252,81 @@ where
         inner(manager, edge.borrowed(), &terminal_val, cache)
     }
 
+    fn weighted_sat_count_edge<'id, W: WeightedCountNumber, S: BuildHasher>(
+        manager: &Self::Manager<'id>,
+        edge: &EdgeOfFunc<'id, Self>,
+        weights: impl Fn(LevelNo) -> (W, W),
+        cache: &mut WeightedSatCountCache<W, S>,
+    ) -> W {
+        fn inner<M, W, S>(
+            manager: &M,
+            e: Borrowed<M::Edge>,
+            weights: &[(W, W)],
+            skip: &[W],
+            cache: &mut WeightedSatCountCache<W, S>,
+        ) -> W
+        where
+            M: Manager<Terminal = BDDTerminal>,
+            M::InnerNode: HasLevel,
+            W: WeightedCountNumber,
+            S: BuildHasher,
+        {
+            let node = match manager.get_node(&e) {
+                Node::Inner(node) => node,
+                Node::Terminal(t) => {
+                    return W::from(match *t.borrow() {
+                        BDDTerminal::False => 0u32,
+                        BDDTerminal::True => 1u32,
+                    })
+                }
+            };
+            let node_id = e.node_id();
+            if let Some(w) = cache.map.get(&node_id) {
+                return w.clone();
+            }
+
+            let level = node.level() as usize;
+            let (e0, e1) = collect_children(node);
+            let mut res = W::from(0u32);
+            for (child, weight) in [(e0, &weights[level].0), (e1, &weights[level].1)] {
+                let mut w = inner(manager, child.borrowed(), weights, skip, cache);
+                w *= weight;
+                let child_level = match manager.get_node(&child) {
+                    Node::Inner(node) => node.level() as usize,
+                    Node::Terminal(_) => weights.len(),
+                };
+                for s in &skip[level + 1..child_level] {
+                    w *= s;
+                }
+                res += &w;
+            }
+            cache.map.insert(node_id, res.clone());
+            res
+        }
+
+        cache.clear_if_invalid(manager);
+        let weights: Vec<(W, W)> = (0..manager.num_levels()).map(weights).collect();
+        // Weight of a level not occurring on a path: the variable is a "don't care"
+        let skip: Vec<W> = weights
+            .iter()
+            .map(|(pos, neg)| {
+                let mut w = pos.clone();
+                w += neg;
+                w
+            })
+            .collect();
+
+        let mut res = inner(manager, edge.borrowed(), &weights, &skip, cache);
+        let level = match manager.get_node(edge) {
+            Node::Inner(node) => node.level() as usize,
+            Node::Terminal(_) => weights.len(),
+        };
+        for s in &skip[..level] {
+            res *= s;
+        }
+        res
+    }
+
     fn pick_cube_edge<'id, 'a, I>(
         manager: &'a Self::Manager<'id>,
         edge: &'a EdgeOfFunc<'id, Self>,
@@ -887,6 +1436,42 @@ where
         restrict(manager, root.borrowed(), vars.borrowed())
     }
 
+    #[inline]
+    fn constrain_edge<'id>(
+        manager: &Self::Manager<'id>,
+        root: &EdgeOfFunc<'id, Self>,
+        care: &EdgeOfFunc<'id, Self>,
+    ) -> AllocResult<EdgeOfFunc<'id, Self>> {
+        gen_cofactor::<_, { BDDOp::Constrain as u8 }>(manager, root.borrowed(), care.borrowed())
+    }
+
+    #[inline]
+    fn minimize_edge<'id>(
+        manager: &Self::Manager<'id>,
+        root: &EdgeOfFunc<'id, Self>,
+        care: &EdgeOfFunc<'id, Self>,
+    ) -> AllocResult<EdgeOfFunc<'id, Self>> {
+        gen_cofactor::<_, { BDDOp::Minimize as u8 }>(manager, root.borrowed(), care.borrowed())
+    }
+
+    #[inline]
+    fn li_compaction_edge<'id>(
+        manager: &Self::Manager<'id>,
+        root: &EdgeOfFunc<'id, Self>,
+        care: &EdgeOfFunc<'id, Self>,
+    ) -> AllocResult<EdgeOfFunc<'id, Self>> {
+        li_compaction(manager, root.borrowed(), care.borrowed())
+    }
+
+    #[inline]
+    fn isop_edge<'id>(
+        manager: &Self::Manager<'id>,
+        lower: &EdgeOfFunc<'id, Self>,
+        upper: &EdgeOfFunc<'id, Self>,
+    ) -> AllocResult<EdgeOfFunc<'id, Self>> {
+        isop(manager, lower.borrowed(), upper.borrowed())
+    }
+
     #[inline]
     fn forall_edge<'id>(
         manager: &Self::Manager<'id>,
@@ -913,6 +1498,57 @@ where
     ) -> AllocResult<EdgeOfFunc<'id, Self>> {
         quant::<_, { BDDOp::Xor as u8 }>(manager, root.borrowed(), vars.borrowed())
     }
+
+    #[inline]
+    fn apply_exist_edge<'id>(
+        manager: &Self::Manager<'id>,
+        op: BooleanOperator,
+        lhs: &EdgeOfFunc<'id, Self>,
+        rhs: &EdgeOfFunc<'id, Self>,
+        vars: &EdgeOfFunc<'id, Self>,
+    ) -> AllocResult<EdgeOfFunc<'id, Self>> {
+        apply_quant_dispatch::<_, { BDDOp::Or as u8 }>(
+            manager,
+            op,
+            lhs.borrowed(),
+            rhs.borrowed(),
+            vars.borrowed(),
+        )
+    }
+
+    #[inline]
+    fn apply_forall_edge<'id>(
+        manager: &Self::Manager<'id>,
+        op: BooleanOperator,
+        lhs: &EdgeOfFunc<'id, Self>,
+        rhs: &EdgeOfFunc<'id, Self>,
+        vars: &EdgeOfFunc<'id, Self>,
+    ) -> AllocResult<EdgeOfFunc<'id, Self>> {
+        apply_quant_dispatch::<_, { BDDOp::And as u8 }>(
+            manager,
+            op,
+            lhs.borrowed(),
+            rhs.borrowed(),
+            vars.borrowed(),
+        )
+    }
+
+    #[inline]
+    fn apply_unique_edge<'id>(
+        manager: &Self::Manager<'id>,
+        op: BooleanOperator,
+        lhs: &EdgeOfFunc<'id, Self>,
+        rhs: &EdgeOfFunc<'id, Self>,
+        vars: &EdgeOfFunc<'id, Self>,
+    ) -> AllocResult<EdgeOfFunc<'id, Self>> {
+        apply_quant_dispatch::<_, { BDDOp::Xor as u8 }>(
+            manager,
+            op,
+            lhs.borrowed(),
+            rhs.borrowed(),
+            vars.borrowed(),
+        )
+    }
 }
 
 impl<F: Function, T: Tag> DotStyle<T> for BDDFunction<F> {}
@@ -6,6 +6,7 @@ use std::hash::Hash;
 
 use oxidd_core::util::AllocResult;
 use oxidd_core::util::Borrowed;
+use oxidd_core::util::OutOfMemory;
 use oxidd_core::DiagramRules;
 use oxidd_core::Edge;
 use oxidd_core::HasApplyCache;
@@ -73,6 +74,32 @@ fn reduce<M>(
     e: M::Edge,
     _op: BDDOp,
 ) -> AllocResult<M::Edge>
+where
+    M: Manager<Terminal = BDDTerminal>,
+{
+    if manager.reorder_requested() {
+        // Abort the operation, it will be restarted after reordering
+        manager.drop_edge(t);
+        manager.drop_edge(e);
+        return Err(OutOfMemory);
+    }
+    reduce_no_abort(manager, level, t, e, _op)
+}
+
+/// Like [`reduce()`], but without aborting if a reordering is requested
+///
+/// The literal chains built by [`cube()`] and [`clause()`] have at most one
+/// node per literal, so aborting would not save any work. Moreover, the
+/// constructors using them only receive a manager and cannot be restarted.
+/// The pending reordering is carried out by the next operation that can.
+#[inline(always)]
+fn reduce_no_abort<M>(
+    manager: &M,
+    level: LevelNo,
+    t: M::Edge,
+    e: M::Edge,
+    _op: BDDOp,
+) -> AllocResult<M::Edge>
 where
     M: Manager<Terminal = BDDTerminal>,
 {
@@ -297,6 +324,110 @@ pub enum BDDOp {
     Exist,
     /// Unique quantification
     Unique,
+
+    ForallAnd,
+    ForallOr,
+    ForallNand,
+    ForallNor,
+    ForallXor,
+    ForallEquiv,
+    ForallImp,
+    ForallImpStrict,
+
+    ExistAnd,
+    ExistOr,
+    ExistNand,
+    ExistNor,
+    ExistXor,
+    ExistEquiv,
+    ExistImp,
+    ExistImpStrict,
+
+    UniqueAnd,
+    UniqueOr,
+    UniqueNand,
+    UniqueNor,
+    UniqueXor,
+    UniqueEquiv,
+    UniqueImp,
+    UniqueImpStrict,
+
+    /// Generalized cofactor (Coudert and Madre's `constrain`)
+    Constrain,
+    /// Coudert and Madre's `restrict` heuristic
+    Minimize,
+    /// Safe leaf-identifying compaction
+    LICompaction,
+    /// Irredundant sum-of-products (Minato–Morreale)
+    Isop,
+}
+
+impl BDDOp {
+    /// Cache tag for applying the binary operator `op` and quantifying the
+    /// result using `q`
+    ///
+    /// `q` is one of `BDDOp::And` (universal quantification), `BDDOp::Or`
+    /// (existential quantification), or `BDDOp::Xor` (unique quantification),
+    /// and `op` is one of the binary operators, both as `u8`. Callers evaluate
+    /// this function in a const context, so invalid combinations are rejected
+    /// at compile time.
+    const fn from_apply_quant(q: u8, op: u8) -> Self {
+        use BDDOp::*;
+        const AND: u8 = And as u8;
+        const OR: u8 = Or as u8;
+        const NAND: u8 = Nand as u8;
+        const NOR: u8 = Nor as u8;
+        const XOR: u8 = Xor as u8;
+        const EQUIV: u8 = Equiv as u8;
+        const IMP: u8 = Imp as u8;
+        const IMP_STRICT: u8 = ImpStrict as u8;
+
+        let tags = match q {
+            AND => [
+                ForallAnd,
+                ForallOr,
+                ForallNand,
+                ForallNor,
+                ForallXor,
+                ForallEquiv,
+                ForallImp,
+                ForallImpStrict,
+            ],
+            OR => [
+                ExistAnd,
+                ExistOr,
+                ExistNand,
+                ExistNor,
+                ExistXor,
+                ExistEquiv,
+                ExistImp,
+                ExistImpStrict,
+            ],
+            XOR => [
+                UniqueAnd,
+                UniqueOr,
+                UniqueNand,
+                UniqueNor,
+                UniqueXor,
+                UniqueEquiv,
+                UniqueImp,
+                UniqueImpStrict,
+            ],
+            _ => panic!("invalid quantifier"),
+        };
+        let i = match op {
+            AND => 0,
+            OR => 1,
+            NAND => 2,
+            NOR => 3,
+            XOR => 4,
+            EQUIV => 5,
+            IMP => 6,
+            IMP_STRICT => 7,
+            _ => panic!("invalid operator"),
+        };
+        tags[i]
+    }
 }
 
 enum Operation<'a, E: 'a + Edge> {
@@ -305,6 +436,141 @@ enum Operation<'a, E: 'a + Edge> {
     Done(E),
 }
 
+/// Terminal cases of applying a binary operator and quantifying the result,
+/// see [`terminal_apply_quant()`]
+enum ApplyQuantOperation<'a, E: 'a + Edge, N> {
+    /// Quantify the negation of the given function
+    QuantNot(Borrowed<'a, E>),
+    /// Quantify the given function
+    Quant(E),
+    /// There are no variables to quantify, just apply the operator
+    Apply(Borrowed<'a, E>, Borrowed<'a, E>),
+    Done(E),
+    Rec(ApplyQuantRec<'a, E, N>),
+}
+
+/// Operands of the recursive case of applying a binary operator and
+/// quantifying the result
+struct ApplyQuantRec<'a, E: 'a + Edge, N> {
+    f: Borrowed<'a, E>,
+    fnode: &'a N,
+    g: Borrowed<'a, E>,
+    gnode: &'a N,
+    vars: Borrowed<'a, E>,
+    vnode: &'a N,
+    /// Top-most level of `f` and `g`
+    level: LevelNo,
+}
+
+impl<'a, E: 'a + Edge, N: InnerNode<E> + HasLevel> ApplyQuantRec<'a, E, N> {
+    /// Whether the variable at [`Self::level`] is quantified
+    #[inline]
+    fn quantify(&self) -> bool {
+        self.vnode.level() == self.level
+    }
+
+    /// Cofactors of `f` and `g` for the variable at [`Self::level`] (then
+    /// case first) along with the remaining variables to quantify
+    #[inline]
+    #[allow(clippy::type_complexity)]
+    fn cofactors(&self) -> ([(Borrowed<'_, E>, Borrowed<'_, E>); 2], Borrowed<'_, E>) {
+        let (ft, fe) = if self.fnode.level() == self.level {
+            collect_children(self.fnode)
+        } else {
+            (self.f.borrowed(), self.f.borrowed())
+        };
+        let (gt, ge) = if self.gnode.level() == self.level {
+            collect_children(self.gnode)
+        } else {
+            (self.g.borrowed(), self.g.borrowed())
+        };
+        let vt = if self.quantify() {
+            self.vnode.child(0)
+        } else {
+            self.vars.borrowed()
+        };
+        ([(ft, gt), (fe, ge)], vt)
+    }
+}
+
+/// Terminal cases for applying the binary operator `OP` to `f` and `g` and
+/// quantifying the result over `vars` using `Q`
+///
+/// `Q` is one of `BDDOp::And`, `BDDOp::Or`, or `BDDOp::Xor` as `u8` for
+/// universal, existential, or unique quantification, respectively. In the
+/// recursive case, the variables above the top-most node of `f` and `g` are
+/// removed from `vars` (except for unique quantification).
+#[inline]
+fn terminal_apply_quant<'a, M, const Q: u8, const OP: u8>(
+    manager: &'a M,
+    f: &'a M::Edge,
+    g: &'a M::Edge,
+    vars: &'a M::Edge,
+) -> ApplyQuantOperation<'a, M::Edge, M::InnerNode>
+where
+    M: Manager<Terminal = BDDTerminal>,
+    M::InnerNode: HasLevel,
+{
+    use ApplyQuantOperation::*;
+
+    let (f, g) = match terminal_bin::<M, OP>(manager, f, g) {
+        Operation::Binary(_, f, g) => (f, g),
+        Operation::Not(h) => return QuantNot(h),
+        Operation::Done(h) => return Quant(h),
+    };
+
+    let fnode = manager.get_node(&f).unwrap_inner();
+    let gnode = manager.get_node(&g).unwrap_inner();
+    let level = std::cmp::min(fnode.level(), gnode.level());
+
+    let vars = if Q != BDDOp::Xor as u8 {
+        // Variables above the top-most node do not occur in the result of
+        // `OP`, so we can ignore them.
+        crate::set_pop(manager, vars.borrowed(), level)
+    } else {
+        // For unique quantification, such a variable yields `h ⊕ h ≡ ⊥`. We
+        // handle this below.
+        vars.borrowed()
+    };
+    let vnode = match manager.get_node(&vars) {
+        Node::Inner(n) => n,
+        Node::Terminal(_) => return Apply(f, g),
+    };
+    if Q == BDDOp::Xor as u8 && vnode.level() < level {
+        return Done(manager.get_terminal(BDDTerminal::False).unwrap());
+    }
+
+    Rec(ApplyQuantRec {
+        f,
+        fnode,
+        g,
+        gnode,
+        vars,
+        vnode,
+        level,
+    })
+}
+
+/// Returns `true` iff `h` is the absorbing element of the quantifier `Q` (see
+/// [`terminal_apply_quant()`]), i.e., `⊥` for universal and `⊤` for
+/// existential quantification
+///
+/// If this holds for one cofactor with respect to a quantified variable, the
+/// other cofactor does not matter.
+#[inline]
+fn quant_absorbing<M: Manager<Terminal = BDDTerminal>, const Q: u8>(
+    manager: &M,
+    h: &M::Edge,
+) -> bool {
+    if Q == BDDOp::And as u8 {
+        manager.get_node(h).is_terminal(&BDDTerminal::False)
+    } else if Q == BDDOp::Or as u8 {
+        manager.get_node(h).is_terminal(&BDDTerminal::True)
+    } else {
+        false
+    }
+}
+
 #[cfg(feature = "statistics")]
 static STAT_COUNTERS: [crate::StatCounters; <BDDOp as oxidd_core::Countable>::MAX_VALUE + 1] =
     [crate::StatCounters::INIT; <BDDOp as oxidd_core::Countable>::MAX_VALUE + 1];
@@ -343,6 +609,42 @@ where
     node.level()
 }
 
+/// Build the conjunction of `literals`
+///
+/// `literals` must be sorted by level and must not contain a variable twice.
+fn cube<M, I>(manager: &M, literals: I) -> AllocResult<M::Edge>
+where
+    M: Manager<Terminal = BDDTerminal>,
+    I: IntoIterator<Item = (LevelNo, bool)>,
+    I::IntoIter: DoubleEndedIterator,
+{
+    let mut cube = manager.get_terminal(BDDTerminal::True).unwrap();
+    for (level, positive) in literals.into_iter().rev() {
+        let f = manager.get_terminal(BDDTerminal::False).unwrap();
+        let (t, e) = if positive { (cube, f) } else { (f, cube) };
+        cube = reduce_no_abort(manager, level, t, e, BDDOp::And)?;
+    }
+    Ok(cube)
+}
+
+/// Build the disjunction of `literals`
+///
+/// `literals` must be sorted by level and must not contain a variable twice.
+fn clause<M, I>(manager: &M, literals: I) -> AllocResult<M::Edge>
+where
+    M: Manager<Terminal = BDDTerminal>,
+    I: IntoIterator<Item = (LevelNo, bool)>,
+    I::IntoIter: DoubleEndedIterator,
+{
+    let mut clause = manager.get_terminal(BDDTerminal::False).unwrap();
+    for (level, positive) in literals.into_iter().rev() {
+        let t = manager.get_terminal(BDDTerminal::True).unwrap();
+        let (t, e) = if positive { (t, clause) } else { (clause, t) };
+        clause = reduce_no_abort(manager, level, t, e, BDDOp::Or)?;
+    }
+    Ok(clause)
+}
+
 // --- Function Interface ------------------------------------------------------
 
 /// Workaround for https://github.com/rust-lang/rust/issues/49601
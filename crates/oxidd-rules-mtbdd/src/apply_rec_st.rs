@@ -18,6 +18,7 @@ use oxidd_derive::Function;
 use oxidd_dump::dot::DotStyle;
 
 use super::collect_children;
+use super::cube;
 use super::reduce;
 use super::stat;
 use super::MTBDDOp;
@@ -186,6 +187,14 @@ where
     ) -> AllocResult<EdgeOfFunc<'id, Self>> {
         apply_bin::<_, T, { MTBDDOp::Max as u8 }>(manager, lhs.borrowed(), rhs.borrowed())
     }
+
+    #[inline]
+    fn support_edge<'id>(
+        manager: &Self::Manager<'id>,
+        edge: &EdgeOfFunc<'id, Self>,
+    ) -> AllocResult<EdgeOfFunc<'id, Self>> {
+        cube(manager, &Self::support_levels_edge(manager, edge))
+    }
 }
 
 impl<F: Function, T: Tag> DotStyle<T> for MTBDDFunction<F> {}
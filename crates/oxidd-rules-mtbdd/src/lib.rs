@@ -110,6 +110,28 @@ fn collect_children<E: Edge, N: InnerNode<E>>(node: &N) -> (Borrowed<E>, Borrowe
     (t, e)
 }
 
+/// Build the product of the variables at `levels`
+///
+/// `levels` must be sorted in ascending order.
+fn cube<M>(manager: &M, levels: &[LevelNo]) -> AllocResult<M::Edge>
+where
+    M: Manager,
+    M::Terminal: NumberBase,
+{
+    let mut cube = manager.get_terminal(M::Terminal::one())?;
+    for &level in levels.iter().rev() {
+        let zero = match manager.get_terminal(M::Terminal::zero()) {
+            Ok(e) => e,
+            Err(e) => {
+                manager.drop_edge(cube);
+                return Err(e);
+            }
+        };
+        cube = reduce(manager, level, cube, zero, MTBDDOp::Mul)?;
+    }
+    Ok(cube)
+}
+
 enum Operation<'a, E: 'a + Edge> {
     Binary(MTBDDOp, Borrowed<'a, E>, Borrowed<'a, E>),
     Done(E),
@@ -20,6 +20,7 @@ use oxidd_derive::Function;
 use oxidd_dump::dot::DotStyle;
 
 use super::collect_children;
+use super::cube;
 use super::reduce;
 use super::stat;
 use super::terminal_bin;
@@ -392,6 +393,14 @@ where
             else_edge.borrowed(),
         )
     }
+
+    #[inline]
+    fn support_edge<'id>(
+        manager: &Self::Manager<'id>,
+        edge: &EdgeOfFunc<'id, Self>,
+    ) -> AllocResult<EdgeOfFunc<'id, Self>> {
+        cube(manager, &Self::support_levels_edge(manager, edge))
+    }
 }
 
 impl<F: Function, T: Tag> DotStyle<T> for TDDFunction<F> {
@@ -166,6 +166,41 @@ pub enum TDDOp {
     Ite,
 }
 
+/// Build the conjunction of the variables at `levels`
+///
+/// `levels` must be sorted in ascending order.
+fn cube<M>(manager: &M, levels: &[LevelNo]) -> AllocResult<M::Edge>
+where
+    M: Manager<Terminal = TDDTerminal>,
+{
+    // For a cube `c` with top variable `x`, we have `c ≡ x ∧ c'` and
+    // `U ∧ c ≡ ite(x, U ∧ c', U ∧ c', ⊥)`, so we build both chains at once.
+    let mut cube = manager.get_terminal(TDDTerminal::True).unwrap();
+    let mut u_cube = manager.get_terminal(TDDTerminal::Unknown).unwrap();
+    for &level in levels.iter().rev() {
+        let f = manager.get_terminal(TDDTerminal::False).unwrap();
+        let u = manager.clone_edge(&u_cube);
+        cube = match reduce(manager, level, cube, u, f, TDDOp::And) {
+            Ok(e) => e,
+            Err(e) => {
+                manager.drop_edge(u_cube);
+                return Err(e);
+            }
+        };
+        let f = manager.get_terminal(TDDTerminal::False).unwrap();
+        let u = manager.clone_edge(&u_cube);
+        u_cube = match reduce(manager, level, u_cube, u, f, TDDOp::And) {
+            Ok(e) => e,
+            Err(e) => {
+                manager.drop_edge(cube);
+                return Err(e);
+            }
+        };
+    }
+    manager.drop_edge(u_cube);
+    Ok(cube)
+}
+
 /// Collect the two children of a ternary node
 #[inline]
 #[must_use]
@@ -7,12 +7,15 @@ use oxidd_core::function::BooleanFunction;
 use oxidd_core::function::BooleanVecSet;
 use oxidd_core::function::EdgeOfFunc;
 use oxidd_core::function::Function;
+use oxidd_core::util::sort_literals;
 use oxidd_core::util::AllocResult;
 use oxidd_core::util::Borrowed;
 use oxidd_core::util::EdgeDropGuard;
 use oxidd_core::util::OptBool;
 use oxidd_core::util::SatCountCache;
 use oxidd_core::util::SatCountNumber;
+use oxidd_core::util::WeightedCountNumber;
+use oxidd_core::util::WeightedSatCountCache;
 use oxidd_core::ApplyCache;
 use oxidd_core::Edge;
 use oxidd_core::HasApplyCache;
@@ -26,7 +29,10 @@ use oxidd_derive::Function;
 use oxidd_dump::dot::DotStyle;
 
 use super::apply_rec_st;
+use super::clause;
 use super::collect_children;
+use super::cube;
+use super::family;
 use super::reduce;
 use super::reduce_borrowed;
 use super::singleton_level;
@@ -66,23 +72,38 @@ where
     };
     stat!(call op);
 
-    let Node::Inner(node) = manager.get_node(&f) else {
-        return Ok(manager.get_terminal(ZBDDTerminal::Empty).unwrap());
+    let node = match manager.get_node(&f) {
+        Node::Inner(node) if node.level() <= var_level => node,
+        // `var` is not contained in any set of `f`
+        _ => {
+            return match op {
+                ZBDDOp::Change => reduce_borrowed(
+                    manager,
+                    var_level,
+                    f,
+                    manager.get_terminal(ZBDDTerminal::Empty).unwrap(),
+                    op,
+                ),
+                ZBDDOp::Subset0 => Ok(manager.clone_edge(&f)),
+                _ => Ok(manager.get_terminal(ZBDDTerminal::Empty).unwrap()),
+            };
+        }
     };
     let level = node.level();
-    match level.cmp(&var_level) {
-        Ordering::Less => {}
-        Ordering::Equal => {
-            if op == ZBDDOp::Change {
-                // The swap of `hi` and `lo` below is intentional
-                let (lo, hi) = collect_children(node);
-                return reduce_borrowed(manager, level, hi, manager.clone_edge(&lo), op);
-            }
-            return Ok(manager.clone_edge(&node.child(VAL as usize)));
-        }
-        Ordering::Greater => {
-            return Ok(manager.get_terminal(ZBDDTerminal::Empty).unwrap());
-        }
+    if level == var_level {
+        let (hi, lo) = collect_children(node);
+        return match op {
+            // The swap of `hi` and `lo` is intentional
+            ZBDDOp::Change => reduce_borrowed(manager, level, lo, manager.clone_edge(&hi), op),
+            ZBDDOp::Subset0 => Ok(manager.clone_edge(&lo)),
+            _ => reduce_borrowed(
+                manager,
+                level,
+                hi,
+                manager.get_terminal(ZBDDTerminal::Empty).unwrap(),
+                op,
+            ),
+        };
     }
 
     // Query apply cache
@@ -653,6 +674,14 @@ where
         manager.get_terminal(ZBDDTerminal::Base).unwrap()
     }
 
+    #[inline]
+    fn family_edge<'id>(
+        manager: &Self::Manager<'id>,
+        sets: &[&[LevelNo]],
+    ) -> AllocResult<EdgeOfFunc<'id, Self>> {
+        family(manager, sets)
+    }
+
     #[inline]
     fn subset0_edge<'id>(
         manager: &Self::Manager<'id>,
@@ -726,6 +755,8 @@ where
     for<'id> <F::Manager<'id> as Manager>::InnerNode: HasLevel,
     for<'id> <F::Manager<'id> as Manager>::Edge: Send + Sync,
 {
+    const SKIPPED_VAR: OptBool = OptBool::False;
+
     fn new_var<'id>(manager: &mut Self::Manager<'id>) -> AllocResult<Self> {
         let hi = manager.get_terminal(ZBDDTerminal::Base).unwrap();
         let lo = manager.get_terminal(ZBDDTerminal::Empty).unwrap();
@@ -761,6 +792,28 @@ where
         manager.clone_edge(manager.zbdd_cache().tautology(0))
     }
 
+    #[inline]
+    fn cube_edge<'id>(
+        manager: &Self::Manager<'id>,
+        literals: &[(LevelNo, bool)],
+    ) -> AllocResult<EdgeOfFunc<'id, Self>> {
+        match sort_literals(literals) {
+            Some(literals) => cube(manager, literals),
+            None => Ok(Self::f_edge(manager)),
+        }
+    }
+
+    #[inline]
+    fn clause_edge<'id>(
+        manager: &Self::Manager<'id>,
+        literals: &[(LevelNo, bool)],
+    ) -> AllocResult<EdgeOfFunc<'id, Self>> {
+        match sort_literals(literals) {
+            Some(literals) => clause(manager, literals),
+            None => Ok(Self::t_edge(manager)),
+        }
+    }
+
     #[inline]
     fn not_edge<'id>(
         manager: &Self::Manager<'id>,
@@ -854,6 +907,15 @@ where
         apply_ite(manager, depth, f.borrowed(), g.borrowed(), h.borrowed())
     }
 
+    #[inline]
+    fn support_edge<'id>(
+        manager: &Self::Manager<'id>,
+        edge: &EdgeOfFunc<'id, Self>,
+    ) -> AllocResult<EdgeOfFunc<'id, Self>> {
+        let levels = Self::support_levels_edge(manager, edge);
+        cube(manager, levels.into_iter().map(|level| (level, true)))
+    }
+
     #[inline]
     fn sat_count_edge<'id, N: SatCountNumber, S: std::hash::BuildHasher>(
         manager: &Self::Manager<'id>,
@@ -864,6 +926,16 @@ where
         apply_rec_st::ZBDDFunction::<F>::sat_count_edge(manager, edge, vars, cache)
     }
 
+    #[inline]
+    fn weighted_sat_count_edge<'id, W: WeightedCountNumber, S: std::hash::BuildHasher>(
+        manager: &Self::Manager<'id>,
+        edge: &EdgeOfFunc<'id, Self>,
+        weights: impl Fn(LevelNo) -> (W, W),
+        cache: &mut WeightedSatCountCache<W, S>,
+    ) -> W {
+        apply_rec_st::ZBDDFunction::<F>::weighted_sat_count_edge(manager, edge, weights, cache)
+    }
+
     #[inline]
     fn pick_cube_edge<'id, 'a, I>(
         manager: &'a Self::Manager<'id>,
@@ -13,12 +13,15 @@ use oxidd_core::function::BooleanFunction;
 use oxidd_core::function::BooleanVecSet;
 use oxidd_core::function::EdgeOfFunc;
 use oxidd_core::function::Function;
+use oxidd_core::util::sort_literals;
 use oxidd_core::util::AllocResult;
 use oxidd_core::util::Borrowed;
 use oxidd_core::util::EdgeDropGuard;
 use oxidd_core::util::OptBool;
 use oxidd_core::util::SatCountCache;
 use oxidd_core::util::SatCountNumber;
+use oxidd_core::util::WeightedCountNumber;
+use oxidd_core::util::WeightedSatCountCache;
 use oxidd_core::ApplyCache;
 use oxidd_core::Edge;
 use oxidd_core::HasApplyCache;
@@ -31,7 +34,10 @@ use oxidd_core::Tag;
 use oxidd_derive::Function;
 use oxidd_dump::dot::DotStyle;
 
+use super::clause;
 use super::collect_children;
+use super::cube;
+use super::family;
 use super::reduce;
 use super::reduce_borrowed;
 use super::singleton_level;
@@ -66,23 +72,38 @@ where
     };
     stat!(call op);
 
-    let Node::Inner(node) = manager.get_node(&f) else {
-        return Ok(manager.get_terminal(ZBDDTerminal::Empty).unwrap());
+    let node = match manager.get_node(&f) {
+        Node::Inner(node) if node.level() <= var_level => node,
+        // `var` is not contained in any set of `f`
+        _ => {
+            return match op {
+                ZBDDOp::Change => reduce_borrowed(
+                    manager,
+                    var_level,
+                    f,
+                    manager.get_terminal(ZBDDTerminal::Empty).unwrap(),
+                    op,
+                ),
+                ZBDDOp::Subset0 => Ok(manager.clone_edge(&f)),
+                _ => Ok(manager.get_terminal(ZBDDTerminal::Empty).unwrap()),
+            };
+        }
     };
     let level = node.level();
-    match level.cmp(&var_level) {
-        Ordering::Less => {}
-        Ordering::Equal => {
-            if op == ZBDDOp::Change {
-                // The swap of `hi` and `lo` below is intentional
-                let (lo, hi) = collect_children(node);
-                return reduce_borrowed(manager, level, hi, manager.clone_edge(&lo), op);
-            }
-            return Ok(manager.clone_edge(&node.child(VAL as usize)));
-        }
-        Ordering::Greater => {
-            return Ok(manager.get_terminal(ZBDDTerminal::Empty).unwrap());
-        }
+    if level == var_level {
+        let (hi, lo) = collect_children(node);
+        return match op {
+            // The swap of `hi` and `lo` is intentional
+            ZBDDOp::Change => reduce_borrowed(manager, level, lo, manager.clone_edge(&hi), op),
+            ZBDDOp::Subset0 => Ok(manager.clone_edge(&lo)),
+            _ => reduce_borrowed(
+                manager,
+                level,
+                hi,
+                manager.get_terminal(ZBDDTerminal::Empty).unwrap(),
+                op,
+            ),
+        };
     }
 
     // Query apply cache
@@ -542,6 +563,14 @@ where
         manager.get_terminal(ZBDDTerminal::Base).unwrap()
     }
 
+    #[inline]
+    fn family_edge<'id>(
+        manager: &Self::Manager<'id>,
+        sets: &[&[LevelNo]],
+    ) -> AllocResult<EdgeOfFunc<'id, Self>> {
+        family(manager, sets)
+    }
+
     #[inline]
     fn subset0_edge<'id>(
         manager: &Self::Manager<'id>,
@@ -607,6 +636,8 @@ where
         + HasZBDDCache<<F::Manager<'id> as Manager>::Edge>,
     for<'id> <F::Manager<'id> as Manager>::InnerNode: HasLevel,
 {
+    const SKIPPED_VAR: OptBool = OptBool::False;
+
     fn new_var<'id>(manager: &mut Self::Manager<'id>) -> AllocResult<Self> {
         let hi = manager.get_terminal(ZBDDTerminal::Base).unwrap();
         let lo = manager.get_terminal(ZBDDTerminal::Empty).unwrap();
@@ -642,6 +673,28 @@ where
         manager.clone_edge(manager.zbdd_cache().tautology(0))
     }
 
+    #[inline]
+    fn cube_edge<'id>(
+        manager: &Self::Manager<'id>,
+        literals: &[(LevelNo, bool)],
+    ) -> AllocResult<EdgeOfFunc<'id, Self>> {
+        match sort_literals(literals) {
+            Some(literals) => cube(manager, literals),
+            None => Ok(Self::f_edge(manager)),
+        }
+    }
+
+    #[inline]
+    fn clause_edge<'id>(
+        manager: &Self::Manager<'id>,
+        literals: &[(LevelNo, bool)],
+    ) -> AllocResult<EdgeOfFunc<'id, Self>> {
+        match sort_literals(literals) {
+            Some(literals) => clause(manager, literals),
+            None => Ok(Self::t_edge(manager)),
+        }
+    }
+
     #[inline]
     fn not_edge<'id>(
         manager: &Self::Manager<'id>,
@@ -729,6 +782,15 @@ where
         apply_ite(manager, f.borrowed(), g.borrowed(), h.borrowed())
     }
 
+    #[inline]
+    fn support_edge<'id>(
+        manager: &Self::Manager<'id>,
+        edge: &EdgeOfFunc<'id, Self>,
+    ) -> AllocResult<EdgeOfFunc<'id, Self>> {
+        let levels = Self::support_levels_edge(manager, edge);
+        cube(manager, levels.into_iter().map(|level| (level, true)))
+    }
+
     #[inline]
     fn sat_count_edge<'id, N: SatCountNumber, S: BuildHasher>(
         manager: &Self::Manager<'id>,
@@ -769,6 +831,74 @@ where
         n
     }
 
+    fn weighted_sat_count_edge<'id, W: WeightedCountNumber, S: BuildHasher>(
+        manager: &Self::Manager<'id>,
+        edge: &EdgeOfFunc<'id, Self>,
+        weights: impl Fn(LevelNo) -> (W, W),
+        cache: &mut WeightedSatCountCache<W, S>,
+    ) -> W {
+        fn inner<M, W, S>(
+            manager: &M,
+            e: Borrowed<M::Edge>,
+            weights: &[(W, W)],
+            skip: &[W],
+            cache: &mut WeightedSatCountCache<W, S>,
+        ) -> W
+        where
+            M: Manager<Terminal = ZBDDTerminal>,
+            M::InnerNode: HasLevel,
+            W: WeightedCountNumber,
+            S: BuildHasher,
+        {
+            let node = match manager.get_node(&e) {
+                Node::Inner(node) => node,
+                Node::Terminal(t) => {
+                    return W::from(match *t.borrow() {
+                        ZBDDTerminal::Empty => 0u32,
+                        ZBDDTerminal::Base => 1u32,
+                    })
+                }
+            };
+            let node_id = e.node_id();
+            if let Some(w) = cache.map.get(&node_id) {
+                return w.clone();
+            }
+
+            let level = node.level() as usize;
+            let (e0, e1) = collect_children(node);
+            let mut res = W::from(0u32);
+            for (child, weight) in [(e0, &weights[level].0), (e1, &weights[level].1)] {
+                let mut w = inner(manager, child.borrowed(), weights, skip, cache);
+                w *= weight;
+                let child_level = match manager.get_node(&child) {
+                    Node::Inner(node) => node.level() as usize,
+                    Node::Terminal(_) => weights.len(),
+                };
+                for s in &skip[level + 1..child_level] {
+                    w *= s;
+                }
+                res += &w;
+            }
+            cache.map.insert(node_id, res.clone());
+            res
+        }
+
+        cache.clear_if_invalid(manager);
+        let weights: Vec<(W, W)> = (0..manager.num_levels()).map(weights).collect();
+        // Weight of a level not occurring on a path: the variable is false
+        let skip: Vec<W> = weights.iter().map(|(_, neg)| neg.clone()).collect();
+
+        let mut res = inner(manager, edge.borrowed(), &weights, &skip, cache);
+        let level = match manager.get_node(edge) {
+            Node::Inner(node) => node.level() as usize,
+            Node::Terminal(_) => weights.len(),
+        };
+        for s in &skip[..level] {
+            res *= s;
+        }
+        res
+    }
+
     fn pick_cube_edge<'id, 'a, I>(
         manager: &'a Self::Manager<'id>,
         edge: &'a EdgeOfFunc<'id, Self>,
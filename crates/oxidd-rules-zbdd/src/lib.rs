@@ -13,6 +13,8 @@ use std::hash::Hash;
 use oxidd_core::util::AllocResult;
 use oxidd_core::util::Borrowed;
 use oxidd_core::util::DropWith;
+use oxidd_core::util::EdgeDropGuard;
+use oxidd_core::util::OutOfMemory;
 use oxidd_core::DiagramRules;
 use oxidd_core::Edge;
 use oxidd_core::HasApplyCache;
@@ -63,6 +65,22 @@ impl<E: Edge, N: InnerNode<E>> DiagramRules<E, N, ZBDDTerminal> for ZBDDRules {
     fn cofactors(_tag: E::Tag, node: &N) -> Self::Cofactors<'_> {
         node.children()
     }
+
+    #[inline]
+    fn skipped_cofactor<M: Manager<Edge = E, InnerNode = N, Terminal = ZBDDTerminal>>(
+        manager: &M,
+        edge: &E,
+        n: usize,
+    ) -> AllocResult<Option<E>> {
+        let _ = edge;
+        // A skipped level means that the variable is false, so there are no
+        // sets containing the variable.
+        if n == 0 {
+            Ok(Some(manager.get_terminal(ZBDDTerminal::Empty)?))
+        } else {
+            Ok(None)
+        }
+    }
 }
 
 #[inline(always)]
@@ -73,6 +91,33 @@ fn reduce<M>(
     lo: M::Edge,
     op: ZBDDOp,
 ) -> AllocResult<M::Edge>
+where
+    M: Manager<Terminal = ZBDDTerminal>,
+{
+    if manager.reorder_requested() {
+        // Abort the operation, it will be restarted after reordering
+        manager.drop_edge(hi);
+        manager.drop_edge(lo);
+        return Err(OutOfMemory);
+    }
+    reduce_no_abort(manager, level, hi, lo, op)
+}
+
+/// Like [`reduce()`], but without aborting if a reordering is requested
+///
+/// Used by [`make_node()`], [`cube()`], [`clause()`], and [`family()`]. These
+/// build the result in a single pass, so there is no work to save by aborting.
+/// Furthermore, their callers only receive a manager and could not restart
+/// them. Any pending reordering happens at the next operation that can be
+/// restarted.
+#[inline(always)]
+fn reduce_no_abort<M>(
+    manager: &M,
+    level: LevelNo,
+    hi: M::Edge,
+    lo: M::Edge,
+    op: ZBDDOp,
+) -> AllocResult<M::Edge>
 where
     M: Manager<Terminal = ZBDDTerminal>,
 {
@@ -100,6 +145,11 @@ where
         stat!(reduced op);
         return Ok(lo);
     }
+    if manager.reorder_requested() {
+        // Abort the operation, it will be restarted after reordering
+        manager.drop_edge(lo);
+        return Err(OutOfMemory);
+    }
     ReducedOrNew::New(
         M::InnerNode::new(level, [manager.clone_edge(&hi), lo]),
         Default::default(),
@@ -327,7 +377,7 @@ where
     M::InnerNode: HasLevel,
 {
     let level = singleton_level(manager, var);
-    reduce(manager, level, hi, lo, ZBDDOp::MkNode)
+    reduce_no_abort(manager, level, hi, lo, ZBDDOp::MkNode)
 }
 
 /// Get the Boolean function v for the singleton set {v} (given by `singleton`)
@@ -370,6 +420,121 @@ where
     }
 }
 
+/// Build the conjunction of `literals` as a Boolean function
+///
+/// `literals` must be sorted by level and must not contain a variable twice.
+fn cube<M, I>(manager: &M, literals: I) -> AllocResult<M::Edge>
+where
+    M: Manager<Terminal = ZBDDTerminal> + HasZBDDCache<M::Edge>,
+    M::InnerNode: HasLevel,
+    I: IntoIterator<Item = (LevelNo, bool)>,
+    I::IntoIter: DoubleEndedIterator,
+{
+    let mut literals = literals.into_iter().rev().peekable();
+    let Some(&(last, _)) = literals.peek() else {
+        return Ok(manager.clone_edge(manager.zbdd_cache().tautology(0)));
+    };
+    let empty = EdgeDropGuard::new(manager, manager.get_terminal(ZBDDTerminal::Empty)?);
+    let mut cube = manager.clone_edge(manager.zbdd_cache().tautology(last + 1));
+    for level in (0..=last).rev() {
+        let (hi, lo) = match literals.next_if(|&(l, _)| l == level) {
+            Some((_, true)) => (cube, manager.clone_edge(&empty)),
+            Some((_, false)) => (manager.clone_edge(&empty), cube),
+            // Variables not in `literals` are "don't cares"
+            None => (manager.clone_edge(&cube), cube),
+        };
+        cube = reduce_no_abort(manager, level, hi, lo, ZBDDOp::Intsec)?;
+    }
+    Ok(cube)
+}
+
+/// Build the disjunction of `literals` as a Boolean function
+///
+/// `literals` must be sorted by level and must not contain a variable twice.
+fn clause<M, I>(manager: &M, literals: I) -> AllocResult<M::Edge>
+where
+    M: Manager<Terminal = ZBDDTerminal> + HasZBDDCache<M::Edge>,
+    M::InnerNode: HasLevel,
+    I: IntoIterator<Item = (LevelNo, bool)>,
+    I::IntoIter: DoubleEndedIterator,
+{
+    let mut clause = manager.get_terminal(ZBDDTerminal::Empty)?;
+    let mut literals = literals.into_iter().rev().peekable();
+    let Some(&(last, _)) = literals.peek() else {
+        return Ok(clause);
+    };
+    for level in (0..=last).rev() {
+        let (hi, lo) = match literals.next_if(|&(l, _)| l == level) {
+            Some((_, positive)) => {
+                let t = manager.clone_edge(manager.zbdd_cache().tautology(level + 1));
+                if positive {
+                    (t, clause)
+                } else {
+                    (clause, t)
+                }
+            }
+            None => (manager.clone_edge(&clause), clause),
+        };
+        clause = reduce_no_abort(manager, level, hi, lo, ZBDDOp::Union)?;
+    }
+    Ok(clause)
+}
+
+/// Build the family of `sets`, where each set is given by the level numbers of
+/// its elements
+fn family<M>(manager: &M, sets: &[&[LevelNo]]) -> AllocResult<M::Edge>
+where
+    M: Manager<Terminal = ZBDDTerminal>,
+{
+    /// `sets` must be sorted lexicographically and must not contain
+    /// duplicates. All sets share a common prefix of length `depth` and are
+    /// strictly longer than this prefix. `with_prefix` indicates whether the
+    /// prefix itself belongs to the family.
+    fn inner<M>(
+        manager: &M,
+        with_prefix: bool,
+        sets: &[Vec<LevelNo>],
+        depth: usize,
+    ) -> AllocResult<M::Edge>
+    where
+        M: Manager<Terminal = ZBDDTerminal>,
+    {
+        let Some(first) = sets.first() else {
+            return manager.get_terminal(if with_prefix {
+                ZBDDTerminal::Base
+            } else {
+                ZBDDTerminal::Empty
+            });
+        };
+        let level = first[depth];
+        let (hi_sets, lo_sets) = sets.split_at(sets.partition_point(|s| s[depth] == level));
+        let (hi_with_prefix, hi_sets) = match hi_sets.split_first() {
+            Some((s, rest)) if s.len() == depth + 1 => (true, rest),
+            _ => (false, hi_sets),
+        };
+
+        let hi = EdgeDropGuard::new(manager, inner(manager, hi_with_prefix, hi_sets, depth + 1)?);
+        let lo = inner(manager, with_prefix, lo_sets, depth)?;
+        reduce_no_abort(manager, level, hi.into_edge(), lo, ZBDDOp::Union)
+    }
+
+    let mut sets: Vec<Vec<LevelNo>> = sets
+        .iter()
+        .map(|&set| {
+            let mut set = set.to_vec();
+            set.sort_unstable();
+            set.dedup();
+            set
+        })
+        .collect();
+    sets.sort_unstable();
+    sets.dedup();
+    match sets.split_first() {
+        Some((s, rest)) if s.is_empty() => inner(manager, true, rest, 0),
+        _ => inner(manager, false, &sets, 0),
+    }
+}
+
 // --- Function Interface ------------------------------------------------------
 
 /// Workaround for https://github.com/rust-lang/rust/issues/49601
@@ -0,0 +1,313 @@
+//! Fixed-width symbolic bit-vectors
+//!
+//! A [`BitVec`] is a vector of Boolean functions representing a word whose
+//! bits depend on the variables of a decision diagram. The arithmetic
+//! operations work modulo `2^width`, as in hardware datapaths.
+
+use oxidd_core::function::BooleanFunction;
+use oxidd_core::ManagerRef;
+
+use crate::util::AllocResult;
+
+/// Fixed-width symbolic word
+///
+/// The bits are stored in little-endian order, i.e., the bit at index 0 is the
+/// least significant one. All binary operations require both operands to have
+/// the same width and to belong to the same manager. They panic otherwise.
+/// Operations on words of width 0 panic as well, unless stated differently.
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct BitVec<B> {
+    bits: Vec<B>,
+}
+
+impl<B: BooleanFunction> BitVec<B> {
+    /// Create a bit-vector from its bits (least significant bit first)
+    pub fn from_bits(bits: Vec<B>) -> Self {
+        Self { bits }
+    }
+
+    /// Get the bits (least significant bit first)
+    pub fn bits(&self) -> &[B] {
+        &self.bits
+    }
+
+    /// Convert `self` into its bits (least significant bit first)
+    pub fn into_bits(self) -> Vec<B> {
+        self.bits
+    }
+
+    /// Get the width
+    pub fn width(&self) -> usize {
+        self.bits.len()
+    }
+
+    /// Get the constant word `value` truncated to `width` bits
+    ///
+    /// Bits beyond the 64 bits of `value` are zero.
+    ///
+    /// Locking behavior: acquires the manager's lock for shared access.
+    pub fn constant(manager: &B::ManagerRef, width: usize, value: u64) -> Self {
+        manager.with_manager_shared(|manager| {
+            let bits = (0..width)
+                .map(|i| match value.checked_shr(i as u32) {
+                    Some(v) if v & 1 != 0 => B::t(manager),
+                    _ => B::f(manager),
+                })
+                .collect();
+            Self { bits }
+        })
+    }
+
+    /// Create a word of `width` fresh variables
+    ///
+    /// The most significant bit gets the top-most level.
+    ///
+    /// Locking behavior: acquires the manager's lock for exclusive access.
+    pub fn new_vars(manager: &B::ManagerRef, width: usize) -> AllocResult<Self> {
+        let mut words = Self::new_interleaved(manager, 1, width)?;
+        Ok(words.pop().unwrap())
+    }
+
+    /// Create `count` words of `width` fresh variables each, with the
+    /// variables of the words interleaved
+    ///
+    /// The levels are allocated from the most significant bit downwards, and
+    /// for each bit position, in the order of the words. This order keeps the
+    /// decision diagrams of adders and comparators linear in the width.
+    ///
+    /// Locking behavior: acquires the manager's lock for exclusive access.
+    pub fn new_interleaved(
+        manager: &B::ManagerRef,
+        count: usize,
+        width: usize,
+    ) -> AllocResult<Vec<Self>> {
+        manager.with_manager_exclusive(|manager| {
+            let mut bits: Vec<Vec<B>> = vec![Vec::with_capacity(width); count];
+            for _ in 0..width {
+                for word in &mut bits {
+                    word.push(B::new_var(manager)?);
+                }
+            }
+            Ok(bits
+                .into_iter()
+                .map(|mut bits| {
+                    bits.reverse();
+                    Self { bits }
+                })
+                .collect())
+        })
+    }
+
+    /// Get the constant `⊥` for the manager of `self`
+    fn f(&self) -> B {
+        self.bits[0].with_manager_shared(|manager, _| B::f(manager))
+    }
+
+    fn zip_with(&self, rhs: &Self, op: impl Fn(&B, &B) -> AllocResult<B>) -> AllocResult<Self> {
+        assert_eq!(self.width(), rhs.width(), "widths must be equal");
+        let bits = self
+            .bits
+            .iter()
+            .zip(&rhs.bits)
+            .map(|(a, b)| op(a, b))
+            .collect::<AllocResult<_>>()?;
+        Ok(Self { bits })
+    }
+
+    /// Compute the bitwise negation
+    ///
+    /// Locking behavior: acquires the manager's lock for shared access
+    /// (multiple times).
+    pub fn not(&self) -> AllocResult<Self> {
+        let bits = self.bits.iter().map(B::not).collect::<AllocResult<_>>()?;
+        Ok(Self { bits })
+    }
+
+    /// Compute the bitwise conjunction
+    ///
+    /// Locking behavior: acquires the manager's lock for shared access
+    /// (multiple times).
+    pub fn and(&self, rhs: &Self) -> AllocResult<Self> {
+        self.zip_with(rhs, B::and)
+    }
+
+    /// Compute the bitwise disjunction
+    ///
+    /// Locking behavior: acquires the manager's lock for shared access
+    /// (multiple times).
+    pub fn or(&self, rhs: &Self) -> AllocResult<Self> {
+        self.zip_with(rhs, B::or)
+    }
+
+    /// Compute the bitwise exclusive disjunction
+    ///
+    /// Locking behavior: acquires the manager's lock for shared access
+    /// (multiple times).
+    pub fn xor(&self, rhs: &Self) -> AllocResult<Self> {
+        self.zip_with(rhs, B::xor)
+    }
+
+    /// Ripple-carry addition of `self`, `rhs`, and the carry-in `carry`
+    fn add_carry(&self, rhs: &Self, mut carry: B) -> AllocResult<Self> {
+        assert_eq!(self.width(), rhs.width(), "widths must be equal");
+        let mut bits = Vec::with_capacity(self.width());
+        for (a, b) in self.bits.iter().zip(&rhs.bits) {
+            let a_xor_b = a.xor(b)?;
+            bits.push(a_xor_b.xor(&carry)?);
+            carry = a.and(b)?.or(&a_xor_b.and(&carry)?)?;
+        }
+        Ok(Self { bits })
+    }
+
+    /// Compute the sum `self + rhs` modulo `2^width`
+    ///
+    /// Locking behavior: acquires the manager's lock for shared access
+    /// (multiple times).
+    pub fn add(&self, rhs: &Self) -> AllocResult<Self> {
+        self.add_carry(rhs, self.f())
+    }
+
+    /// Compute the difference `self - rhs` modulo `2^width`
+    ///
+    /// Locking behavior: acquires the manager's lock for shared access
+    /// (multiple times).
+    pub fn sub(&self, rhs: &Self) -> AllocResult<Self> {
+        self.add_carry(&rhs.not()?, self.f().not()?)
+    }
+
+    /// Compute the two's complement negation `-self` modulo `2^width`
+    ///
+    /// Locking behavior: acquires the manager's lock for shared access
+    /// (multiple times).
+    pub fn neg(&self) -> AllocResult<Self> {
+        let zero = Self {
+            bits: vec![self.f(); self.width()],
+        };
+        zero.sub(self)
+    }
+
+    /// Compute the product `self * rhs` modulo `2^width` (shift-and-add)
+    ///
+    /// Locking behavior: acquires the manager's lock for shared access
+    /// (multiple times).
+    pub fn mul(&self, rhs: &Self) -> AllocResult<Self> {
+        assert_eq!(self.width(), rhs.width(), "widths must be equal");
+        let zero = Self {
+            bits: vec![self.f(); self.width()],
+        };
+        let mut acc = zero.clone();
+        for (i, b) in rhs.bits.iter().enumerate() {
+            let partial = Self::mux(b, &self.shl(i), &zero)?;
+            acc = acc.add(&partial)?;
+        }
+        Ok(acc)
+    }
+
+    /// Shift left by `amount` bits, filling with zeros
+    ///
+    /// Locking behavior: acquires the manager's lock for shared access.
+    pub fn shl(&self, amount: usize) -> Self {
+        let amount = amount.min(self.width());
+        let mut bits = vec![self.f(); amount];
+        bits.extend_from_slice(&self.bits[..self.width() - amount]);
+        Self { bits }
+    }
+
+    /// Logical shift right by `amount` bits, filling with zeros
+    ///
+    /// Locking behavior: acquires the manager's lock for shared access.
+    pub fn lshr(&self, amount: usize) -> Self {
+        let amount = amount.min(self.width());
+        let mut bits = self.bits[amount..].to_vec();
+        bits.resize(self.width(), self.f());
+        Self { bits }
+    }
+
+    /// Arithmetic shift right by `amount` bits, filling with copies of the
+    /// sign bit
+    ///
+    /// Locking behavior: does not acquire the manager's lock.
+    pub fn ashr(&self, amount: usize) -> Self {
+        let Some(sign) = self.bits.last() else {
+            return self.clone();
+        };
+        let amount = amount.min(self.width());
+        let mut bits = self.bits[amount..].to_vec();
+        bits.resize(self.width(), sign.clone());
+        Self { bits }
+    }
+
+    /// Compute `self == rhs`
+    ///
+    /// In contrast to [`PartialEq::eq()`], which compares the functions
+    /// representing the bits, this yields the function that is true iff both
+    /// words have the same value.
+    ///
+    /// Locking behavior: acquires the manager's lock for shared access
+    /// (multiple times).
+    pub fn equals(&self, rhs: &Self) -> AllocResult<B> {
+        assert_eq!(self.width(), rhs.width(), "widths must be equal");
+        let mut res = self.f().not()?;
+        for (a, b) in self.bits.iter().zip(&rhs.bits).rev() {
+            res = a.equiv(b)?.and(&res)?;
+        }
+        Ok(res)
+    }
+
+    /// Compute `self < rhs` (or `self ≤ rhs` if `or_equal` is set), treating
+    /// the most significant bits as sign bits if `signed` is set
+    fn less(&self, rhs: &Self, signed: bool, or_equal: bool) -> AllocResult<B> {
+        assert_eq!(self.width(), rhs.width(), "widths must be equal");
+        let mut res = if or_equal { self.f().not()? } else { self.f() };
+        let last = self.width().wrapping_sub(1);
+        for (i, (a, b)) in self.bits.iter().zip(&rhs.bits).enumerate() {
+            // For the sign bit, `a` being set means that `self` is smaller
+            let (a, b) = if signed && i == last { (b, a) } else { (a, b) };
+            // `self < rhs` on the bits up to `i` iff `¬a ∧ b`, or `a ≡ b` and
+            // `self < rhs` on the bits below `i`
+            res = b.ite(&a.imp(&res)?, &a.imp_strict(&res)?)?;
+        }
+        Ok(res)
+    }
+
+    /// Compute the unsigned comparison `self < rhs`
+    ///
+    /// Locking behavior: acquires the manager's lock for shared access
+    /// (multiple times).
+    pub fn ult(&self, rhs: &Self) -> AllocResult<B> {
+        self.less(rhs, false, false)
+    }
+
+    /// Compute the unsigned comparison `self ≤ rhs`
+    ///
+    /// Locking behavior: acquires the manager's lock for shared access
+    /// (multiple times).
+    pub fn ule(&self, rhs: &Self) -> AllocResult<B> {
+        self.less(rhs, false, true)
+    }
+
+    /// Compute the signed (two's complement) comparison `self < rhs`
+    ///
+    /// Locking behavior: acquires the manager's lock for shared access
+    /// (multiple times).
+    pub fn slt(&self, rhs: &Self) -> AllocResult<B> {
+        self.less(rhs, true, false)
+    }
+
+    /// Compute the signed (two's complement) comparison `self ≤ rhs`
+    ///
+    /// Locking behavior: acquires the manager's lock for shared access
+    /// (multiple times).
+    pub fn sle(&self, rhs: &Self) -> AllocResult<B> {
+        self.less(rhs, true, true)
+    }
+
+    /// Select `then_case` if `cond` is true and `else_case` otherwise
+    /// (bitwise if-then-else)
+    ///
+    /// Locking behavior: acquires the manager's lock for shared access
+    /// (multiple times).
+    pub fn mux(cond: &B, then_case: &Self, else_case: &Self) -> AllocResult<Self> {
+        then_case.zip_with(else_case, |t, e| cond.ite(t, e))
+    }
+}
@@ -0,0 +1,178 @@
+//! Cardinality and pseudo-Boolean constraints
+//!
+//! The constructors in this module directly build the decision diagram of a
+//! constraint `lo ≤ Σ aᵢ·xᵢ ≤ hi` via dynamic programming over the variables,
+//! where the states are the partial sums of the terms processed so far. This
+//! avoids the potentially exponential intermediate results of combining
+//! the terms using [`BooleanFunction::and()`] and [`BooleanFunction::or()`].
+//!
+//! There is one [`BooleanFunction::ite()`] call per reachable state, where the
+//! condition is the variable of the current term. Passing the variables ordered
+//! by their level (top-most first) makes each of these calls cheap, since the
+//! variable is then above the variables of both branches. Every variable
+//! should occur at most once.
+
+use std::collections::HashMap;
+
+use oxidd_core::function::BooleanFunction;
+use oxidd_core::ManagerRef;
+
+use crate::util::AllocResult;
+
+/// Dynamic programming state for building `lo ≤ Σ aᵢ·xᵢ ≤ hi`
+struct Linear<'a, B> {
+    terms: &'a [(B, i64)],
+    lo: i64,
+    hi: i64,
+    /// `min_rest[i]` is the minimum of `Σ_{j ≥ i} aⱼ·xⱼ`
+    min_rest: Vec<i64>,
+    /// `max_rest[i]` is the maximum of `Σ_{j ≥ i} aⱼ·xⱼ`
+    max_rest: Vec<i64>,
+    t: B,
+    f: B,
+    /// Functions for the states `(i, sum)`
+    memo: HashMap<(usize, i64), B>,
+}
+
+impl<B: BooleanFunction> Linear<'_, B> {
+    /// Function that is true iff `sum + Σ_{j ≥ i} aⱼ·xⱼ` lies within the
+    /// bounds
+    fn build(&mut self, i: usize, sum: i64) -> AllocResult<B> {
+        if sum + self.min_rest[i] >= self.lo && sum + self.max_rest[i] <= self.hi {
+            return Ok(self.t.clone());
+        }
+        if sum + self.max_rest[i] < self.lo || sum + self.min_rest[i] > self.hi {
+            return Ok(self.f.clone());
+        }
+        if let Some(res) = self.memo.get(&(i, sum)) {
+            return Ok(res.clone());
+        }
+
+        let (var, a) = &self.terms[i];
+        let then_case = self.build(i + 1, sum + a)?;
+        let else_case = self.build(i + 1, sum)?;
+        let res = var.ite(&then_case, &else_case)?;
+        self.memo.insert((i, sum), res.clone());
+        Ok(res)
+    }
+}
+
+/// Build the constraint `lo ≤ Σ aᵢ·xᵢ ≤ hi` for `terms` `(xᵢ, aᵢ)`
+fn linear_range<B: BooleanFunction>(
+    manager: &B::ManagerRef,
+    terms: &[(B, i64)],
+    lo: i64,
+    hi: i64,
+) -> AllocResult<B> {
+    let n = terms.len();
+    let mut min_rest = vec![0; n + 1];
+    let mut max_rest = vec![0; n + 1];
+    for (i, &(_, a)) in terms.iter().enumerate().rev() {
+        min_rest[i] = min_rest[i + 1] + a.min(0);
+        max_rest[i] = max_rest[i + 1] + a.max(0);
+    }
+    let (t, f) = manager.with_manager_shared(|manager| (B::t(manager), B::f(manager)));
+
+    Linear {
+        terms,
+        lo,
+        hi,
+        min_rest,
+        max_rest,
+        t,
+        f,
+        memo: HashMap::new(),
+    }
+    .build(0, 0)
+}
+
+fn cardinality<B: BooleanFunction>(
+    manager: &B::ManagerRef,
+    vars: &[B],
+    lo: i64,
+    hi: i64,
+) -> AllocResult<B> {
+    let terms: Vec<(B, i64)> = vars.iter().map(|var| (var.clone(), 1)).collect();
+    linear_range(manager, &terms, lo, hi)
+}
+
+/// Build the constraint that at most `k` of `vars` are true
+///
+/// Locking behavior: acquires the manager's lock for shared access (multiple
+/// times).
+pub fn at_most_k<B: BooleanFunction>(
+    manager: &B::ManagerRef,
+    vars: &[B],
+    k: u32,
+) -> AllocResult<B> {
+    cardinality(manager, vars, i64::MIN, k as i64)
+}
+
+/// Build the constraint that at least `k` of `vars` are true
+///
+/// Locking behavior: acquires the manager's lock for shared access (multiple
+/// times).
+pub fn at_least_k<B: BooleanFunction>(
+    manager: &B::ManagerRef,
+    vars: &[B],
+    k: u32,
+) -> AllocResult<B> {
+    cardinality(manager, vars, k as i64, i64::MAX)
+}
+
+/// Build the constraint that exactly `k` of `vars` are true
+///
+/// Locking behavior: acquires the manager's lock for shared access (multiple
+/// times).
+pub fn exactly_k<B: BooleanFunction>(
+    manager: &B::ManagerRef,
+    vars: &[B],
+    k: u32,
+) -> AllocResult<B> {
+    cardinality(manager, vars, k as i64, k as i64)
+}
+
+/// Build the pseudo-Boolean constraint `Σ aᵢ·xᵢ ≤ bound` for `terms` of the
+/// form `(xᵢ, aᵢ)`
+///
+/// The coefficients may be negative. The sums must not overflow an [`i64`].
+///
+/// Locking behavior: acquires the manager's lock for shared access (multiple
+/// times).
+pub fn linear_le<B: BooleanFunction>(
+    manager: &B::ManagerRef,
+    terms: &[(B, i64)],
+    bound: i64,
+) -> AllocResult<B> {
+    linear_range(manager, terms, i64::MIN, bound)
+}
+
+/// Build the pseudo-Boolean constraint `Σ aᵢ·xᵢ ≥ bound` for `terms` of the
+/// form `(xᵢ, aᵢ)`
+///
+/// The coefficients may be negative. The sums must not overflow an [`i64`].
+///
+/// Locking behavior: acquires the manager's lock for shared access (multiple
+/// times).
+pub fn linear_ge<B: BooleanFunction>(
+    manager: &B::ManagerRef,
+    terms: &[(B, i64)],
+    bound: i64,
+) -> AllocResult<B> {
+    linear_range(manager, terms, bound, i64::MAX)
+}
+
+/// Build the pseudo-Boolean constraint `Σ aᵢ·xᵢ = bound` for `terms` of the
+/// form `(xᵢ, aᵢ)`
+///
+/// The coefficients may be negative. The sums must not overflow an [`i64`].
+///
+/// Locking behavior: acquires the manager's lock for shared access (multiple
+/// times).
+pub fn linear_eq<B: BooleanFunction>(
+    manager: &B::ManagerRef,
+    terms: &[(B, i64)],
+    bound: i64,
+) -> AllocResult<B> {
+    linear_range(manager, terms, bound, bound)
+}
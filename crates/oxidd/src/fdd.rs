@@ -0,0 +1,178 @@
+//! Finite-domain variables
+//!
+//! A finite-domain variable with domain `{0, …, n - 1}` is encoded in
+//! `⌈log₂ n⌉` Boolean variables (at least one), which are obtained via
+//! [`BooleanFunction::new_var()`]. This is similar to the `fdd` module of
+//! BuDDy.
+
+use std::ops::Bound;
+use std::ops::RangeBounds;
+
+use oxidd_core::function::BooleanFunction;
+use oxidd_core::function::INodeOfFunc;
+use oxidd_core::HasLevel;
+use oxidd_core::Manager;
+
+use crate::bitvec::BitVec;
+use crate::util::AllocResult;
+use crate::util::OptBool;
+
+/// Finite-domain variable
+///
+/// The value is the unsigned integer given by the [`BitVec`] of its Boolean
+/// variables.
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct FddVar<B> {
+    bits: BitVec<B>,
+    domain_size: u64,
+}
+
+impl<B: BooleanFunction> FddVar<B> {
+    /// Create a variable with domain `{0, …, domain_size - 1}`
+    ///
+    /// The most significant bit gets the top-most level.
+    ///
+    /// Locking behavior: acquires the manager's lock for exclusive access.
+    ///
+    /// Panics if `domain_size` is 0.
+    pub fn new(manager: &B::ManagerRef, domain_size: u64) -> AllocResult<Self> {
+        let mut vars = Self::new_interleaved(manager, 1, domain_size)?;
+        Ok(vars.pop().unwrap())
+    }
+
+    /// Create `count` variables with domain `{0, …, domain_size - 1}` each,
+    /// with their Boolean variables interleaved
+    ///
+    /// See [`BitVec::new_interleaved()`] for the resulting variable order.
+    /// Interleaving keeps, e.g., the decision diagram of [`Self::equals()`]
+    /// linear in the number of bits.
+    ///
+    /// Locking behavior: acquires the manager's lock for exclusive access.
+    ///
+    /// Panics if `domain_size` is 0.
+    pub fn new_interleaved(
+        manager: &B::ManagerRef,
+        count: usize,
+        domain_size: u64,
+    ) -> AllocResult<Vec<Self>> {
+        assert!(domain_size > 0, "the domain must not be empty");
+        let num_bits = (u64::BITS - (domain_size - 1).leading_zeros()).max(1);
+        let words = BitVec::new_interleaved(manager, count, num_bits as usize)?;
+        Ok(words
+            .into_iter()
+            .map(|bits| Self { bits, domain_size })
+            .collect())
+    }
+
+    /// Get the size of the domain
+    pub fn domain_size(&self) -> u64 {
+        self.domain_size
+    }
+
+    /// Get the Boolean variables encoding this variable
+    pub fn bits(&self) -> &BitVec<B> {
+        &self.bits
+    }
+
+    /// Get the constant `⊥` for the manager of `self`
+    fn ff(&self) -> B {
+        self.bits.bits()[0].with_manager_shared(|manager, _| B::f(manager))
+    }
+
+    /// Get the constant word `value` with the width of `self`
+    fn constant(&self, value: u64) -> BitVec<B> {
+        let manager = self.bits.bits()[0].manager_ref();
+        BitVec::constant(&manager, self.bits.width(), value)
+    }
+
+    /// Get the function that is true iff `self` has the value `value`
+    ///
+    /// Locking behavior: acquires the manager's lock for shared access
+    /// (multiple times).
+    ///
+    /// Panics if `value` is not contained in the domain.
+    pub fn ithvar(&self, value: u64) -> AllocResult<B> {
+        assert!(value < self.domain_size, "value out of domain");
+        self.bits.equals(&self.constant(value))
+    }
+
+    /// Get the function that is true iff the value of `self` is contained in
+    /// the domain
+    ///
+    /// Unless the domain size is a power of two, there are valuations of the
+    /// Boolean variables that do not correspond to a value of the domain.
+    ///
+    /// Locking behavior: acquires the manager's lock for shared access
+    /// (multiple times).
+    pub fn domain(&self) -> AllocResult<B> {
+        self.bits.ule(&self.constant(self.domain_size - 1))
+    }
+
+    /// Get the function that is true iff the value of `self` lies in `range`
+    ///
+    /// Values outside the domain are not excluded, combine the result with
+    /// [`Self::domain()`] if needed.
+    ///
+    /// Locking behavior: acquires the manager's lock for shared access
+    /// (multiple times).
+    pub fn in_range(&self, range: impl RangeBounds<u64>) -> AllocResult<B> {
+        let max = u64::MAX >> (u64::BITS - self.bits.width() as u32);
+        let lo = match range.start_bound() {
+            Bound::Included(&lo) => Some(lo),
+            Bound::Excluded(&lo) => lo.checked_add(1),
+            Bound::Unbounded => Some(0),
+        };
+        let hi = match range.end_bound() {
+            Bound::Included(&hi) => Some(hi.min(max)),
+            Bound::Excluded(&hi) => hi.checked_sub(1).map(|hi| hi.min(max)),
+            Bound::Unbounded => Some(max),
+        };
+        let (Some(lo), Some(hi)) = (lo, hi) else {
+            return Ok(self.ff());
+        };
+        if lo > hi {
+            return Ok(self.ff());
+        }
+        let lower = self.constant(lo).ule(&self.bits)?;
+        lower.and(&self.bits.ule(&self.constant(hi))?)
+    }
+
+    /// Get the function that is true iff `self` and `other` have the same
+    /// value
+    ///
+    /// Locking behavior: acquires the manager's lock for shared access
+    /// (multiple times).
+    ///
+    /// Panics if `self` and `other` are encoded using different numbers of
+    /// Boolean variables.
+    pub fn equals(&self, other: &Self) -> AllocResult<B> {
+        self.bits.equals(&other.bits)
+    }
+
+    /// Decode the value of `self` from `cube`
+    ///
+    /// The i-th entry of `cube` refers to the variable at level `i`, as for
+    /// the result of [`BooleanFunction::pick_cube()`] with an empty `order`.
+    /// "Don't care" bits are treated as false.
+    ///
+    /// Locking behavior: acquires the manager's lock for shared access.
+    pub fn decode(&self, cube: &[OptBool]) -> u64
+    where
+        for<'id> INodeOfFunc<'id, B>: HasLevel,
+    {
+        let bits = self.bits.bits();
+        bits[0].with_manager_shared(|manager, _| {
+            let mut value = 0;
+            for (i, bit) in bits.iter().enumerate() {
+                let level = manager
+                    .get_node(bit.as_edge(manager))
+                    .expect_inner("variables must refer to inner nodes")
+                    .level();
+                if cube[level as usize] == OptBool::True {
+                    value |= 1 << i;
+                }
+            }
+            value
+        })
+    }
+}
@@ -0,0 +1,378 @@
+//! Image and preimage computation for partitioned transition relations
+//!
+//! A transition relation `T(x, i, y)` over current-state variables `x`, input
+//! variables `i`, and next-state variables `y` is given as a list of
+//! conjuncts. Instead of building the (potentially huge) conjunction, the
+//! image `∃x, i. S(x) ∧ T(x, i, y)` is computed by conjoining one cluster of
+//! conjuncts at a time and quantifying each variable as soon as no remaining
+//! cluster depends on it (early quantification). The clusters and their order
+//! are determined as proposed by Ranjan et al. at IWLS 1995:
+//!
+//! 1. The conjuncts are ordered greedily, preferring conjuncts that allow
+//!    quantifying many of their variables early and introduce few new
+//!    variables that cannot be quantified.
+//! 2. Consecutive conjuncts are combined into clusters as long as the
+//!    decision diagram of a cluster does not exceed a size threshold.
+//! 3. The clusters are ordered using the same heuristic.
+//!
+//! Since the variables that may be quantified differ, there are separate
+//! schedules for the image and the preimage.
+
+use oxidd_core::function::BooleanFunctionQuant;
+use oxidd_core::function::FunctionSubst;
+use oxidd_core::function::INodeOfFunc;
+use oxidd_core::util::new_substitution_id;
+use oxidd_core::util::Substitution;
+use oxidd_core::HasLevel;
+use oxidd_core::LevelNo;
+use oxidd_core::Manager;
+use oxidd_core::Node;
+
+use crate::util::AllocResult;
+use crate::BooleanOperator;
+
+/// Substitution owning its variables and replacements, such that it keeps
+/// its ID (and hence, apply cache entries remain valid) across multiple
+/// image computations
+struct VarMap<B> {
+    id: u32,
+    vars: Vec<B>,
+    replacements: Vec<B>,
+}
+
+impl<B> VarMap<B> {
+    fn new(vars: &[B], replacements: &[B]) -> Self
+    where
+        B: Clone,
+    {
+        Self {
+            id: new_substitution_id(),
+            vars: vars.to_vec(),
+            replacements: replacements.to_vec(),
+        }
+    }
+}
+
+impl<'a, B> Substitution for &'a VarMap<B> {
+    type Var = &'a B;
+    type Replacement = &'a B;
+
+    fn id(&self) -> u32 {
+        self.id
+    }
+
+    fn pairs(&self) -> impl ExactSizeIterator<Item = (&'a B, &'a B)> {
+        let map: &'a VarMap<B> = self;
+        map.vars.iter().zip(&map.replacements)
+    }
+}
+
+/// Early quantification schedule
+struct Schedule<B> {
+    /// Cube of the variables to quantify before conjoining any cluster
+    pre_quant: B,
+    /// Clusters along with the cube of the variables to quantify right after
+    /// conjoining the cluster
+    steps: Vec<(B, B)>,
+}
+
+/// Level of the variable referenced by `var`
+///
+/// Panics if `var` references a terminal node.
+fn var_level<M: Manager>(manager: &M, var: &M::Edge) -> LevelNo
+where
+    M::InnerNode: HasLevel,
+{
+    match manager.get_node(var) {
+        Node::Inner(node) => node.level(),
+        Node::Terminal(_) => panic!("variables must not be constant"),
+    }
+}
+
+/// Order `supports` greedily (IWLS95 heuristic)
+///
+/// The supports are given as sorted lists of variable indices. Returns a
+/// permutation of the indices of `supports`.
+fn order(supports: &[Vec<usize>], quantifiable: &[bool]) -> Vec<usize> {
+    // Number of remaining items depending on each variable
+    let mut occurrences = vec![0usize; quantifiable.len()];
+    for support in supports {
+        for &var in support {
+            occurrences[var] += 1;
+        }
+    }
+    let mut introduced = vec![false; quantifiable.len()];
+
+    let mut remaining: Vec<usize> = (0..supports.len()).collect();
+    let mut res = Vec::with_capacity(supports.len());
+    while !remaining.is_empty() {
+        let score = |i: usize| {
+            let (mut quant, mut last, mut new) = (0u32, 0u32, 0u32);
+            for &var in &supports[i] {
+                if quantifiable[var] {
+                    quant += 1;
+                    if occurrences[var] == 1 {
+                        last += 1;
+                    }
+                } else if !introduced[var] {
+                    new += 1;
+                }
+            }
+            let ratio = if quant == 0 {
+                0.0
+            } else {
+                last as f64 / quant as f64
+            };
+            (ratio, new)
+        };
+        let mut best = 0;
+        let mut best_score = score(remaining[0]);
+        for (j, &i) in remaining.iter().enumerate().skip(1) {
+            let s = score(i);
+            if s.0 > best_score.0 || (s.0 == best_score.0 && s.1 < best_score.1) {
+                best = j;
+                best_score = s;
+            }
+        }
+
+        let i = remaining.remove(best);
+        for &var in &supports[i] {
+            occurrences[var] -= 1;
+            introduced[var] = true;
+        }
+        res.push(i);
+    }
+    res
+}
+
+/// Union of two sorted lists of variable indices
+fn union(a: &[usize], b: &[usize]) -> Vec<usize> {
+    let mut res: Vec<usize> = a.iter().chain(b).copied().collect();
+    res.sort_unstable();
+    res.dedup();
+    res
+}
+
+impl<B: BooleanFunctionQuant> Schedule<B>
+where
+    for<'id> INodeOfFunc<'id, B>: HasLevel,
+{
+    /// Create a schedule for quantifying the variables `vars[i]` with
+    /// `quantifiable[i]` set
+    ///
+    /// `supports[j]` is the sorted list of indices into `vars` the j-th
+    /// conjunct depends on.
+    fn new(
+        conjuncts: &[B],
+        vars: &[B],
+        supports: &[Vec<usize>],
+        quantifiable: &[bool],
+        max_cluster_size: usize,
+    ) -> AllocResult<Self> {
+        // Cluster the ordered conjuncts
+        let mut clusters: Vec<(B, Vec<usize>)> = Vec::new();
+        for i in order(supports, quantifiable) {
+            if let Some((cluster, support)) = clusters.last_mut() {
+                let conj = cluster.and(&conjuncts[i])?;
+                if conj.node_count() <= max_cluster_size {
+                    *cluster = conj;
+                    *support = union(support, &supports[i]);
+                    continue;
+                }
+            }
+            clusters.push((conjuncts[i].clone(), supports[i].clone()));
+        }
+
+        // Order the clusters and quantify each variable after the last cluster
+        // depending on it
+        let cluster_supports: Vec<Vec<usize>> = clusters.iter().map(|(_, s)| s.clone()).collect();
+        let cluster_order = order(&cluster_supports, quantifiable);
+        let mut last = vec![None; quantifiable.len()];
+        for (step, &i) in cluster_order.iter().enumerate() {
+            for &var in &cluster_supports[i] {
+                last[var] = Some(step);
+            }
+        }
+        let mut step_vars: Vec<Vec<&B>> = vec![Vec::new(); clusters.len()];
+        let mut pre_vars = Vec::new();
+        for ((var, &last), &quantifiable) in vars.iter().zip(&last).zip(quantifiable) {
+            if !quantifiable {
+                continue;
+            }
+            match last {
+                Some(step) => step_vars[step].push(var),
+                None => pre_vars.push(var),
+            }
+        }
+
+        // The clustering above may have reordered the variables, so determine
+        // the levels for the cubes in the closure building them.
+        conjuncts[0].with_manager_shared_restart(|manager, _| {
+            let cube = |vars: &[&B]| {
+                let literals: Vec<(LevelNo, bool)> = vars
+                    .iter()
+                    .map(|var| (var_level(manager, var.as_edge(manager)), true))
+                    .collect();
+                B::cube(manager, &literals)
+            };
+            let pre_quant = cube(&pre_vars)?;
+            let steps = cluster_order
+                .iter()
+                .zip(&step_vars)
+                .map(|(&i, vars)| Ok((clusters[i].0.clone(), cube(vars)?)))
+                .collect::<AllocResult<_>>()?;
+            Ok(Self { pre_quant, steps })
+        })
+    }
+
+    /// Compute `∃ quantifiable. f ∧ ⋀ clusters`
+    fn apply(&self, f: &B) -> AllocResult<B> {
+        let mut f = f.exist(&self.pre_quant)?;
+        for (cluster, vars) in &self.steps {
+            f = f.apply_exist(BooleanOperator::And, cluster, vars)?;
+        }
+        Ok(f)
+    }
+}
+
+/// Partitioned transition relation with early quantification schedules for
+/// image and preimage computations
+///
+/// See the [module-level documentation][self] for more details.
+pub struct TransitionRelation<B> {
+    image: Schedule<B>,
+    preimage: Schedule<B>,
+    next_to_current: VarMap<B>,
+    current_to_next: VarMap<B>,
+}
+
+impl<B: BooleanFunctionQuant + FunctionSubst> TransitionRelation<B>
+where
+    for<'id> INodeOfFunc<'id, B>: HasLevel,
+{
+    /// Default threshold for the number of nodes of a cluster
+    pub const DEFAULT_CLUSTER_SIZE: usize = 5000;
+
+    /// Create a transition relation given as the conjunction of `conjuncts`
+    ///
+    /// The i-th variable of `current` is the current-state version of the i-th
+    /// variable of `next`. All variables the conjuncts depend on that are
+    /// neither in `current` nor in `next` are considered inputs. Uses
+    /// [`Self::DEFAULT_CLUSTER_SIZE`] as the cluster size threshold.
+    ///
+    /// The schedules refer to the variables by their handles, so they remain
+    /// valid if the variable order changes, e.g., due to automatic reordering
+    /// during their construction or between image computations.
+    ///
+    /// Locking behavior: acquires the manager's lock for shared access
+    /// (multiple times).
+    ///
+    /// Panics if `conjuncts` is empty, if `current` and `next` have different
+    /// lengths, if an element of `current` or `next` is constant (i.e., does
+    /// not reference an inner node), or if the functions do not all belong to
+    /// the same manager.
+    pub fn new(conjuncts: &[B], current: &[B], next: &[B]) -> AllocResult<Self> {
+        Self::with_cluster_size(conjuncts, current, next, Self::DEFAULT_CLUSTER_SIZE)
+    }
+
+    /// Like [`Self::new()`], but with a custom threshold for the number of
+    /// nodes of a cluster
+    ///
+    /// A cluster may exceed the threshold if it consists of a single conjunct.
+    /// With a threshold of 0, each conjunct forms its own cluster.
+    ///
+    /// Locking behavior: acquires the manager's lock for shared access
+    /// (multiple times).
+    ///
+    /// Panics under the same conditions as [`Self::new()`].
+    pub fn with_cluster_size(
+        conjuncts: &[B],
+        current: &[B],
+        next: &[B],
+        max_cluster_size: usize,
+    ) -> AllocResult<Self> {
+        assert!(!conjuncts.is_empty(), "there must be at least one conjunct");
+        assert_eq!(
+            current.len(),
+            next.len(),
+            "`current` and `next` must have the same length"
+        );
+
+        // Refer to the variables by their handles instead of their levels,
+        // such that the schedules do not depend on the variable order. The
+        // first `current.len()` variables are the current-state variables,
+        // followed by the next-state variables and the inputs.
+        let (vars, supports) = conjuncts[0].with_manager_shared_restart(|manager, _| {
+            let mut var_of_level = vec![None; manager.num_levels() as usize];
+            let mut vars: Vec<B> = Vec::new();
+            for var in current.iter().chain(next) {
+                let level = var_level(manager, var.as_edge(manager));
+                var_of_level[level as usize] = Some(vars.len());
+                vars.push(var.clone());
+            }
+            let mut supports: Vec<Vec<usize>> = Vec::with_capacity(conjuncts.len());
+            for conjunct in conjuncts {
+                let levels = B::support_levels_edge(manager, conjunct.as_edge(manager));
+                let mut support = Vec::with_capacity(levels.len());
+                for level in levels {
+                    let var = match var_of_level[level as usize] {
+                        Some(var) => var,
+                        None => {
+                            var_of_level[level as usize] = Some(vars.len());
+                            vars.push(B::cube(manager, &[(level, true)])?);
+                            vars.len() - 1
+                        }
+                    };
+                    support.push(var);
+                }
+                support.sort_unstable();
+                supports.push(support);
+            }
+            Ok((vars, supports))
+        })?;
+
+        let num_state_vars = current.len();
+        let image_quant: Vec<bool> = (0..vars.len())
+            .map(|i| !(num_state_vars..2 * num_state_vars).contains(&i))
+            .collect();
+        let preimage_quant: Vec<bool> = (0..vars.len()).map(|i| i >= num_state_vars).collect();
+        Ok(Self {
+            image: Schedule::new(conjuncts, &vars, &supports, &image_quant, max_cluster_size)?,
+            preimage: Schedule::new(
+                conjuncts,
+                &vars,
+                &supports,
+                &preimage_quant,
+                max_cluster_size,
+            )?,
+            next_to_current: VarMap::new(next, current),
+            current_to_next: VarMap::new(current, next),
+        })
+    }
+
+    /// Get the number of clusters used for image computations
+    pub fn num_image_clusters(&self) -> usize {
+        self.image.steps.len()
+    }
+
+    /// Compute the image of `states`, i.e., the set of successor states
+    ///
+    /// `states` and the result are given over the current-state variables.
+    ///
+    /// Locking behavior: acquires the manager's lock for shared access
+    /// (multiple times).
+    pub fn image(&self, states: &B) -> AllocResult<B> {
+        self.image.apply(states)?.substitute(&self.next_to_current)
+    }
+
+    /// Compute the preimage of `states`, i.e., the set of predecessor states
+    ///
+    /// `states` and the result are given over the current-state variables.
+    ///
+    /// Locking behavior: acquires the manager's lock for shared access
+    /// (multiple times).
+    pub fn preimage(&self, states: &B) -> AllocResult<B> {
+        let states = states.substitute(&self.current_to_next)?;
+        self.preimage.apply(&states)
+    }
+}
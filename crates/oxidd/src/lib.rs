@@ -14,6 +14,7 @@ std::compile_error!(
 
 pub use oxidd_core::function::BooleanFunction;
 pub use oxidd_core::function::BooleanFunctionQuant;
+pub use oxidd_core::function::BooleanOperator;
 pub use oxidd_core::function::BooleanVecSet;
 pub use oxidd_core::function::Function;
 pub use oxidd_core::function::FunctionSubst;
@@ -27,6 +28,10 @@ pub use oxidd_core::Manager;
 pub use oxidd_core::ManagerRef;
 pub use oxidd_core::NodeID;
 
+pub mod bitvec;
+pub mod constraints;
+pub mod fdd;
+pub mod image;
 pub mod util;
 
 #[deprecated = "use AllocResult from the oxidd::util module"]
@@ -5,13 +5,20 @@ pub(crate) mod type_cons;
 
 pub use oxidd_core::util::num;
 pub use oxidd_core::util::AllocResult;
+pub use oxidd_core::util::Assignments;
+pub use oxidd_core::util::AutoReorder;
 pub use oxidd_core::util::Borrowed;
+pub use oxidd_core::util::CostNumber;
+pub use oxidd_core::util::Cubes;
 pub use oxidd_core::util::IsFloatingPoint;
 pub use oxidd_core::util::OptBool;
 pub use oxidd_core::util::OutOfMemory;
 pub use oxidd_core::util::Rng;
 pub use oxidd_core::util::SatCountCache;
 pub use oxidd_core::util::SatCountNumber;
+pub use oxidd_core::util::Unateness;
+pub use oxidd_core::util::WeightedCountNumber;
+pub use oxidd_core::util::WeightedSatCountCache;
 pub use rustc_hash::FxHasher;
 
 // We have a few `allow(unused)` attributes here to not spam the user with
@@ -69,6 +76,11 @@ macro_rules! manager_data {
             }
         }
 
+        impl<'id, $($($gen),*)?> ::oxidd_core::util::ReorderHook<<$dd$(<$($dd_gen),*>)? as $crate::util::type_cons::DD>::Manager<'id>>
+            for $name<'id, $($($gen),*)?> $(where $($where)*)?
+        {
+        }
+
         impl<'id, $($($gen),*)?> ::oxidd_core::HasApplyCache<<$dd$(<$($dd_gen),*>)? as $crate::util::type_cons::DD>::Manager<'id>, $op>
             for $name<'id, $($($gen),*)?> $(where $($where)*)?
         {
@@ -56,6 +56,16 @@ macro_rules! manager_data {
                 unsafe { self.apply_cache.post_gc(manager) }
             }
         }
+        impl<'id>
+            ::oxidd_core::util::ReorderHook<<$dd as $crate::util::type_cons::DD>::Manager<'id>>
+            for $name<'id>
+        {
+            #[inline]
+            fn post_reorder(manager: &mut <$dd as $crate::util::type_cons::DD>::Manager<'id>) {
+                // The tautologies depend on the variable order
+                ::oxidd_rules_zbdd::ZBDDCache::rebuild(manager)
+            }
+        }
         impl<'id>
             ::oxidd_core::HasApplyCache<<$dd as $crate::util::type_cons::DD>::Manager<'id>, $op>
             for $name<'id>
@@ -5,15 +5,26 @@
 mod boolean_prop;
 mod util;
 
+use std::hash::BuildHasherDefault;
+
 use oxidd_core::function::FunctionSubst;
+use oxidd_core::function::INodeOfFunc;
+use oxidd_core::HasLevel;
+use oxidd_core::LevelNo;
 use rustc_hash::FxHashMap;
+use rustc_hash::FxHasher;
 
 use oxidd::bcdd::BCDDFunction;
 use oxidd::bdd::BDDFunction;
+use oxidd::util::num::LogF64;
+use oxidd::util::OptBool;
+use oxidd::util::Unateness;
+use oxidd::util::WeightedSatCountCache;
 use oxidd::zbdd::ZBDDFunction;
 use oxidd::zbdd::ZBDDManagerRef;
 use oxidd::BooleanFunction;
 use oxidd::BooleanFunctionQuant;
+use oxidd::BooleanOperator;
 use oxidd::BooleanVecSet;
 use oxidd::Function;
 use oxidd::ManagerRef;
@@ -742,7 +753,572 @@ impl<'a, B: BooleanFunctionQuant> TestAllBooleanFunctions<'a, B> {
 
                 let unique_actual = self.dd_to_boolean_func[&f.unique(&dd_var_set).unwrap()];
                 assert_eq!(unique_actual, unique_expected);
+
+                // fused apply and quantification
+                for (g_explicit, g) in self.boolean_functions.iter().enumerate() {
+                    let g_explicit = g_explicit as ExplicitBFunc;
+                    for (op, h_explicit) in [
+                        (BooleanOperator::And, f_explicit & g_explicit),
+                        (BooleanOperator::Or, f_explicit | g_explicit),
+                        (BooleanOperator::Xor, f_explicit ^ g_explicit),
+                        (BooleanOperator::Equiv, !(f_explicit ^ g_explicit)),
+                        (BooleanOperator::Nand, !(f_explicit & g_explicit)),
+                        (BooleanOperator::Nor, !(f_explicit | g_explicit)),
+                        (BooleanOperator::Imp, !f_explicit | g_explicit),
+                        (BooleanOperator::ImpStrict, !f_explicit & g_explicit),
+                    ] {
+                        let h_explicit = h_explicit & func_mask;
+                        let mut exist_expected: ExplicitBFunc = 0;
+                        let mut forall_expected: ExplicitBFunc = 0;
+                        let mut unique_expected: ExplicitBFunc = 0;
+                        for (assignment, mask) in assignment_to_mask.iter().copied().enumerate() {
+                            let exist_bit = h_explicit & mask != 0;
+                            let forall_bit = h_explicit & mask == mask;
+                            let unique_bit = (h_explicit & mask).count_ones() & 1;
+                            exist_expected |= (exist_bit as ExplicitBFunc) << assignment;
+                            forall_expected |= (forall_bit as ExplicitBFunc) << assignment;
+                            unique_expected |= (unique_bit as ExplicitBFunc) << assignment;
+                        }
+
+                        let actual = f.apply_exist(op, g, &dd_var_set).unwrap();
+                        assert_eq!(self.dd_to_boolean_func[&actual], exist_expected, "{op:?}");
+
+                        let actual = f.apply_forall(op, g, &dd_var_set).unwrap();
+                        assert_eq!(self.dd_to_boolean_func[&actual], forall_expected, "{op:?}");
+
+                        let actual = f.apply_unique(op, g, &dd_var_set).unwrap();
+                        assert_eq!(self.dd_to_boolean_func[&actual], unique_expected, "{op:?}");
+                    }
+
+                    let actual = f.and_exist(g, &dd_var_set).unwrap();
+                    let expected =
+                        self.dd_to_boolean_func[&f.and(g).unwrap().exist(&dd_var_set).unwrap()];
+                    assert_eq!(self.dd_to_boolean_func[&actual], expected);
+                }
+            }
+        }
+    }
+}
+
+impl<'a, B: BooleanFunctionQuant> TestAllBooleanFunctions<'a, B> {
+    /// Test `constrain()`, `minimize()`, and `li_compaction()` for all pairs
+    /// of Boolean functions
+    pub fn care_set(&self) {
+        let nvars = self.vars.len() as u32;
+        let num_assignments = 1u32 << nvars;
+
+        // Distance between two assignments, where the top-most variable is the
+        // most significant one
+        let distance = |a: u32, b: u32| (a ^ b).reverse_bits() >> (u32::BITS - nvars);
+
+        for (f_explicit, f) in self.boolean_functions.iter().enumerate() {
+            let f_explicit = f_explicit as ExplicitBFunc;
+            for (c_explicit, c) in self.boolean_functions.iter().enumerate() {
+                let c_explicit = c_explicit as ExplicitBFunc;
+                let f_and_c = self.dd_to_boolean_func[&f.and(c).unwrap()];
+
+                let mut constrain_expected: ExplicitBFunc = 0;
+                if c_explicit != 0 {
+                    for assignment in 0..num_assignments {
+                        let closest = (0..num_assignments)
+                            .filter(|&b| (c_explicit >> b) & 1 != 0)
+                            .min_by_key(|&b| distance(assignment, b))
+                            .unwrap();
+                        constrain_expected |= ((f_explicit >> closest) & 1) << assignment;
+                    }
+                }
+                let constrain = f.constrain(c).unwrap();
+                assert_eq!(self.dd_to_boolean_func[&constrain], constrain_expected);
+
+                let minimize = f.minimize(c).unwrap();
+                let li_compaction = f.li_compaction(c).unwrap();
+                for res in [&constrain, &minimize, &li_compaction] {
+                    assert_eq!(self.dd_to_boolean_func[&res.and(c).unwrap()], f_and_c);
+                }
+                assert!(li_compaction.node_count() <= f.node_count());
+            }
+        }
+    }
+}
+
+impl<'a, B: BooleanFunction> TestAllBooleanFunctions<'a, B> {
+    /// Test weighted model counting on all Boolean functions
+    pub fn weighted_sat_count(&self) {
+        let nvars = self.vars.len() as u32;
+        let num_assignments = 1u32 << nvars;
+
+        // Dyadic weights such that the results are exact
+        let weights = |level: LevelNo| (0.25 * (level + 1) as f64, 0.5f64.powi(level as i32 + 1));
+        let mut cache: WeightedSatCountCache<f64, BuildHasherDefault<FxHasher>> =
+            WeightedSatCountCache::default();
+        let mut log_cache: WeightedSatCountCache<LogF64, BuildHasherDefault<FxHasher>> =
+            WeightedSatCountCache::default();
+
+        for (f_explicit, f) in self.boolean_functions.iter().enumerate() {
+            let mut expected = 0.0;
+            for assignment in 0..num_assignments {
+                if (f_explicit >> assignment) & 1 == 0 {
+                    continue;
+                }
+                let mut w = 1.0;
+                for var in 0..nvars {
+                    let (pos, neg) = weights(var);
+                    w *= if (assignment >> var) & 1 != 0 {
+                        pos
+                    } else {
+                        neg
+                    };
+                }
+                expected += w;
             }
+
+            assert_eq!(f.weighted_sat_count(weights, &mut cache), expected);
+
+            let log_weights = |level| {
+                let (pos, neg) = weights(level);
+                (LogF64::from_f64(pos), LogF64::from_f64(neg))
+            };
+            let actual = f.weighted_sat_count(log_weights, &mut log_cache).to_f64();
+            assert!((actual - expected).abs() < 1e-9, "{actual} != {expected}");
+        }
+    }
+
+    /// Test `probability()` for all Boolean functions
+    pub fn probability(&self) {
+        let nvars = self.vars.len() as u32;
+        let num_assignments = 1u32 << nvars;
+
+        // Dyadic probabilities such that the results are exact
+        let p = |level: LevelNo| [0.25, 0.5, 0.875, 0.125][level as usize % 4];
+
+        for (f_explicit, f) in self.boolean_functions.iter().enumerate() {
+            let expected: f64 = (0..num_assignments)
+                .filter(|&assignment| (f_explicit >> assignment) & 1 != 0)
+                .map(|assignment| {
+                    (0..nvars)
+                        .map(|var| {
+                            if (assignment >> var) & 1 != 0 {
+                                p(var)
+                            } else {
+                                1.0 - p(var)
+                            }
+                        })
+                        .product::<f64>()
+                })
+                .sum();
+            assert_eq!(f.probability(p), expected);
+        }
+    }
+}
+
+impl<'a, B: BooleanFunction> TestAllBooleanFunctions<'a, B> {
+    /// Test `cube()` and `clause()` for all combinations of literals
+    pub fn cube_clause(&self) {
+        let nvars = self.vars.len() as u32;
+
+        let (f, t) = self
+            .mref
+            .with_manager_shared(|manager| (B::f(manager), B::t(manager)));
+
+        // Every variable is either absent, positive, or negative
+        for combination in 0..3u32.pow(nvars) {
+            let mut literals = Vec::new();
+            let mut expected_cube = t.clone();
+            let mut expected_clause = f.clone();
+            let mut c = combination;
+            for (i, var) in self.vars.iter().enumerate() {
+                let lit = match c % 3 {
+                    0 => None,
+                    1 => Some((var.clone(), true)),
+                    _ => Some((var.not().unwrap(), false)),
+                };
+                c /= 3;
+                if let Some((lit, positive)) = lit {
+                    literals.push((i as LevelNo, positive));
+                    expected_cube = expected_cube.and(&lit).unwrap();
+                    expected_clause = expected_clause.or(&lit).unwrap();
+                }
+            }
+
+            // The order of literals and duplicates are irrelevant
+            literals.reverse();
+            if let Some(&lit) = literals.first() {
+                literals.push(lit);
+            }
+
+            self.mref.with_manager_shared(|manager| {
+                let cube = B::cube(manager, &literals).unwrap();
+                assert!(cube == expected_cube);
+                let clause = B::clause(manager, &literals).unwrap();
+                assert!(clause == expected_clause);
+
+                // Complementary literals
+                if let Some(&(level, positive)) = literals.first() {
+                    literals.push((level, !positive));
+                    assert!(B::cube(manager, &literals).unwrap() == f);
+                    assert!(B::clause(manager, &literals).unwrap() == t);
+                }
+            });
+        }
+    }
+}
+
+impl<'a, B: BooleanFunction> TestAllBooleanFunctions<'a, B>
+where
+    for<'id> INodeOfFunc<'id, B>: HasLevel,
+{
+    /// Test `support()` and `support_levels()` for all Boolean functions
+    pub fn support(&self) {
+        let nvars = self.vars.len() as u32;
+        let num_assignments = 1u32 << nvars;
+
+        let t = self.mref.with_manager_shared(|manager| B::t(manager));
+
+        for (f_explicit, f) in self.boolean_functions.iter().enumerate() {
+            let f_explicit = f_explicit as ExplicitBFunc;
+
+            let mut expected_levels = Vec::new();
+            let mut expected_cube = t.clone();
+            for (i, var) in self.vars.iter().enumerate() {
+                let depends = (0..num_assignments).any(|assignment| {
+                    let flipped = assignment ^ (1 << i);
+                    (f_explicit >> assignment) & 1 != (f_explicit >> flipped) & 1
+                });
+                if depends {
+                    expected_levels.push(i as LevelNo);
+                    expected_cube = expected_cube.and(var).unwrap();
+                }
+            }
+
+            let levels: Vec<LevelNo> = f.support_levels().collect();
+            assert_eq!(levels, expected_levels);
+            assert!(f.support().unwrap() == expected_cube);
+        }
+    }
+
+    /// Test `cubes()` and `assignments()` for all Boolean functions
+    pub fn cubes_assignments(&self) {
+        let nvars = self.vars.len() as u32;
+
+        for (f_explicit, f) in self.boolean_functions.iter().enumerate() {
+            let f_explicit = f_explicit as ExplicitBFunc;
+
+            // The cubes must be disjoint and cover exactly the satisfying
+            // assignments
+            let mut covered: ExplicitBFunc = 0;
+            for cube in f.cubes() {
+                assert_eq!(cube.len(), nvars as usize);
+                for assignment in 0..1u32 << nvars {
+                    let matches = cube.iter().enumerate().all(|(i, &c)| {
+                        c == OptBool::None || (c == OptBool::True) == ((assignment >> i) & 1 != 0)
+                    });
+                    if matches {
+                        assert_eq!(covered & (1 << assignment), 0);
+                        covered |= 1 << assignment;
+                    }
+                }
+            }
+            assert_eq!(covered, f_explicit);
+
+            let mut covered: ExplicitBFunc = 0;
+            for assignment in f.assignments(self.var_handles) {
+                assert_eq!(assignment.len(), nvars as usize);
+                let assignment = (0..nvars)
+                    .filter(|&i| assignment[i as usize])
+                    .fold(0, |acc, i| acc | (1 << i));
+                assert_eq!(covered & (1 << assignment), 0);
+                covered |= 1 << assignment;
+            }
+            assert_eq!(covered, f_explicit);
+        }
+    }
+
+    /// Test `min_cost_sat()` for all Boolean functions
+    pub fn min_cost_sat(&self) {
+        let nvars = self.vars.len() as u32;
+        let costs = |level: LevelNo| (3 * level + 2, 4 - level);
+        let assignment_cost = |assignment: u32| {
+            (0..nvars)
+                .map(|var| {
+                    let (pos, neg) = costs(var);
+                    if (assignment >> var) & 1 != 0 {
+                        pos
+                    } else {
+                        neg
+                    }
+                })
+                .sum::<u32>()
+        };
+
+        for (f_explicit, f) in self.boolean_functions.iter().enumerate() {
+            let f_explicit = f_explicit as ExplicitBFunc;
+            let expected = (0..1u32 << nvars)
+                .filter(|&assignment| (f_explicit >> assignment) & 1 != 0)
+                .map(assignment_cost)
+                .min();
+
+            let Some((assignment, cost)) = f.min_cost_sat(costs) else {
+                assert_eq!(expected, None);
+                continue;
+            };
+            assert_eq!(Some(cost), expected);
+            assert_eq!(assignment.len(), nvars as usize);
+            let assignment = (0..nvars)
+                .filter(|&i| assignment[i as usize])
+                .fold(0, |acc, i| acc | (1 << i));
+            assert_ne!(f_explicit & (1 << assignment), 0);
+            assert_eq!(assignment_cost(assignment), cost);
+        }
+    }
+
+    /// Test `sample()` and `sample_n()` by comparing the relative frequencies
+    /// of the assignments with their probabilities
+    pub fn sample(&self) {
+        use rand::rngs::SmallRng;
+        use rand::SeedableRng;
+
+        const N: usize = 2000;
+        let nvars = self.vars.len() as u32;
+        let weights = |level: LevelNo| [(1.0, 3.0), (2.0, 1.0), (1.0, 1.0)][level as usize % 3];
+        let assignment_weight = |assignment: u32| {
+            (0..nvars)
+                .map(|var| {
+                    let (pos, neg) = weights(var);
+                    if (assignment >> var) & 1 != 0 {
+                        pos
+                    } else {
+                        neg
+                    }
+                })
+                .product::<f64>()
+        };
+
+        for (f_explicit, f) in self.boolean_functions.iter().enumerate() {
+            let f_explicit = f_explicit as ExplicitBFunc;
+            let mut rng = SmallRng::seed_from_u64(42);
+            let samples = f.sample_n(&mut rng, weights, N);
+            if f_explicit == 0 {
+                assert!(samples.is_empty());
+                assert_eq!(f.sample(&mut rng, weights), None);
+                continue;
+            }
+            // reproducible
+            let mut rng = SmallRng::seed_from_u64(42);
+            assert_eq!(f.sample_n(&mut rng, weights, N), samples);
+            assert!(f.sample(&mut rng, weights).is_some());
+
+            let mut counts = vec![0usize; 1 << nvars];
+            for sample in &samples {
+                assert_eq!(sample.len(), nvars as usize);
+                let assignment = (0..nvars)
+                    .filter(|&i| sample[i as usize])
+                    .fold(0, |acc, i| acc | (1 << i));
+                counts[assignment as usize] += 1;
+            }
+            let total: f64 = (0..1u32 << nvars)
+                .filter(|&a| (f_explicit >> a) & 1 != 0)
+                .map(assignment_weight)
+                .sum();
+            for (assignment, &count) in counts.iter().enumerate() {
+                let assignment = assignment as u32;
+                let expected = if (f_explicit >> assignment) & 1 != 0 {
+                    assignment_weight(assignment) / total
+                } else {
+                    0.0
+                };
+                let actual = count as f64 / N as f64;
+                assert!((actual - expected).abs() < 0.05, "{actual} vs. {expected}");
+            }
+        }
+    }
+}
+
+impl<'a, B: BooleanFunctionQuant> TestAllBooleanFunctions<'a, B>
+where
+    for<'id> INodeOfFunc<'id, B>: HasLevel,
+{
+    /// Test `isop()` and `isop_cover()` for all intervals of Boolean functions
+    pub fn isop(&self) {
+        let nvars = self.vars.len() as u32;
+        let num_assignments = 1u32 << nvars;
+
+        let zmref = oxidd::zbdd::new_manager(1024, 128, 1);
+        // two levels per variable, one for each literal
+        zbdd_singletons_vars(&zmref, 2 * nvars as usize);
+
+        for (lower_explicit, lower) in self.boolean_functions.iter().enumerate() {
+            let lower_explicit = lower_explicit as ExplicitBFunc;
+            for (upper_explicit, upper) in self.boolean_functions.iter().enumerate() {
+                let upper_explicit = upper_explicit as ExplicitBFunc;
+                if lower_explicit & !upper_explicit != 0 {
+                    continue;
+                }
+
+                let (f, cubes) = B::isop(lower, upper).unwrap();
+                let f_explicit = self.dd_to_boolean_func[&f];
+                assert_eq!(lower_explicit & !f_explicit, 0);
+                assert_eq!(f_explicit & !upper_explicit, 0);
+
+                let cube_explicit = |cube: &[OptBool]| {
+                    (0..num_assignments)
+                        .filter(|&assignment| {
+                            cube.iter().enumerate().all(|(i, &c)| {
+                                c == OptBool::None
+                                    || (c == OptBool::True) == ((assignment >> i) & 1 != 0)
+                            })
+                        })
+                        .fold(0, |acc, assignment| acc | (1 << assignment))
+                };
+                let cube_functions: Vec<ExplicitBFunc> =
+                    cubes.iter().map(|cube| cube_explicit(cube)).collect();
+                let union = cube_functions.iter().fold(0, |acc, c| acc | c);
+                assert_eq!(union, f_explicit);
+                // irredundant
+                for i in 0..cube_functions.len() {
+                    let others = (0..cube_functions.len())
+                        .filter(|&j| j != i)
+                        .fold(0, |acc, j| acc | cube_functions[j]);
+                    assert_ne!(others, f_explicit);
+                }
+
+                let (f2, cover) = B::isop_cover::<ZBDDFunction>(lower, upper, &zmref).unwrap();
+                assert!(f2 == f);
+                let mut expected =
+                    zmref.with_manager_shared(|manager| ZBDDFunction::empty(manager));
+                for cube in &cubes {
+                    let elements: Vec<LevelNo> = (0..nvars)
+                        .filter(|&level| cube[level as usize] != OptBool::None)
+                        .map(|level| {
+                            2 * level + (cube[level as usize] == OptBool::False) as LevelNo
+                        })
+                        .collect();
+                    let set = zmref
+                        .with_manager_shared(|manager| ZBDDFunction::set(manager, &elements))
+                        .unwrap();
+                    expected = expected.union(&set).unwrap();
+                }
+                assert!(cover == expected);
+            }
+        }
+    }
+
+    /// Test `prime_implicants()`, `essential_literals()`, and `unateness()`
+    pub fn primes(&self) {
+        let nvars = self.vars.len() as u32;
+        let num_assignments = 1u32 << nvars;
+
+        let zmref = oxidd::zbdd::new_manager(1024, 128, 1);
+        // two levels per variable, one for each literal
+        zbdd_singletons_vars(&zmref, 2 * nvars as usize);
+
+        // All cubes as lists of literals along with their explicit functions
+        let mut cubes: Vec<(Vec<(u32, bool)>, ExplicitBFunc)> = Vec::new();
+        for code in 0..3u32.pow(nvars) {
+            let literals: Vec<(u32, bool)> = (0..nvars)
+                .filter_map(|i| match (code / 3u32.pow(i)) % 3 {
+                    0 => None,
+                    c => Some((i, c == 1)),
+                })
+                .collect();
+            let explicit = (0..num_assignments)
+                .filter(|&a| literals.iter().all(|&(i, v)| ((a >> i) & 1 != 0) == v))
+                .fold(0, |acc, a| acc | (1 << a));
+            cubes.push((literals, explicit));
+        }
+
+        for (f_explicit, f) in self.boolean_functions.iter().enumerate() {
+            let f_explicit = f_explicit as ExplicitBFunc;
+            let implies = |c: ExplicitBFunc| c & !f_explicit == 0;
+
+            let mut expected = zmref.with_manager_shared(|manager| ZBDDFunction::empty(manager));
+            for (literals, explicit) in &cubes {
+                if !implies(*explicit) {
+                    continue;
+                }
+                let prime = cubes.iter().all(|(other, other_explicit)| {
+                    other.len() + 1 != literals.len()
+                        || !other.iter().all(|l| literals.contains(l))
+                        || !implies(*other_explicit)
+                });
+                if !prime {
+                    continue;
+                }
+                let elements: Vec<LevelNo> = literals
+                    .iter()
+                    .map(|&(i, v)| 2 * i + !v as LevelNo)
+                    .collect();
+                let set = zmref
+                    .with_manager_shared(|manager| ZBDDFunction::set(manager, &elements))
+                    .unwrap();
+                expected = expected.union(&set).unwrap();
+            }
+            let primes = f.prime_implicants::<ZBDDFunction>(&zmref).unwrap();
+            assert!(primes == expected);
+
+            let essential = f.essential_literals().unwrap();
+            let unateness = f.unateness().unwrap();
+            for i in 0..nvars {
+                let pos = (0..num_assignments)
+                    .filter(|&a| (a >> i) & 1 != 0)
+                    .fold(0, |acc, a| acc | (1 << a));
+                let expected = if f_explicit == 0 {
+                    OptBool::None
+                } else if f_explicit & !pos == 0 {
+                    OptBool::True
+                } else if f_explicit & pos == 0 {
+                    OptBool::False
+                } else {
+                    OptBool::None
+                };
+                assert_eq!(essential[i as usize], expected);
+
+                let (mut positive, mut negative) = (true, true);
+                for a in (0..num_assignments).filter(|&a| (a >> i) & 1 == 0) {
+                    let v0 = (f_explicit >> a) & 1 != 0;
+                    let v1 = (f_explicit >> (a | (1 << i))) & 1 != 0;
+                    positive &= !v0 || v1;
+                    negative &= !v1 || v0;
+                }
+                let expected = match (positive, negative) {
+                    (true, true) => Unateness::Independent,
+                    (true, false) => Unateness::Positive,
+                    (false, true) => Unateness::Negative,
+                    (false, false) => Unateness::Binate,
+                };
+                assert_eq!(unateness[i as usize], expected);
+            }
+        }
+    }
+
+    /// Test `boolean_difference()`, `influence()`, `sensitivity()`, and
+    /// `banzhaf_values()`
+    pub fn influence(&self) {
+        let nvars = self.vars.len() as u32;
+        let num_assignments = 1u32 << nvars;
+
+        for (f_explicit, f) in self.boolean_functions.iter().enumerate() {
+            let f_explicit = f_explicit as ExplicitBFunc;
+            let banzhaf = f.banzhaf_values().unwrap();
+            let mut sensitivity = 0.0;
+            for (i, var) in self.vars.iter().enumerate() {
+                let bit = 1u32 << i;
+                let mut diff_explicit: ExplicitBFunc = 0;
+                for a in 0..num_assignments {
+                    let v = (f_explicit >> a) & 1;
+                    let v_flipped = (f_explicit >> (a ^ bit)) & 1;
+                    diff_explicit |= (v ^ v_flipped) << a;
+                }
+
+                let diff = f.boolean_difference(var).unwrap();
+                assert_eq!(self.dd_to_boolean_func[&diff], diff_explicit);
+
+                let expected = diff_explicit.count_ones() as f64 / num_assignments as f64;
+                let influence = f.influence(var).unwrap();
+                assert!((influence - expected).abs() < 1e-9);
+                assert!((banzhaf[i] - expected).abs() < 1e-9);
+                sensitivity += expected;
+            }
+            assert!((f.sensitivity().unwrap() - sensitivity).abs() < 1e-9);
         }
     }
 }
@@ -754,8 +1330,19 @@ fn bdd_all_boolean_functions_2vars_t1() {
     let vars = bdd_vars::<BDDFunction>(&mref, 2);
     let test = TestAllBooleanFunctions::init(&mref, &vars, &vars);
     test.basic();
+    test.cube_clause();
+    test.weighted_sat_count();
+    test.probability();
+    test.cubes_assignments();
+    test.min_cost_sat();
+    test.sample();
     test.subst();
     test.quant();
+    test.care_set();
+    test.isop();
+    test.primes();
+    test.influence();
+    test.support();
 }
 
 #[test]
@@ -765,8 +1352,19 @@ fn bdd_all_boolean_functions_2vars_t2() {
     let vars = bdd_vars::<BDDFunction>(&mref, 2);
     let test = TestAllBooleanFunctions::init(&mref, &vars, &vars);
     test.basic();
+    test.cube_clause();
+    test.weighted_sat_count();
+    test.probability();
+    test.cubes_assignments();
+    test.min_cost_sat();
+    test.sample();
     test.subst();
     test.quant();
+    test.care_set();
+    test.isop();
+    test.primes();
+    test.influence();
+    test.support();
 }
 
 #[test]
@@ -776,8 +1374,19 @@ fn bcdd_all_boolean_functions_2vars_t1() {
     let vars = bdd_vars::<BCDDFunction>(&mref, 2);
     let test = TestAllBooleanFunctions::init(&mref, &vars, &vars);
     test.basic();
+    test.cube_clause();
+    test.weighted_sat_count();
+    test.probability();
+    test.cubes_assignments();
+    test.min_cost_sat();
+    test.sample();
     test.subst();
     test.quant();
+    test.care_set();
+    test.isop();
+    test.primes();
+    test.influence();
+    test.support();
 }
 
 #[test]
@@ -787,8 +1396,19 @@ fn bcdd_all_boolean_functions_2vars_t2() {
     let vars = bdd_vars::<BCDDFunction>(&mref, 2);
     let test = TestAllBooleanFunctions::init(&mref, &vars, &vars);
     test.basic();
+    test.cube_clause();
+    test.weighted_sat_count();
+    test.probability();
+    test.cubes_assignments();
+    test.min_cost_sat();
+    test.sample();
     test.subst();
     test.quant();
+    test.care_set();
+    test.isop();
+    test.primes();
+    test.influence();
+    test.support();
 }
 
 #[test]
@@ -798,6 +1418,12 @@ fn zbdd_all_boolean_functions_2vars_t1() {
     let (singletons, vars) = zbdd_singletons_vars(&mref, 2);
     let test = TestAllBooleanFunctions::init(&mref, &vars, &singletons);
     test.basic();
+    test.cube_clause();
+    test.weighted_sat_count();
+    test.probability();
+    test.cubes_assignments();
+    test.min_cost_sat();
+    test.sample();
 }
 
 #[test]
@@ -807,4 +1433,571 @@ fn zbdd_all_boolean_functions_2vars_t2() {
     let (singletons, vars) = zbdd_singletons_vars(&mref, 2);
     let test = TestAllBooleanFunctions::init(&mref, &vars, &singletons);
     test.basic();
+    test.cube_clause();
+    test.weighted_sat_count();
+    test.probability();
+    test.cubes_assignments();
+    test.min_cost_sat();
+    test.sample();
+}
+
+#[test]
+fn zbdd_support() {
+    let mref = oxidd::zbdd::new_manager(1024, 128, 1);
+    let (singletons, vars) = zbdd_singletons_vars(&mref, 3);
+
+    // {{x0}, {x1, x2}}: the support is the union of all sets
+    let x12 = vars[1].and(&vars[2]).unwrap();
+    let x12 = x12.and(&vars[0].not().unwrap()).unwrap();
+    assert!(x12.support_levels().eq([1, 2]));
+    let family = singletons[0].union(&x12).unwrap();
+    assert!(family.support_levels().eq([0, 1, 2]));
+
+    let expected = vars[0].and(&vars[1]).unwrap().and(&vars[2]).unwrap();
+    assert!(family.support().unwrap() == expected);
+
+    let empty = mref.with_manager_shared(|manager| ZBDDFunction::empty(manager));
+    assert_eq!(empty.support_levels().len(), 0);
+    let base = mref.with_manager_shared(|manager| ZBDDFunction::base(manager));
+    assert_eq!(base.support_levels().len(), 0);
+}
+
+#[test]
+fn mtbdd_support() {
+    use oxidd::mtbdd::terminal::Int64;
+    use oxidd::mtbdd::MTBDDFunction;
+    use oxidd::PseudoBooleanFunction;
+
+    let mref = oxidd::mtbdd::new_manager::<Int64>(1024, 128, 128, 1);
+    let vars: Vec<MTBDDFunction<Int64>> = mref.with_manager_exclusive(|manager| {
+        (0..3)
+            .map(|_| MTBDDFunction::new_var(manager).unwrap())
+            .collect()
+    });
+
+    let f = vars[0].add(&vars[2]).unwrap();
+    assert!(f.support_levels().eq([0, 2]));
+    let expected = vars[0].mul(&vars[2]).unwrap();
+    assert!(f.support().unwrap() == expected);
+}
+
+#[test]
+fn zbdd_family() {
+    let mref = oxidd::zbdd::new_manager(1024, 128, 1);
+    let (singletons, _) = zbdd_singletons_vars(&mref, 3);
+
+    mref.with_manager_shared(|manager| {
+        let empty = ZBDDFunction::empty(manager);
+        let base = ZBDDFunction::base(manager);
+        assert!(ZBDDFunction::family(manager, &[]).unwrap() == empty);
+        assert!(ZBDDFunction::set(manager, &[]).unwrap() == base);
+        assert!(ZBDDFunction::set(manager, &[1]).unwrap() == singletons[1]);
+
+        // As a Boolean function, {{x0, x2}} is x0 ∧ ¬x1 ∧ x2
+        let x02 = ZBDDFunction::set(manager, &[2, 0, 2]).unwrap();
+        let cube = ZBDDFunction::cube(manager, &[(0, true), (1, false), (2, true)]).unwrap();
+        assert!(x02 == cube);
+
+        let sets: [&[LevelNo]; 5] = [&[1], &[0, 2], &[], &[2, 0], &[0, 1, 2]];
+        let mut expected = empty.clone();
+        for set in sets {
+            let set = ZBDDFunction::set(manager, set).unwrap();
+            expected = expected.union(&set).unwrap();
+        }
+        assert!(ZBDDFunction::family(manager, &sets).unwrap() == expected);
+        assert!(expected.intsec(&base).unwrap() == base);
+        assert!(expected.intsec(&singletons[1]).unwrap() == singletons[1]);
+    });
+}
+
+#[test]
+fn zbdd_subset_change() {
+    let mref = oxidd::zbdd::new_manager(1024, 128, 1);
+    let (singletons, _) = zbdd_singletons_vars(&mref, 3);
+
+    mref.with_manager_shared(|manager| {
+        let family = |sets: &[&[LevelNo]]| ZBDDFunction::family(manager, sets).unwrap();
+        let f = family(&[&[0, 1], &[1], &[2]]);
+
+        assert!(f.subset0(&singletons[1]).unwrap() == family(&[&[2]]));
+        assert!(f.subset1(&singletons[1]).unwrap() == family(&[&[0, 1], &[1]]));
+        assert!(f.subset0(&singletons[0]).unwrap() == family(&[&[1], &[2]]));
+        assert!(f.subset1(&singletons[0]).unwrap() == family(&[&[0, 1]]));
+        assert!(f.change(&singletons[0]).unwrap() == family(&[&[1], &[0, 1], &[0, 2]]));
+        assert!(f.change(&singletons[2]).unwrap() == family(&[&[0, 1, 2], &[1, 2], &[]]));
+
+        // `var` above the top node and terminals
+        let x2 = &singletons[2];
+        assert!(x2.subset0(&singletons[0]).unwrap() == *x2);
+        assert!(x2.subset1(&singletons[0]).unwrap() == ZBDDFunction::empty(manager));
+        assert!(x2.change(&singletons[0]).unwrap() == family(&[&[0, 2]]));
+        let base = ZBDDFunction::base(manager);
+        assert!(base.change(&singletons[1]).unwrap() == singletons[1]);
+        assert!(base.subset0(&singletons[1]).unwrap() == base);
+    });
+}
+
+/// Check the constraint constructors against explicit evaluation
+///
+/// `eval_vars` are the functions to pass to `eval()` (the variables for BDDs
+/// and the singletons for ZBDDs)
+fn test_constraints<B: BooleanFunction>(mref: &B::ManagerRef, vars: &[B], eval_vars: &[B]) {
+    use oxidd::constraints::*;
+
+    let coefficients = [3i64, -2, 5, 1];
+    let terms: Vec<(B, i64)> = vars.iter().cloned().zip(coefficients).collect();
+    let check = |f: &B, pred: &dyn Fn(u32, i64) -> bool| {
+        for a in 0..1u32 << vars.len() {
+            let args = eval_vars
+                .iter()
+                .enumerate()
+                .map(|(i, v)| (v, (a >> i) & 1 != 0));
+            let count = a.count_ones();
+            let sum = (0..vars.len())
+                .filter(|&i| (a >> i) & 1 != 0)
+                .map(|i| coefficients[i])
+                .sum();
+            assert_eq!(f.eval(args), pred(count, sum), "assignment {a:#b}");
+        }
+    };
+
+    for k in 0..=vars.len() as u32 + 1 {
+        check(&at_most_k(mref, vars, k).unwrap(), &|c, _| c <= k);
+        check(&at_least_k(mref, vars, k).unwrap(), &|c, _| c >= k);
+        check(&exactly_k(mref, vars, k).unwrap(), &|c, _| c == k);
+    }
+    for b in -3..=10 {
+        check(&linear_le(mref, &terms, b).unwrap(), &|_, s| s <= b);
+        check(&linear_ge(mref, &terms, b).unwrap(), &|_, s| s >= b);
+        check(&linear_eq(mref, &terms, b).unwrap(), &|_, s| s == b);
+    }
+}
+
+#[test]
+fn bdd_constraints() {
+    let mref = oxidd::bdd::new_manager(1024, 128, 1);
+    let vars = bdd_vars::<BDDFunction>(&mref, 4);
+    test_constraints(&mref, &vars, &vars);
+}
+
+#[test]
+fn bcdd_constraints() {
+    let mref = oxidd::bcdd::new_manager(1024, 128, 1);
+    let vars = bdd_vars::<BCDDFunction>(&mref, 4);
+    test_constraints(&mref, &vars, &vars);
+}
+
+#[test]
+fn zbdd_constraints() {
+    let mref = oxidd::zbdd::new_manager(1024, 128, 1);
+    let (singletons, vars) = zbdd_singletons_vars(&mref, 4);
+    test_constraints(&mref, &vars, &singletons);
+}
+
+/// Check `isop()` and `isop_cover()` on functions with more variables than
+/// the exhaustive tests can cover
+fn test_isop<B: BooleanFunctionQuant>(mref: &B::ManagerRef, vars: &[B])
+where
+    for<'id> INodeOfFunc<'id, B>: HasLevel,
+{
+    use oxidd::constraints::*;
+
+    let nvars = vars.len();
+    let zmref = oxidd::zbdd::new_manager(1024, 128, 1);
+    zbdd_singletons_vars(&zmref, 2 * nvars);
+
+    let eval = |f: &B, a: u32| f.eval(vars.iter().enumerate().map(|(i, v)| (v, (a >> i) & 1 != 0)));
+    let covered = |cube: &[OptBool], a: u32| {
+        cube.iter()
+            .enumerate()
+            .all(|(i, &c)| c == OptBool::None || (c == OptBool::True) == ((a >> i) & 1 != 0))
+    };
+
+    // Check the result for the interval `[lower, upper]` and return the cubes
+    let check = |lower: &B, upper: &B| {
+        let (f, cubes) = B::isop(lower, upper).unwrap();
+        for a in 0..1u32 << nvars {
+            let value = eval(&f, a);
+            assert!(!eval(lower, a) || value, "assignment {a:#b}");
+            assert!(!value || eval(upper, a), "assignment {a:#b}");
+            let count = cubes.iter().filter(|cube| covered(cube, a)).count();
+            assert_eq!(count != 0, value, "assignment {a:#b}");
+        }
+        // irredundant: every cube covers some assignment on its own
+        for cube in &cubes {
+            let alone = (0..1u32 << nvars).any(|a| {
+                covered(cube, a) && cubes.iter().filter(|other| covered(other, a)).count() == 1
+            });
+            assert!(alone, "redundant cube {cube:?}");
+        }
+
+        let (f2, cover) = B::isop_cover::<ZBDDFunction>(lower, upper, &zmref).unwrap();
+        assert!(f2 == f);
+        let sets: Vec<Vec<LevelNo>> = cubes
+            .iter()
+            .map(|cube| {
+                (0..nvars as LevelNo)
+                    .filter(|&level| cube[level as usize] != OptBool::None)
+                    .map(|level| 2 * level + (cube[level as usize] == OptBool::False) as LevelNo)
+                    .collect()
+            })
+            .collect();
+        let sets: Vec<&[LevelNo]> = sets.iter().map(Vec::as_slice).collect();
+        let expected = zmref
+            .with_manager_shared(|manager| ZBDDFunction::family(manager, &sets))
+            .unwrap();
+        assert!(cover == expected);
+        cubes
+    };
+
+    // The only irredundant cover of the parity function consists of all
+    // minterms with an odd number of positive literals.
+    let parity = vars[1..]
+        .iter()
+        .fold(vars[0].clone(), |acc, v| acc.xor(v).unwrap());
+    let cubes = check(&parity, &parity);
+    assert_eq!(cubes.len(), 1 << (nvars - 1));
+    assert!(cubes
+        .iter()
+        .all(|cube| cube.iter().all(|&c| c != OptBool::None)));
+
+    // The majority function is covered by the conjunctions of all its
+    // minimal true sets.
+    let k = nvars.div_ceil(2) as u32;
+    let majority = at_least_k(mref, vars, k).unwrap();
+    let cubes = check(&majority, &majority);
+    let binomial = (0..k as usize).fold(1, |acc, i| acc * (nvars - i) / (i + 1));
+    assert_eq!(cubes.len(), binomial);
+    for cube in &cubes {
+        assert_eq!(
+            cube.iter().filter(|&&c| c == OptBool::True).count(),
+            k as usize
+        );
+        assert!(!cube.contains(&OptBool::False));
+    }
+
+    // Don't cares allow for a smaller cover: x0 is the only single-cube
+    // function in [x0 ∧ x1 ∧ x2, x0 ∨ x1]
+    let lower = vars[0].and(&vars[1]).unwrap().and(&vars[2]).unwrap();
+    let upper = vars[0].or(&vars[1]).unwrap();
+    let cubes = check(&lower, &upper);
+    assert_eq!(cubes.len(), 1);
+    assert_eq!(cubes[0].iter().filter(|&&c| c != OptBool::None).count(), 1);
+
+    // Intervals given by pseudo-Boolean constraints
+    let terms: Vec<(B, i64)> = vars.iter().cloned().zip([3, -2, 5, 1, -4, 2]).collect();
+    for b in -4..=7 {
+        let lower = linear_ge(mref, &terms, b + 1).unwrap();
+        let upper = linear_ge(mref, &terms, b - 1).unwrap();
+        check(&lower, &upper);
+        check(&lower.and(&parity).unwrap(), &upper);
+        check(&lower, &upper.or(&parity).unwrap());
+    }
+}
+
+#[test]
+fn bdd_isop() {
+    let mref = oxidd::bdd::new_manager(1024, 128, 1);
+    let vars = bdd_vars::<BDDFunction>(&mref, 6);
+    test_isop(&mref, &vars);
+}
+
+#[test]
+fn bcdd_isop() {
+    let mref = oxidd::bcdd::new_manager(1024, 128, 1);
+    let vars = bdd_vars::<BCDDFunction>(&mref, 6);
+    test_isop(&mref, &vars);
+}
+
+/// Check `banzhaf_values()` against `influence()` for a non-monotone function
+/// whose diagram skips levels
+fn test_banzhaf<B: BooleanFunctionQuant>(vars: &[B])
+where
+    for<'id> INodeOfFunc<'id, B>: HasLevel,
+{
+    // (x0 ⊕ x2) ∧ x4 ∨ (x1 ∧ ¬x3)
+    let f = vars[0].xor(&vars[2]).unwrap().and(&vars[4]).unwrap();
+    let f = f
+        .or(&vars[1].and(&vars[3].not().unwrap()).unwrap())
+        .unwrap();
+    let banzhaf = f.banzhaf_values().unwrap();
+    assert_eq!(banzhaf.len(), vars.len());
+    let mut sum = 0.0;
+    for (var, value) in vars.iter().zip(&banzhaf) {
+        let influence = f.influence(var).unwrap();
+        assert!((value - influence).abs() < 1e-9, "{value} vs. {influence}");
+        sum += influence;
+    }
+    assert!((f.sensitivity().unwrap() - sum).abs() < 1e-9);
+    // x5 does not occur in `f`
+    assert_eq!(banzhaf[5], 0.0);
+    // x0 matters iff x4 ∧ ¬(x1 ∧ ¬x3), which holds for 3/8 of the valuations
+    assert!((banzhaf[0] - 0.375).abs() < 1e-9);
+}
+
+#[test]
+fn bdd_banzhaf() {
+    let mref = oxidd::bdd::new_manager(1024, 128, 1);
+    test_banzhaf(&bdd_vars::<BDDFunction>(&mref, 6));
+}
+
+#[test]
+fn bcdd_banzhaf() {
+    let mref = oxidd::bcdd::new_manager(1024, 128, 1);
+    test_banzhaf(&bdd_vars::<BCDDFunction>(&mref, 6));
+}
+
+/// Check the bit-vector operations against integer arithmetic on all
+/// valuations of two 3-bit words
+fn test_bitvec<B: BooleanFunction>(mref: &B::ManagerRef) {
+    use oxidd::bitvec::BitVec;
+
+    const WIDTH: usize = 3;
+    const MASK: u64 = (1 << WIDTH) - 1;
+    let words = BitVec::<B>::new_interleaved(mref, 2, WIDTH).unwrap();
+    let (x, y) = (&words[0], &words[1]);
+    let signed = |v: u64| ((v << (64 - WIDTH)) as i64) >> (64 - WIDTH);
+
+    let c = BitVec::<B>::constant(mref, WIDTH, 5);
+    type Op<T> = Box<dyn Fn(u64, u64) -> T>;
+    let results: Vec<(BitVec<B>, Op<u64>)> = vec![
+        (x.add(y).unwrap(), Box::new(|a, b| a + b)),
+        (x.sub(y).unwrap(), Box::new(|a, b| a.wrapping_sub(b))),
+        (x.mul(y).unwrap(), Box::new(|a, b| a * b)),
+        (x.neg().unwrap(), Box::new(|a, _| a.wrapping_neg())),
+        (x.xor(&c).unwrap(), Box::new(|a, _| a ^ 5)),
+        (x.and(y).unwrap(), Box::new(|a, b| a & b)),
+        (x.or(y).unwrap(), Box::new(|a, b| a | b)),
+        (x.shl(1), Box::new(|a, _| a << 1)),
+        (x.lshr(2), Box::new(|a, _| a >> 2)),
+        (x.ashr(1), Box::new(move |a, _| (signed(a) >> 1) as u64)),
+    ];
+    let cond = x.ult(y).unwrap();
+    let mux = BitVec::mux(&cond, x, y).unwrap();
+    let predicates: Vec<(B, Op<bool>)> = vec![
+        (x.equals(y).unwrap(), Box::new(|a, b| a == b)),
+        (cond, Box::new(|a, b| a < b)),
+        (x.ule(y).unwrap(), Box::new(|a, b| a <= b)),
+        (
+            x.slt(y).unwrap(),
+            Box::new(move |a, b| signed(a) < signed(b)),
+        ),
+        (
+            x.sle(y).unwrap(),
+            Box::new(move |a, b| signed(a) <= signed(b)),
+        ),
+    ];
+
+    for a in 0..=MASK {
+        for b in 0..=MASK {
+            let args: Vec<(&B, bool)> = (0..WIDTH)
+                .flat_map(|i| {
+                    [
+                        (&x.bits()[i], (a >> i) & 1 != 0),
+                        (&y.bits()[i], (b >> i) & 1 != 0),
+                    ]
+                })
+                .collect();
+            let value = |word: &BitVec<B>| {
+                (0..WIDTH).fold(0, |acc, i| {
+                    acc | ((word.bits()[i].eval(args.iter().cloned()) as u64) << i)
+                })
+            };
+            for (word, expected) in &results {
+                assert_eq!(value(word), expected(a, b) & MASK, "a = {a}, b = {b}");
+            }
+            for (f, expected) in &predicates {
+                assert_eq!(
+                    f.eval(args.iter().cloned()),
+                    expected(a, b),
+                    "a = {a}, b = {b}"
+                );
+            }
+            assert_eq!(value(&mux), a.min(b));
+        }
+    }
+}
+
+#[test]
+fn bdd_bitvec() {
+    test_bitvec::<BDDFunction>(&oxidd::bdd::new_manager(1024, 128, 1));
+}
+
+#[test]
+fn bcdd_bitvec() {
+    test_bitvec::<BCDDFunction>(&oxidd::bcdd::new_manager(1024, 128, 1));
+}
+
+fn test_fdd<B: BooleanFunction>(mref: &B::ManagerRef)
+where
+    for<'id> INodeOfFunc<'id, B>: HasLevel,
+{
+    use oxidd::fdd::FddVar;
+
+    let vars = FddVar::<B>::new_interleaved(mref, 2, 5).unwrap();
+    let (x, y) = (&vars[0], &vars[1]);
+    assert_eq!(x.bits().width(), 3);
+    assert_eq!(FddVar::<B>::new(mref, 1).unwrap().bits().width(), 1);
+    assert_eq!(FddVar::<B>::new(mref, 8).unwrap().bits().width(), 3);
+
+    let domain = x.domain().unwrap();
+    let equals = x.equals(y).unwrap();
+    let range = x.in_range(1..4).unwrap();
+    let range_incl = x.in_range(3..=10).unwrap();
+    let empty = x.in_range(3..3).unwrap();
+    let ithvars: Vec<B> = (0..5).map(|v| x.ithvar(v).unwrap()).collect();
+    for a in 0..8u64 {
+        for b in 0..8u64 {
+            let args: Vec<(&B, bool)> = (0..3)
+                .flat_map(|i| {
+                    [
+                        (&x.bits().bits()[i], (a >> i) & 1 != 0),
+                        (&y.bits().bits()[i], (b >> i) & 1 != 0),
+                    ]
+                })
+                .collect();
+            let eval = |f: &B| f.eval(args.iter().cloned());
+            assert_eq!(eval(&domain), a < 5);
+            assert_eq!(eval(&equals), a == b);
+            assert_eq!(eval(&range), (1..4).contains(&a));
+            assert_eq!(eval(&range_incl), a >= 3);
+            assert!(!eval(&empty));
+            for (v, f) in ithvars.iter().enumerate() {
+                assert_eq!(eval(f), a == v as u64);
+            }
+        }
+    }
+
+    for v in 0..5 {
+        let f = x.ithvar(v).unwrap().and(&y.ithvar(4 - v).unwrap()).unwrap();
+        let cube = f.pick_cube([], |_, _| false).unwrap();
+        assert_eq!(x.decode(&cube), v);
+        assert_eq!(y.decode(&cube), 4 - v);
+    }
+}
+
+#[test]
+fn bdd_fdd() {
+    test_fdd::<BDDFunction>(&oxidd::bdd::new_manager(1024, 128, 1));
+}
+
+#[test]
+fn bcdd_fdd() {
+    test_fdd::<BCDDFunction>(&oxidd::bcdd::new_manager(1024, 128, 1));
+}
+
+/// 5-bit counter with an enable input, compared against the monolithic
+/// transition relation
+fn test_image<B: BooleanFunctionQuant + FunctionSubst>(mref: &B::ManagerRef)
+where
+    for<'id> INodeOfFunc<'id, B>: HasLevel,
+{
+    use oxidd::image::TransitionRelation;
+
+    const BITS: usize = 5;
+    const MAX: usize = (1 << BITS) - 1;
+    // interleaved current-state and next-state variables, input at the bottom
+    let vars = bdd_vars::<B>(mref, 2 * BITS + 1);
+    let current: Vec<B> = (0..BITS).map(|i| vars[2 * i].clone()).collect();
+    let next: Vec<B> = (0..BITS).map(|i| vars[2 * i + 1].clone()).collect();
+    let enable = &vars[2 * BITS];
+
+    // y_i ↔ x_i ⊕ (enable ∧ x_0 ∧ … ∧ x_{i-1})
+    let mut carry = enable.clone();
+    let mut conjuncts = Vec::new();
+    for i in 0..BITS {
+        let value = current[i].xor(&carry).unwrap();
+        conjuncts.push(next[i].equiv(&value).unwrap());
+        carry = carry.and(&current[i]).unwrap();
+    }
+
+    let state = |value: usize| {
+        let mut f = mref.with_manager_shared(|manager| B::t(manager));
+        for (i, var) in current.iter().enumerate() {
+            let lit = if (value >> i) & 1 != 0 {
+                var.clone()
+            } else {
+                var.not().unwrap()
+            };
+            f = f.and(&lit).unwrap();
+        }
+        f
+    };
+    let states = |values: &[usize]| {
+        let mut f = mref.with_manager_shared(|manager| B::f(manager));
+        for &v in values {
+            f = f.or(&state(v)).unwrap();
+        }
+        f
+    };
+
+    let monolithic = conjuncts.iter().fold(
+        mref.with_manager_shared(|manager| B::t(manager)),
+        |acc, c| acc.and(c).unwrap(),
+    );
+    let cube = |vars: &[&B]| {
+        vars.iter().fold(
+            mref.with_manager_shared(|manager| B::t(manager)),
+            |acc, v| acc.and(v).unwrap(),
+        )
+    };
+    let mut quant_image: Vec<&B> = current.iter().collect();
+    quant_image.push(enable);
+    let quant_image = cube(&quant_image);
+    let mut quant_preimage: Vec<&B> = next.iter().collect();
+    quant_preimage.push(enable);
+    let quant_preimage = cube(&quant_preimage);
+
+    let default_size = TransitionRelation::<B>::DEFAULT_CLUSTER_SIZE;
+    for max_cluster_size in [0, 15, 30, default_size] {
+        let rel =
+            TransitionRelation::with_cluster_size(&conjuncts, &current, &next, max_cluster_size)
+                .unwrap();
+        // With the intermediate thresholds, some but not all conjuncts are
+        // combined into clusters
+        let clusters = rel.num_image_clusters();
+        if max_cluster_size == 0 {
+            assert_eq!(clusters, BITS);
+        } else if max_cluster_size == default_size {
+            assert_eq!(clusters, 1);
+        } else {
+            assert!(1 < clusters && clusters < BITS);
+        }
+
+        assert!(rel.image(&state(3)).unwrap() == states(&[3, 4]));
+        assert!(rel.image(&state(MAX)).unwrap() == states(&[MAX, 0]));
+        assert!(rel.preimage(&state(0)).unwrap() == states(&[0, MAX]));
+        assert!(rel.preimage(&states(&[2, 5])).unwrap() == states(&[1, 2, 4, 5]));
+
+        for s in [
+            states(&[0, 6]),
+            states(&[1, 2, 3, 17]),
+            state(5),
+            state(MAX),
+        ] {
+            let expected = s
+                .apply_exist(BooleanOperator::And, &monolithic, &quant_image)
+                .unwrap()
+                .substitute(oxidd_core::util::Subst::new(&next, &current))
+                .unwrap();
+            assert!(rel.image(&s).unwrap() == expected);
+
+            let expected = s
+                .substitute(oxidd_core::util::Subst::new(&current, &next))
+                .unwrap()
+                .apply_exist(BooleanOperator::And, &monolithic, &quant_preimage)
+                .unwrap();
+            assert!(rel.preimage(&s).unwrap() == expected);
+        }
+    }
+}
+
+#[test]
+fn bdd_image() {
+    test_image::<BDDFunction>(&oxidd::bdd::new_manager(1024, 128, 1));
+}
+
+#[test]
+fn bcdd_image() {
+    test_image::<BCDDFunction>(&oxidd::bcdd::new_manager(1024, 128, 1));
 }
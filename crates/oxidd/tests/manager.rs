@@ -0,0 +1,39 @@
+//! Tests for the manager implementations
+
+#![cfg_attr(miri, allow(unused))]
+
+use oxidd::bdd::BDDFunction;
+use oxidd::BooleanFunction;
+use oxidd::ManagerRef;
+
+// spell-checker:ignore mref
+
+/// A manager operation that does not create any nodes must not leave the
+/// thread-local node store state attached. Otherwise, the slots reserved in
+/// later operations on this thread are never given back.
+#[test]
+#[cfg_attr(miri, ignore)]
+fn local_store_state_detached() {
+    const CHUNK_SIZE: usize = 64 * 1024;
+    let mref = oxidd::bdd::new_manager(CHUNK_SIZE + 1024, 1024, 1);
+
+    mref.with_manager_shared(|_| {});
+    let x = mref.with_manager_exclusive(|manager| BDDFunction::new_var(manager).unwrap());
+
+    // The previous operation reserved an entire chunk of slots for the current
+    // thread. Hence, the other thread can only create the nodes below if the
+    // unused slots have been given back.
+    let vars = std::thread::scope(|s| {
+        s.spawn(|| {
+            mref.with_manager_exclusive(|manager| {
+                (0..2048)
+                    .map(|_| BDDFunction::new_var(manager))
+                    .collect::<Result<Vec<_>, _>>()
+            })
+        })
+        .join()
+        .unwrap()
+    });
+    assert!(vars.is_ok());
+    drop((x, vars));
+}
@@ -0,0 +1,498 @@
+//! Tests for the reordering heuristics
+
+#![cfg_attr(miri, allow(unused))]
+
+use oxidd::bcdd::BCDDFunction;
+use oxidd::bcdd::BCDDManagerRef;
+use oxidd::bdd::BDDFunction;
+use oxidd::bdd::BDDManagerRef;
+use oxidd::util::AutoReorder;
+use oxidd::util::OptBool;
+use oxidd::zbdd::ZBDDFunction;
+use oxidd::zbdd::ZBDDManagerRef;
+use oxidd::BooleanFunction;
+use oxidd::BooleanFunctionQuant;
+use oxidd::BooleanVecSet;
+use oxidd::Function;
+use oxidd::LevelNo;
+use oxidd::Manager;
+use oxidd::ManagerRef;
+use oxidd_core::HasLevel;
+use oxidd_reorder::SiftConfig;
+
+// spell-checker:ignore mref
+
+/// Truth table of `f` over `vars`
+fn truth_table<B: BooleanFunction>(f: &B, vars: &[B]) -> Vec<bool> {
+    (0..1u32 << vars.len())
+        .map(|assignment| {
+            f.eval(
+                vars.iter()
+                    .enumerate()
+                    .map(|(i, x)| (x, assignment & (1 << i) != 0)),
+            )
+        })
+        .collect()
+}
+
+/// Level of the variable `x`
+fn level<F: Function>(x: &F) -> LevelNo
+where
+    for<'id> <F::Manager<'id> as Manager>::InnerNode: HasLevel,
+{
+    x.with_manager_shared(|manager, edge| manager.get_node(edge).unwrap_inner().level())
+}
+
+/// New BDD manager with `n` variables
+fn bdd_vars(n: usize) -> (BDDManagerRef, Vec<BDDFunction>) {
+    let mref = oxidd::bdd::new_manager(65536, 1024, 2);
+    let vars = mref.with_manager_exclusive(|manager| {
+        (0..n)
+            .map(|_| BDDFunction::new_var(manager).unwrap())
+            .collect()
+    });
+    (mref, vars)
+}
+
+/// New BCDD manager with `n` variables
+fn bcdd_vars(n: usize) -> (BCDDManagerRef, Vec<BCDDFunction>) {
+    let mref = oxidd::bcdd::new_manager(65536, 1024, 2);
+    let vars = mref.with_manager_exclusive(|manager| {
+        (0..n)
+            .map(|_| BCDDFunction::new_var(manager).unwrap())
+            .collect()
+    });
+    (mref, vars)
+}
+
+/// New ZBDD manager with `n` singleton sets
+fn zbdd_singletons(n: usize) -> (ZBDDManagerRef, Vec<ZBDDFunction>) {
+    let mref = oxidd::zbdd::new_manager(65536, 1024, 2);
+    let singletons = mref.with_manager_exclusive(|manager| {
+        (0..n)
+            .map(|_| ZBDDFunction::new_singleton(manager).unwrap())
+            .collect()
+    });
+    (mref, singletons)
+}
+
+/// `(x0 ∧ x_n) ∨ (x1 ∧ x_{n+1}) ∨ … ∨ (x_{n-1} ∧ x_{2n-1})`, which has
+/// exponential size in the initial order but linear size in the interleaved
+/// order
+fn pairs<B: BooleanFunction>(vars: &[B]) -> B {
+    let n = vars.len() / 2;
+    let mut f = vars[0].and(&vars[n]).unwrap();
+    for i in 1..n {
+        f = f.or(&vars[i].and(&vars[n + i]).unwrap()).unwrap();
+    }
+    f
+}
+
+#[test]
+fn bdd_set_var_order() {
+    let (mref, vars) = bdd_vars(6);
+    let f = pairs(&vars);
+
+    let before = f.node_count();
+    let table = truth_table(&f, &vars);
+
+    let interleaved: Vec<BDDFunction> = (0..3)
+        .flat_map(|i| [vars[i].clone(), vars[3 + i].clone()])
+        .collect();
+    mref.with_manager_exclusive(|manager| oxidd_reorder::set_var_order(manager, &interleaved));
+    assert!(f.node_count() < before);
+    assert_eq!(truth_table(&f, &vars), table);
+
+    let reversed: Vec<BDDFunction> = vars.iter().rev().cloned().collect();
+    mref.with_manager_exclusive(|manager| oxidd_reorder::set_var_order(manager, &reversed));
+    assert_eq!(truth_table(&f, &vars), table);
+}
+
+#[test]
+fn bdd_sift() {
+    let (mref, vars) = bdd_vars(10);
+    let f = pairs(&vars);
+
+    let before = f.node_count();
+    let table = truth_table(&f, &vars);
+
+    mref.with_manager_exclusive(|manager| {
+        oxidd_reorder::sift(manager, &SiftConfig::default());
+    });
+
+    assert!(f.node_count() < before);
+    assert_eq!(truth_table(&f, &vars), table);
+}
+
+#[test]
+fn bcdd_sift() {
+    let (mref, vars) = bcdd_vars(10);
+    let f = pairs(&vars);
+
+    let before = f.node_count();
+    let table = truth_table(&f, &vars);
+    let g = f.not().unwrap();
+
+    mref.with_manager_exclusive(|manager| {
+        oxidd_reorder::sift(manager, &SiftConfig::default());
+    });
+
+    assert!(f.node_count() < before);
+    assert_eq!(truth_table(&f, &vars), table);
+    assert!(g == f.not().unwrap());
+}
+
+#[test]
+fn bdd_window_permutation() {
+    let (mref, vars) = bdd_vars(8);
+    let f = pairs(&vars);
+
+    let before = f.node_count();
+    let table = truth_table(&f, &vars);
+
+    for window in 2..=4 {
+        mref.with_manager_exclusive(|manager| {
+            oxidd_reorder::window_permutation(manager, window, true);
+        });
+        assert_eq!(truth_table(&f, &vars), table);
+    }
+    assert!(f.node_count() < before);
+}
+
+#[test]
+fn bdd_var_groups() {
+    let (mref, vars) = bdd_vars(8);
+    mref.with_manager_exclusive(|manager| {
+        manager.var_groups_mut().unwrap().add(0..2);
+        manager.var_groups_mut().unwrap().add(4..7);
+    });
+    let f = pairs(&vars);
+    let table = truth_table(&f, &vars);
+
+    let check_groups = || {
+        assert_eq!(level(&vars[0]) + 1, level(&vars[1]));
+        assert_eq!(level(&vars[4]) + 1, level(&vars[5]));
+        assert_eq!(level(&vars[5]) + 1, level(&vars[6]));
+        mref.with_manager_shared(|manager| {
+            let groups: Vec<_> = manager.var_groups().iter().collect();
+            let l0 = level(&vars[0]);
+            let l4 = level(&vars[4]);
+            assert!(groups.contains(&(l0..l0 + 2)));
+            assert!(groups.contains(&(l4..l4 + 3)));
+            assert_eq!(groups.len(), 2);
+        });
+        assert_eq!(truth_table(&f, &vars), table);
+    };
+
+    mref.with_manager_exclusive(|manager| {
+        oxidd_reorder::set_var_order_seq(manager, &[vars[4].clone(), vars[1].clone()]);
+    });
+    check_groups();
+    assert!(level(&vars[6]) < level(&vars[0]));
+
+    mref.with_manager_exclusive(|manager| {
+        oxidd_reorder::sift(manager, &SiftConfig::default());
+    });
+    check_groups();
+
+    mref.with_manager_exclusive(|manager| {
+        oxidd_reorder::window_permutation(manager, 3, true);
+    });
+    check_groups();
+}
+
+#[test]
+fn bdd_auto_reorder() {
+    let (mref, vars) = bdd_vars(16);
+    mref.with_manager_exclusive(|manager| {
+        manager.set_auto_reorder(Some(AutoReorder {
+            threshold: 512,
+            ..AutoReorder::new(|manager| oxidd_reorder::sift(manager, &SiftConfig::default()))
+        }))
+    });
+    let f = pairs(&vars);
+
+    assert!(mref.with_manager_shared(|manager| manager.reorder_count()) > 0);
+    assert!(f.node_count() < 128);
+
+    // Compare to a manager without automatic reordering
+    let (mref2, vars2) = bdd_vars(16);
+    let g = pairs(&vars2);
+    assert_eq!(truth_table(&f, &vars), truth_table(&g, &vars2));
+    assert_eq!(
+        mref2.with_manager_shared(|manager| manager.reorder_count()),
+        0
+    );
+}
+
+/// Operations must not fail spuriously if a reordering is requested while
+/// they are running, but restart (or, for the constructors taking a manager,
+/// complete without aborting)
+#[test]
+fn bdd_auto_reorder_restart() {
+    use oxidd::image::TransitionRelation;
+
+    let (mref, vars) = bdd_vars(16);
+    let enable_auto_reorder = || {
+        mref.with_manager_exclusive(|manager| {
+            manager.set_auto_reorder(Some(AutoReorder {
+                threshold: 1,
+                ..AutoReorder::new(|manager| oxidd_reorder::sift(manager, &SiftConfig::default()))
+            }))
+        })
+    };
+
+    // Cube and clause inside a manager closure with a pending reordering
+    enable_auto_reorder();
+    let (cube, clause) = mref.with_manager_shared(|manager| {
+        let x = vars[0].as_edge(manager);
+        let y = vars[8].as_edge(manager);
+        // Creating a node exceeds the threshold and requests a reordering
+        if let Ok(e) = BDDFunction::and_edge(manager, x, y) {
+            manager.drop_edge(e);
+        }
+        assert!(manager.reorder_requested());
+        assert!(BDDFunction::or_edge(manager, x, y).is_err());
+        (
+            BDDFunction::cube(manager, &[(0, true), (8, false)]).unwrap(),
+            BDDFunction::clause(manager, &[(0, false), (8, true)]).unwrap(),
+        )
+    });
+    assert!(cube == vars[0].and(&vars[8].not().unwrap()).unwrap());
+    assert!(clause == cube.not().unwrap());
+    assert!(mref.with_manager_shared(|manager| manager.reorder_count()) > 0);
+
+    // ISOP of a function with exponential size in the initial order
+    mref.with_manager_exclusive(|manager| {
+        manager.set_auto_reorder(None);
+        oxidd_reorder::set_var_order(manager, &vars);
+    });
+    let f = pairs(&vars);
+    let count = mref.with_manager_shared(|manager| manager.reorder_count());
+    enable_auto_reorder();
+    let (g, cubes) = BDDFunction::isop(&f, &f).unwrap();
+    assert!(mref.with_manager_shared(|manager| manager.reorder_count()) > count);
+    assert!(g == f);
+    // The cover consists of the terms `x_i ∧ x_{8+i}`
+    assert_eq!(cubes.len(), 8);
+    for cube in cubes {
+        let literals: Vec<usize> = vars
+            .iter()
+            .enumerate()
+            .filter(|&(_, x)| cube[level(x) as usize] == OptBool::True)
+            .map(|(i, _)| i)
+            .collect();
+        assert_eq!(literals.len(), 2);
+        assert_eq!(literals[0] + 8, literals[1]);
+        assert_eq!(cube.iter().filter(|&&l| l != OptBool::None).count(), 2);
+    }
+
+    // Image computation for the transition relation `y_i ↔ x_i ⊕ x_{i+1}`
+    // (indices modulo 8) with the next-state variables below all current-state
+    // variables
+    mref.with_manager_exclusive(|manager| {
+        manager.set_auto_reorder(None);
+        oxidd_reorder::set_var_order(manager, &vars);
+    });
+    let (current, next) = vars.split_at(8);
+    let conjuncts: Vec<BDDFunction> = (0..8)
+        .map(|i| {
+            let value = current[i].xor(&current[(i + 1) % 8]).unwrap();
+            next[i].equiv(&value).unwrap()
+        })
+        .collect();
+    let count = mref.with_manager_shared(|manager| manager.reorder_count());
+    enable_auto_reorder();
+    let rel = TransitionRelation::new(&conjuncts, current, next).unwrap();
+    // The successor of `x_0 ∧ ¬x_1 ∧ … ∧ ¬x_7` is `x_0 ∧ ¬x_1 ∧ … ∧ x_7`
+    let state = |ones: &[usize]| {
+        let mut f = mref.with_manager_shared(|manager| BDDFunction::t(manager));
+        for (i, x) in current.iter().enumerate() {
+            let lit = if ones.contains(&i) {
+                x.clone()
+            } else {
+                x.not().unwrap()
+            };
+            f = f.and(&lit).unwrap();
+        }
+        f
+    };
+    assert!(rel.image(&state(&[0])).unwrap() == state(&[0, 7]));
+    assert!(
+        rel.preimage(&state(&[0, 7])).unwrap()
+            == state(&[0]).or(&state(&[1, 2, 3, 4, 5, 6, 7])).unwrap()
+    );
+    assert!(mref.with_manager_shared(|manager| manager.reorder_count()) > count);
+}
+
+/// Like the cube and clause constructors in [`bdd_auto_reorder_restart`],
+/// `make_node()` only receives a manager and must not abort
+#[test]
+fn zbdd_auto_reorder_make_node() {
+    let (mref, singletons) = zbdd_singletons(4);
+    mref.with_manager_exclusive(|manager| {
+        manager.set_auto_reorder(Some(AutoReorder {
+            threshold: 1,
+            ..AutoReorder::new(|manager| oxidd_reorder::sift(manager, &SiftConfig::default()))
+        }))
+    });
+
+    let set = mref.with_manager_shared(|manager| {
+        let x = singletons[0].as_edge(manager);
+        let y = singletons[1].as_edge(manager);
+        // Creating a node exceeds the threshold and requests a reordering
+        if let Ok(e) = ZBDDFunction::union_edge(manager, x, y) {
+            manager.drop_edge(e);
+        }
+        assert!(manager.reorder_requested());
+        let z = singletons[2].as_edge(manager);
+        assert!(ZBDDFunction::union_edge(manager, x, z).is_err());
+        // `{{x0}, {x1}}`
+        let hi = ZBDDFunction::base_edge(manager);
+        let edge = oxidd::zbdd::make_node(manager, x, hi, manager.clone_edge(y)).unwrap();
+        ZBDDFunction::from_edge(manager, edge)
+    });
+    assert!(set == singletons[0].union(&singletons[1]).unwrap());
+    // The next operation that can be restarted performs the reordering
+    let _ = singletons[2].union(&singletons[3]).unwrap();
+    assert!(mref.with_manager_shared(|manager| manager.reorder_count()) > 0);
+}
+
+#[test]
+fn bdd_exact() {
+    let (mref, vars) = bdd_vars(8);
+    let f = pairs(&vars);
+    let table = truth_table(&f, &vars);
+
+    mref.with_manager_exclusive(|manager| {
+        oxidd_reorder::window_permutation(manager, 2, false);
+    });
+    let heuristic = f.node_count();
+
+    mref.with_manager_exclusive(|manager| oxidd_reorder::exact(manager));
+
+    // The interleaved order has one node per variable plus the two terminals
+    assert_eq!(f.node_count(), 8 + 2);
+    assert!(f.node_count() <= heuristic);
+    assert_eq!(truth_table(&f, &vars), table);
+}
+
+#[test]
+fn bcdd_exact() {
+    let (mref, vars) = bcdd_vars(8);
+    mref.with_manager_exclusive(|manager| {
+        manager.var_groups_mut().unwrap().add(2..4);
+    });
+    let f = pairs(&vars);
+    let table = truth_table(&f, &vars);
+
+    mref.with_manager_exclusive(|manager| oxidd_reorder::exact(manager));
+
+    assert_eq!(level(&vars[2]) + 1, level(&vars[3]));
+    assert_eq!(truth_table(&f, &vars), table);
+    // Sifting cannot beat the exact result
+    let size = f.node_count();
+    mref.with_manager_exclusive(|manager| {
+        oxidd_reorder::sift(manager, &SiftConfig::default());
+    });
+    assert!(f.node_count() >= size);
+}
+
+#[test]
+fn bdd_set_var_order_groups() {
+    let (mref, vars) = bdd_vars(16);
+    mref.with_manager_exclusive(|manager| {
+        manager.var_groups_mut().unwrap().add(2..4);
+        manager.var_groups_mut().unwrap().add(9..12);
+    });
+    let f = pairs(&vars);
+    let table = truth_table(&f, &vars);
+
+    // Interleave the pairs in reverse order
+    let order: Vec<BDDFunction> = (0..8)
+        .rev()
+        .flat_map(|i| [vars[i].clone(), vars[8 + i].clone()])
+        .collect();
+    mref.with_manager_exclusive(|manager| oxidd_reorder::set_var_order(manager, &order));
+
+    assert_eq!(level(&vars[2]) + 1, level(&vars[3]));
+    assert_eq!(level(&vars[9]) + 1, level(&vars[10]));
+    assert_eq!(level(&vars[10]) + 1, level(&vars[11]));
+    assert!(level(&vars[7]) < level(&vars[15]));
+    assert!(level(&vars[15]) < level(&vars[6]));
+    assert!(level(&vars[1]) < level(&vars[0]));
+    assert_eq!(truth_table(&f, &vars), table);
+}
+
+#[test]
+fn zbdd_reorder() {
+    // Create all singletons first such that the variables' Boolean functions
+    // refer to all levels
+    let (mref, singletons) = zbdd_singletons(8);
+    let vars: Vec<ZBDDFunction> = mref.with_manager_shared(|manager| {
+        singletons
+            .iter()
+            .map(|s| {
+                let edge = oxidd::zbdd::var_boolean_function(manager, s.as_edge(manager));
+                ZBDDFunction::from_edge(manager, edge.unwrap())
+            })
+            .collect()
+    });
+    let f = pairs(&vars);
+    let table = truth_table(&f, &singletons);
+    let before = f.node_count();
+
+    mref.with_manager_exclusive(|manager| {
+        oxidd_reorder::sift(manager, &SiftConfig::default());
+    });
+    assert_eq!(truth_table(&f, &singletons), table);
+    assert!(f.node_count() < before);
+
+    mref.with_manager_exclusive(|manager| {
+        oxidd_reorder::window_permutation(manager, 3, true);
+    });
+    assert_eq!(truth_table(&f, &singletons), table);
+
+    mref.with_manager_exclusive(|manager| oxidd_reorder::exact(manager));
+    assert_eq!(truth_table(&f, &singletons), table);
+
+    let order: Vec<ZBDDFunction> = singletons.iter().rev().cloned().collect();
+    mref.with_manager_exclusive(|manager| oxidd_reorder::set_var_order(manager, &order));
+    assert_eq!(truth_table(&f, &singletons), table);
+    assert!(level(&singletons[7]) < level(&singletons[0]));
+
+    // Operations relying on the `ZBDDCache` must still work
+    let g = f.not().unwrap();
+    let expected: Vec<bool> = table.iter().map(|&b| !b).collect();
+    assert_eq!(truth_table(&g, &singletons), expected);
+    let h = vars[3].and(&vars[5]).unwrap();
+    let expected: Vec<bool> = (0..1u32 << 8).map(|a| a & 0b101000 == 0b101000).collect();
+    assert_eq!(truth_table(&h, &singletons), expected);
+}
+
+#[test]
+fn zbdd_reorder_singletons() {
+    let (mref, singletons) = zbdd_singletons(6);
+    // {{x0, x3}, {x1, x4}, {x2, x5}, {x0}}
+    let mut family = singletons[0].clone();
+    for i in 0..3 {
+        let set = singletons[i].change(&singletons[i + 3]).unwrap();
+        family = family.union(&set).unwrap();
+    }
+    let table = truth_table(&family, &singletons);
+
+    mref.with_manager_exclusive(|manager| {
+        oxidd_reorder::sift(manager, &SiftConfig::default());
+    });
+    assert_eq!(truth_table(&family, &singletons), table);
+
+    let order: Vec<ZBDDFunction> = singletons.iter().rev().cloned().collect();
+    mref.with_manager_exclusive(|manager| oxidd_reorder::set_var_order_seq(manager, &order));
+    assert_eq!(truth_table(&family, &singletons), table);
+
+    // The singletons must still be singletons
+    for (i, x) in singletons.iter().enumerate() {
+        let expected: Vec<bool> = (0..1u32 << 6).map(|a| a == 1 << i).collect();
+        assert_eq!(truth_table(x, &singletons), expected);
+    }
+}